@@ -26,6 +26,8 @@
 
 typedef struct foxglove_channel foxglove_channel;
 
+typedef struct foxglove_context foxglove_context;
+
 
 typedef struct foxglove_websocket_server foxglove_websocket_server;
 
@@ -40,11 +42,29 @@ typedef struct foxglove_schema {
 extern "C" {
 #endif // __cplusplus
 
+/**
+ * Create a new log context. The context must later be freed with `foxglove_context_free`.
+ *
+ * Channels and servers created with a context are isolated from those created with other
+ * contexts, including the global context.
+ */
+foxglove_context *foxglove_context_new(void);
+
+/**
+ * Free a context created via `foxglove_context_new`.
+ *
+ * Servers created with the context keep it alive until they are stopped, so channels created
+ * with the context remain connected to them.
+ */
+void foxglove_context_free(foxglove_context *context);
+
 /**
  * Create and start a server. The server must later be freed with `foxglove_server_free`.
  *
  * `port` may be 0, in which case an available port will be automatically selected.
  *
+ * The server is registered with the global context.
+ *
  * # Safety
  * `name` and `host` must be null-terminated strings with valid UTF8.
  */
@@ -52,6 +72,23 @@ struct foxglove_websocket_server *foxglove_server_start(const char *name,
                                                         const char *host,
                                                         uint16_t port);
 
+/**
+ * Create and start a server registered with a context. The server must later be freed with
+ * `foxglove_server_free`.
+ *
+ * `port` may be 0, in which case an available port will be automatically selected.
+ *
+ * `context` is an optional pointer to a context created via `foxglove_context_new`. If it is
+ * null, the server is registered with the global context.
+ *
+ * # Safety
+ * `name` and `host` must be null-terminated strings with valid UTF8.
+ */
+struct foxglove_websocket_server *foxglove_server_start_with_context(const char *name,
+                                                                     const char *host,
+                                                                     uint16_t port,
+                                                                     const foxglove_context *context);
+
 /**
  * Free a server created via `foxglove_server_start`.
  *
@@ -72,6 +109,8 @@ void foxglove_server_stop(struct foxglove_websocket_server *server);
 /**
  * Create a new channel. The channel must later be freed with `foxglove_channel_free`.
  *
+ * The channel is registered with the global context.
+ *
  * # Safety
  * `topic` and `message_encoding` must be null-terminated strings with valid UTF8. `schema` is an
  * optional pointer to a schema. The schema and the data it points to need only remain alive for
@@ -81,6 +120,23 @@ foxglove_channel *foxglove_channel_create(const char *topic,
                                           const char *message_encoding,
                                           const struct foxglove_schema *schema);
 
+/**
+ * Create a new channel registered with a context. The channel must later be freed with
+ * `foxglove_channel_free`.
+ *
+ * `context` is an optional pointer to a context created via `foxglove_context_new`. If it is
+ * null, the channel is registered with the global context.
+ *
+ * # Safety
+ * `topic` and `message_encoding` must be null-terminated strings with valid UTF8. `schema` is an
+ * optional pointer to a schema. The schema and the data it points to need only remain alive for
+ * the duration of this function call (they will be copied).
+ */
+foxglove_channel *foxglove_channel_create_with_context(const char *topic,
+                                                       const char *message_encoding,
+                                                       const struct foxglove_schema *schema,
+                                                       const foxglove_context *context);
+
 /**
  * Free a channel created via `foxglove_channel_create`.
  */
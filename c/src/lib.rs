@@ -12,6 +12,10 @@ pub struct FoxgloveWebSocketServer(Option<foxglove::WebSocketServerBlockingHandl
 // after_includes
 pub use foxglove::Channel as FoxgloveChannel;
 
+// cbindgen does not actually generate a declaration for this, so we manually write one in
+// after_includes
+pub use foxglove::LogContext as FoxgloveContext;
+
 #[repr(C)]
 pub struct FoxgloveSchema {
     pub name: *const c_char,
@@ -20,10 +24,34 @@ pub struct FoxgloveSchema {
     pub data_len: usize,
 }
 
+/// Create a new log context. The context must later be freed with `foxglove_context_free`.
+///
+/// Channels and servers created with a context are isolated from those created with other
+/// contexts, including the global context.
+#[unsafe(no_mangle)]
+#[must_use]
+pub extern "C" fn foxglove_context_new() -> *mut FoxgloveContext {
+    Arc::into_raw(Arc::new(foxglove::LogContext::new())).cast_mut()
+}
+
+/// Free a context created via `foxglove_context_new`.
+///
+/// Servers created with the context keep it alive until they are stopped, so channels created
+/// with the context remain connected to them.
+#[unsafe(no_mangle)]
+pub extern "C" fn foxglove_context_free(context: Option<&mut FoxgloveContext>) {
+    let Some(context) = context else {
+        return;
+    };
+    drop(unsafe { Arc::from_raw(context) });
+}
+
 /// Create and start a server. The server must later be freed with `foxglove_server_free`.
 ///
 /// `port` may be 0, in which case an available port will be automatically selected.
 ///
+/// The server is registered with the global context.
+///
 /// # Safety
 /// `name` and `host` must be null-terminated strings with valid UTF8.
 #[unsafe(no_mangle)]
@@ -32,6 +60,27 @@ pub unsafe extern "C" fn foxglove_server_start(
     name: *const c_char,
     host: *const c_char,
     port: u16,
+) -> *mut FoxgloveWebSocketServer {
+    unsafe { foxglove_server_start_with_context(name, host, port, None) }
+}
+
+/// Create and start a server registered with a context. The server must later be freed with
+/// `foxglove_server_free`.
+///
+/// `port` may be 0, in which case an available port will be automatically selected.
+///
+/// `context` is an optional pointer to a context created via `foxglove_context_new`. If it is
+/// null, the server is registered with the global context.
+///
+/// # Safety
+/// `name` and `host` must be null-terminated strings with valid UTF8.
+#[unsafe(no_mangle)]
+#[must_use]
+pub unsafe extern "C" fn foxglove_server_start_with_context(
+    name: *const c_char,
+    host: *const c_char,
+    port: u16,
+    context: Option<&FoxgloveContext>,
 ) -> *mut FoxgloveWebSocketServer {
     let name = unsafe { CStr::from_ptr(name) }
         .to_str()
@@ -39,12 +88,14 @@ pub unsafe extern "C" fn foxglove_server_start(
     let host = unsafe { CStr::from_ptr(host) }
         .to_str()
         .expect("host is invalid");
+    let mut server = foxglove::WebSocketServer::new().name(name).bind(host, port);
+    if let Some(context) = context {
+        // avoid decrementing ref count
+        let context = ManuallyDrop::new(unsafe { Arc::from_raw(context) });
+        server = server.context(&context);
+    }
     Box::into_raw(Box::new(FoxgloveWebSocketServer(Some(
-        foxglove::WebSocketServer::new()
-            .name(name)
-            .bind(host, port)
-            .start_blocking()
-            .expect("Server failed to start"),
+        server.start_blocking().expect("Server failed to start"),
     ))))
 }
 
@@ -88,6 +139,8 @@ pub extern "C" fn foxglove_server_stop(server: Option<&mut FoxgloveWebSocketServ
 
 /// Create a new channel. The channel must later be freed with `foxglove_channel_free`.
 ///
+/// The channel is registered with the global context.
+///
 /// # Safety
 /// `topic` and `message_encoding` must be null-terminated strings with valid UTF8. `schema` is an
 /// optional pointer to a schema. The schema and the data it points to need only remain alive for
@@ -97,6 +150,26 @@ pub unsafe extern "C" fn foxglove_channel_create(
     topic: *const c_char,
     message_encoding: *const c_char,
     schema: *const FoxgloveSchema,
+) -> *mut FoxgloveChannel {
+    unsafe { foxglove_channel_create_with_context(topic, message_encoding, schema, None) }
+}
+
+/// Create a new channel registered with a context. The channel must later be freed with
+/// `foxglove_channel_free`.
+///
+/// `context` is an optional pointer to a context created via `foxglove_context_new`. If it is
+/// null, the channel is registered with the global context.
+///
+/// # Safety
+/// `topic` and `message_encoding` must be null-terminated strings with valid UTF8. `schema` is an
+/// optional pointer to a schema. The schema and the data it points to need only remain alive for
+/// the duration of this function call (they will be copied).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn foxglove_channel_create_with_context(
+    topic: *const c_char,
+    message_encoding: *const c_char,
+    schema: *const FoxgloveSchema,
+    context: Option<&FoxgloveContext>,
 ) -> *mut FoxgloveChannel {
     let topic = unsafe { CStr::from_ptr(topic) }
         .to_str()
@@ -116,14 +189,13 @@ pub unsafe extern "C" fn foxglove_channel_create(
             foxglove::Schema::new(name, encoding, data)
         })
     };
-    Arc::into_raw(
-        foxglove::ChannelBuilder::new(topic)
-            .message_encoding(message_encoding)
-            .schema(schema)
-            .build()
-            .expect("Failed to create channel"),
-    )
-    .cast_mut()
+    let mut builder = foxglove::ChannelBuilder::new(topic)
+        .message_encoding(message_encoding)
+        .schema(schema);
+    if let Some(context) = context {
+        builder = builder.with_context(context);
+    }
+    Arc::into_raw(builder.build().expect("Failed to create channel")).cast_mut()
 }
 
 /// Free a channel created via `foxglove_channel_create`.
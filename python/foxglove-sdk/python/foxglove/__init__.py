@@ -14,6 +14,7 @@ from ._foxglove_py import (
     ChannelView,
     Client,
     ConnectionGraph,
+    Context,
     MCAPWriter,
     MessageSchema,
     Parameter,
@@ -198,6 +199,7 @@ def start_server(
     supported_encodings: Optional[List[str]] = None,
     services: Optional[List[Service]] = None,
     asset_handler: Optional[AssetHandler] = None,
+    context: Optional[Context] = None,
 ) -> WebSocketServer:
     """
     Start a websocket server for live visualization.
@@ -219,6 +221,8 @@ def start_server(
     :param asset_handler: A callback function that returns the asset for a given URI, or None if
         it doesn't exist.
     :type asset_handler: Optional[:py:class:`AssetHandler`] = None
+    :param context: The context whose channels are served. Defaults to the global context.
+    :type context: Optional[:py:class:`Context`] = None
     """
     return _start_server(
         name=name,
@@ -229,6 +233,7 @@ def start_server(
         supported_encodings=supported_encodings,
         services=services,
         asset_handler=asset_handler,
+        context=context,
     )
 
 
@@ -282,6 +287,7 @@ __all__ = [
     "ChannelView",
     "Client",
     "ConnectionGraph",
+    "Context",
     "MCAPWriter",
     "MessageSchema",
     "Parameter",
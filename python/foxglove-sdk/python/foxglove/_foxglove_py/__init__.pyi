@@ -28,6 +28,16 @@ class MCAPWriter:
         """
         ...
 
+class Context:
+    """
+    A context for logging messages.
+
+    Channels, MCAP writers, and websocket servers created with a context only exchange messages
+    with other members of the same context. By default, everything uses a shared global context.
+    """
+
+    def __new__(cls) -> "Context": ...
+
 class StatusLevel(Enum):
     Info = ...
     Warning = ...
@@ -64,6 +74,7 @@ class BaseChannel:
         message_encoding: str,
         schema: Optional["Schema"] = None,
         metadata: Optional[List[Tuple[str, str]]] = None,
+        context: Optional["Context"] = None,
     ) -> "BaseChannel": ...
     def log(
         self,
@@ -283,6 +294,7 @@ def start_server(
     supported_encodings: Optional[List[str]] = None,
     services: Optional[List["Service"]] = None,
     asset_handler: Optional["AssetHandler"] = None,
+    context: Optional["Context"] = None,
 ) -> WebSocketServer:
     """
     Start a websocket server for live visualization.
@@ -307,17 +319,25 @@ def shutdown() -> None:
     """
     ...
 
-def open_mcap(path: str | Path, allow_overwrite: bool = False) -> MCAPWriter:
+def open_mcap(
+    path: str | Path,
+    allow_overwrite: bool = False,
+    *,
+    context: Optional["Context"] = None,
+) -> MCAPWriter:
     """
     Creates a new MCAP file for recording.
 
     :param path: The path to the MCAP file. This file will be created and must not already exist.
     :param allow_overwrite: Set this flag in order to overwrite an existing file at this path.
+    :param context: The context to record messages from. Defaults to the global context.
     :rtype: :py:class:`MCAPWriter`
     """
     ...
 
-def get_channel_for_topic(topic: str) -> BaseChannel:
+def get_channel_for_topic(
+    topic: str, *, context: Optional["Context"] = None
+) -> BaseChannel:
     """
     Get a previously-registered channel.
     """
@@ -1,4 +1,5 @@
 # Generated by https://github.com/foxglove/foxglove-sdk
+from . import Context
 from .schemas import (
     CameraCalibration,
     CircleAnnotation,
@@ -40,6 +41,8 @@ class CameraCalibrationChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "CameraCalibrationChannel": ...
     def close(self) -> None: ...
     def log(
@@ -58,6 +61,8 @@ class CircleAnnotationChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "CircleAnnotationChannel": ...
     def close(self) -> None: ...
     def log(
@@ -76,6 +81,8 @@ class ColorChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "ColorChannel": ...
     def close(self) -> None: ...
     def log(
@@ -94,6 +101,8 @@ class CompressedImageChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "CompressedImageChannel": ...
     def close(self) -> None: ...
     def log(
@@ -112,6 +121,8 @@ class CompressedVideoChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "CompressedVideoChannel": ...
     def close(self) -> None: ...
     def log(
@@ -130,6 +141,8 @@ class FrameTransformChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "FrameTransformChannel": ...
     def close(self) -> None: ...
     def log(
@@ -148,6 +161,8 @@ class FrameTransformsChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "FrameTransformsChannel": ...
     def close(self) -> None: ...
     def log(
@@ -166,6 +181,8 @@ class GeoJsonChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "GeoJsonChannel": ...
     def close(self) -> None: ...
     def log(
@@ -184,6 +201,8 @@ class GridChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "GridChannel": ...
     def close(self) -> None: ...
     def log(
@@ -202,6 +221,8 @@ class ImageAnnotationsChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "ImageAnnotationsChannel": ...
     def close(self) -> None: ...
     def log(
@@ -220,6 +241,8 @@ class KeyValuePairChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "KeyValuePairChannel": ...
     def close(self) -> None: ...
     def log(
@@ -238,6 +261,8 @@ class LaserScanChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "LaserScanChannel": ...
     def close(self) -> None: ...
     def log(
@@ -256,6 +281,8 @@ class LocationFixChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "LocationFixChannel": ...
     def close(self) -> None: ...
     def log(
@@ -274,6 +301,8 @@ class LogChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "LogChannel": ...
     def close(self) -> None: ...
     def log(
@@ -292,6 +321,8 @@ class PackedElementFieldChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "PackedElementFieldChannel": ...
     def close(self) -> None: ...
     def log(
@@ -310,6 +341,8 @@ class Point2Channel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "Point2Channel": ...
     def close(self) -> None: ...
     def log(
@@ -328,6 +361,8 @@ class Point3Channel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "Point3Channel": ...
     def close(self) -> None: ...
     def log(
@@ -346,6 +381,8 @@ class PointCloudChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "PointCloudChannel": ...
     def close(self) -> None: ...
     def log(
@@ -364,6 +401,8 @@ class PointsAnnotationChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "PointsAnnotationChannel": ...
     def close(self) -> None: ...
     def log(
@@ -382,6 +421,8 @@ class PoseChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "PoseChannel": ...
     def close(self) -> None: ...
     def log(
@@ -400,6 +441,8 @@ class PoseInFrameChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "PoseInFrameChannel": ...
     def close(self) -> None: ...
     def log(
@@ -418,6 +461,8 @@ class PosesInFrameChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "PosesInFrameChannel": ...
     def close(self) -> None: ...
     def log(
@@ -436,6 +481,8 @@ class QuaternionChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "QuaternionChannel": ...
     def close(self) -> None: ...
     def log(
@@ -454,6 +501,8 @@ class RawImageChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "RawImageChannel": ...
     def close(self) -> None: ...
     def log(
@@ -472,6 +521,8 @@ class SceneEntityChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "SceneEntityChannel": ...
     def close(self) -> None: ...
     def log(
@@ -490,6 +541,8 @@ class SceneEntityDeletionChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "SceneEntityDeletionChannel": ...
     def close(self) -> None: ...
     def log(
@@ -508,6 +561,8 @@ class SceneUpdateChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "SceneUpdateChannel": ...
     def close(self) -> None: ...
     def log(
@@ -526,6 +581,8 @@ class TextAnnotationChannel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "TextAnnotationChannel": ...
     def close(self) -> None: ...
     def log(
@@ -544,6 +601,8 @@ class Vector2Channel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "Vector2Channel": ...
     def close(self) -> None: ...
     def log(
@@ -562,6 +621,8 @@ class Vector3Channel:
     def __new__(
         cls,
         topic: str,
+        *,
+        context: Context | None = None,
     ) -> "Vector3Channel": ...
     def close(self) -> None: ...
     def log(
@@ -1,7 +1,7 @@
 import json
 from typing import Any, Dict, Optional, Union
 
-from ._foxglove_py import BaseChannel, Schema, channels
+from ._foxglove_py import BaseChannel, Context, Schema, channels
 
 JsonSchema = Dict[str, Any]
 JsonMessage = Dict[str, Any]
@@ -22,6 +22,7 @@ class Channel:
         *,
         schema: Union[JsonSchema, Schema, None],
         message_encoding: Optional[str] = None,
+        context: Optional[Context] = None,
     ):
         """
         Create a new channel for logging messages on a topic.
@@ -33,10 +34,11 @@ class Channel:
         :param schema: A definition of your schema. Pass a :py:class:`Schema`
             for full control. If a dictionary is passed, it will be treated as a
             JSON schema.
+        :param context: The context to log messages to. Defaults to the global context.
 
         :raises KeyError: if a channel already exists for the given topic.
         """
-        if topic in _channels_by_topic:
+        if context is None and topic in _channels_by_topic:
             raise ValueError(f"Channel for topic '{topic}' already exists")
 
         message_encoding, schema = _normalize_schema(message_encoding, schema)
@@ -47,9 +49,11 @@ class Channel:
             topic,
             message_encoding,
             schema,
+            context=context,
         )
 
-        _channels_by_topic[topic] = self
+        if context is None:
+            _channels_by_topic[topic] = self
 
     def log(
         self,
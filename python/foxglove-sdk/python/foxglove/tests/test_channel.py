@@ -1,6 +1,6 @@
 import unittest
 
-from foxglove import Schema
+from foxglove import Context, Schema
 from foxglove.channel import Channel
 from foxglove.channels import LogChannel
 from foxglove.schemas import Log
@@ -19,6 +19,13 @@ class TestChannel(unittest.TestCase):
             ValueError, "already exists", Channel, "test-duplicate", schema=schema
         )
 
+    def test_allows_same_topic_in_separate_contexts(self) -> None:
+        schema = {"type": "object"}
+        first = Channel(self.topic, schema=schema, context=Context())
+        second = Channel(self.topic, schema=schema, context=Context())
+        first.log({"test": "first"})
+        second.log({"test": "second"})
+
     def test_requires_an_object_schema(self) -> None:
         schema = {"type": "array"}
         self.assertRaisesRegex(
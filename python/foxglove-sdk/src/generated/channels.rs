@@ -1,6 +1,7 @@
 use super::schemas;
 use crate::errors::PyFoxgloveError;
-use foxglove::{PartialMetadata, TypedChannel};
+use crate::PyContext;
+use foxglove::{ChannelBuilder, PartialMetadata, TypedChannel};
 use pyo3::prelude::*;
 
 pub fn register_submodule(parent_module: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -56,9 +57,14 @@ impl CameraCalibrationChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -121,9 +127,14 @@ impl CircleAnnotationChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -186,9 +197,14 @@ impl ColorChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -251,9 +267,14 @@ impl CompressedImageChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -316,9 +337,14 @@ impl CompressedVideoChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -381,9 +407,14 @@ impl FrameTransformChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -446,9 +477,14 @@ impl FrameTransformsChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -511,9 +547,14 @@ impl GeoJsonChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -576,9 +617,14 @@ impl GridChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -641,9 +687,14 @@ impl ImageAnnotationsChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -706,9 +757,14 @@ impl KeyValuePairChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -771,9 +827,14 @@ impl LaserScanChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -836,9 +897,14 @@ impl LocationFixChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -901,9 +967,14 @@ impl LogChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -966,9 +1037,14 @@ impl SceneEntityDeletionChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1031,9 +1107,14 @@ impl SceneEntityChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1096,9 +1177,14 @@ impl SceneUpdateChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1161,9 +1247,14 @@ impl PackedElementFieldChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1226,9 +1317,14 @@ impl Point2Channel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1291,9 +1387,14 @@ impl Point3Channel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1356,9 +1457,14 @@ impl PointCloudChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1421,9 +1527,14 @@ impl PointsAnnotationChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1486,9 +1597,14 @@ impl PoseChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1551,9 +1667,14 @@ impl PoseInFrameChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1616,9 +1737,14 @@ impl PosesInFrameChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1681,9 +1807,14 @@ impl QuaternionChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1746,9 +1877,14 @@ impl RawImageChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1811,9 +1947,14 @@ impl TextAnnotationChannel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1876,9 +2017,14 @@ impl Vector2Channel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -1941,9 +2087,14 @@ impl Vector3Channel {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
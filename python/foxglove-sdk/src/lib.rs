@@ -22,6 +22,30 @@ mod generated;
 mod schemas_wkt;
 mod websocket_server;
 
+/// A context for channels and sinks, isolated from other contexts.
+///
+/// By default, channels, MCAP writers and servers use a single global context. Create a
+/// :py:class:`Context` and pass it to each of these to log an independent set of channels,
+/// for example when simulating several robots in one process. Topics need only be unique within
+/// a context.
+#[pyclass(name = "Context", module = "foxglove")]
+pub(crate) struct PyContext(pub(crate) Arc<LogContext>);
+
+#[pymethods]
+impl PyContext {
+    #[new]
+    fn new() -> Self {
+        Self(Arc::new(LogContext::new()))
+    }
+}
+
+impl PyContext {
+    /// Returns the provided context, or the global context if none was provided.
+    pub(crate) fn or_global(context: Option<PyRef<Self>>) -> Arc<LogContext> {
+        context.map_or_else(|| LogContext::global().clone(), |ctx| ctx.0.clone())
+    }
+}
+
 #[pyclass(module = "foxglove")]
 struct BaseChannel(Option<Arc<Channel>>);
 
@@ -76,18 +100,21 @@ impl PyMcapWriter {
 impl BaseChannel {
     #[new]
     #[pyo3(
-        signature = (topic, message_encoding, schema=None, metadata=None)
+        signature = (topic, message_encoding, schema=None, metadata=None, context=None)
     )]
     fn new(
         topic: &str,
         message_encoding: &str,
         schema: Option<PySchema>,
         metadata: Option<BTreeMap<String, String>>,
+        context: Option<PyRef<PyContext>>,
     ) -> PyResult<Self> {
+        let context = PyContext::or_global(context);
         let channel = ChannelBuilder::new(topic)
             .message_encoding(message_encoding)
             .schema(schema.map(Schema::from))
             .metadata(metadata.unwrap_or_default())
+            .with_context(&context)
             .build()
             .map_err(PyFoxgloveError::from)?;
 
@@ -124,10 +151,15 @@ impl BaseChannel {
 ///
 /// :param path: The path to the MCAP file. This file will be created and must not already exist.
 /// :param allow_overwrite: Set this flag in order to overwrite an existing file at this path.
+/// :param context: The context whose channels are recorded. Defaults to the global context.
 /// :rtype: :py:class:`MCAPWriter`
 #[pyfunction]
-#[pyo3(signature = (path, *, allow_overwrite = false))]
-fn open_mcap(path: PathBuf, allow_overwrite: bool) -> PyResult<PyMcapWriter> {
+#[pyo3(signature = (path, *, allow_overwrite = false, context = None))]
+fn open_mcap(
+    path: PathBuf,
+    allow_overwrite: bool,
+    context: Option<PyRef<PyContext>>,
+) -> PyResult<PyMcapWriter> {
     let file = if allow_overwrite {
         File::create(path)?
     } else {
@@ -135,14 +167,19 @@ fn open_mcap(path: PathBuf, allow_overwrite: bool) -> PyResult<PyMcapWriter> {
     };
     let writer = BufWriter::new(file);
     let handle = McapWriter::new()
+        .context(&PyContext::or_global(context))
         .create(writer)
         .map_err(PyFoxgloveError::from)?;
     Ok(PyMcapWriter(Some(handle)))
 }
 
 #[pyfunction]
-fn get_channel_for_topic(topic: &str) -> PyResult<Option<BaseChannel>> {
-    let channel = LogContext::global().get_channel_by_topic(topic);
+#[pyo3(signature = (topic, *, context = None))]
+fn get_channel_for_topic(
+    topic: &str,
+    context: Option<PyRef<PyContext>>,
+) -> PyResult<Option<BaseChannel>> {
+    let channel = PyContext::or_global(context).get_channel_by_topic(topic);
     Ok(channel.map(|chan| BaseChannel(Some(chan))))
 }
 
@@ -188,6 +225,7 @@ fn _foxglove_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(start_server, m)?)?;
     m.add_function(wrap_pyfunction!(get_channel_for_topic, m)?)?;
     m.add_class::<BaseChannel>()?;
+    m.add_class::<PyContext>()?;
     m.add_class::<PyMcapWriter>()?;
 
     // Websocket server classes
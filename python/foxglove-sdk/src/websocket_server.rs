@@ -1,4 +1,5 @@
 use crate::errors::PyFoxgloveError;
+use crate::PyContext;
 use bytes::Bytes;
 use foxglove::{
     websocket::{
@@ -312,11 +313,12 @@ impl foxglove::websocket::service::Handler for ServiceHandler {
 /// :param server_listener: A Python object that implements the :py:class:`ServerListener` protocol.
 /// :param supported_encodings: A list of encodings to advertise to clients.
 ///    Foxglove currently supports "json", "ros1", and "cdr" for client-side publishing.
+/// :param context: The context whose channels are advertised. Defaults to the global context.
 ///
 /// To connect to this server: open Foxglove, choose "Open a new connection", and select Foxglove
 /// WebSocket. The default connection string matches the defaults used by the SDK.
 #[pyfunction]
-#[pyo3(signature = (*, name = None, host="127.0.0.1", port=8765, capabilities=None, server_listener=None, supported_encodings=None, services=None, asset_handler=None))]
+#[pyo3(signature = (*, name = None, host="127.0.0.1", port=8765, capabilities=None, server_listener=None, supported_encodings=None, services=None, asset_handler=None, context=None))]
 #[allow(clippy::too_many_arguments)]
 pub fn start_server(
     py: Python<'_>,
@@ -328,6 +330,7 @@ pub fn start_server(
     supported_encodings: Option<Vec<String>>,
     services: Option<Vec<PyService>>,
     asset_handler: Option<Py<PyAny>>,
+    context: Option<PyRef<PyContext>>,
 ) -> PyResult<PyWebSocketServer> {
     let session_id = time::SystemTime::now()
         .duration_since(time::UNIX_EPOCH)
@@ -337,7 +340,8 @@ pub fn start_server(
 
     let mut server = WebSocketServer::new()
         .session_id(session_id)
-        .bind(host, port);
+        .bind(host, port)
+        .context(&PyContext::or_global(context));
 
     if let Some(py_obj) = server_listener {
         let listener = PyServerListener { listener: py_obj };
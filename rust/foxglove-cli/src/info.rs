@@ -0,0 +1,169 @@
+//! The `info` command, which summarizes MCAP files.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Args;
+use serde::Serialize;
+
+use crate::summary::Summary;
+
+#[derive(Debug, Args)]
+pub struct InfoArgs {
+    /// MCAP files to summarize.
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+    /// Print the summary as JSON.
+    #[arg(long)]
+    json: bool,
+}
+
+/// A summary of an MCAP file.
+#[derive(Debug, Serialize)]
+struct Info {
+    path: PathBuf,
+    /// Time of the first message, in nanoseconds.
+    start_time: Option<u64>,
+    /// Time of the last message, in nanoseconds.
+    end_time: Option<u64>,
+    message_count: u64,
+    attachment_count: u32,
+    metadata_count: u32,
+    schemas: Vec<SchemaInfo>,
+    channels: Vec<ChannelInfo>,
+}
+
+#[derive(Debug, Serialize)]
+struct SchemaInfo {
+    id: u16,
+    name: String,
+    encoding: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ChannelInfo {
+    id: u16,
+    topic: String,
+    message_encoding: String,
+    schema_id: u16,
+    message_count: u64,
+}
+
+impl From<&Summary> for Info {
+    fn from(summary: &Summary) -> Self {
+        let stats = &summary.stats;
+        Self {
+            path: summary.path.clone(),
+            start_time: summary.start_time(),
+            end_time: summary.start_time().map(|_| stats.message_end_time),
+            message_count: stats.message_count,
+            attachment_count: stats.attachment_count,
+            metadata_count: stats.metadata_count,
+            schemas: summary
+                .schemas
+                .iter()
+                .map(|(&id, schema)| SchemaInfo {
+                    id,
+                    name: schema.name.clone(),
+                    encoding: schema.encoding.clone(),
+                })
+                .collect(),
+            channels: summary
+                .channels
+                .iter()
+                .map(|(&id, channel)| ChannelInfo {
+                    id,
+                    topic: channel.topic.clone(),
+                    message_encoding: channel.message_encoding.clone(),
+                    schema_id: channel.schema_id,
+                    message_count: stats
+                        .channel_message_counts
+                        .get(&id)
+                        .copied()
+                        .unwrap_or_default(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Info {
+    /// Returns the time between the first and last messages, in seconds.
+    fn duration_secs(&self) -> Option<f64> {
+        let (start, end) = self.start_time.zip(self.end_time)?;
+        #[allow(clippy::cast_precision_loss)]
+        Some(end.saturating_sub(start) as f64 / 1e9)
+    }
+
+    /// Formats the summary as a human-readable report.
+    fn report(&self) -> String {
+        use std::fmt::Write;
+
+        let mut out = String::new();
+        let time = |t: Option<u64>| {
+            t.map_or_else(
+                || "-".to_string(),
+                |t| format!("{}.{:09}", t / 1_000_000_000, t % 1_000_000_000),
+            )
+        };
+        let duration = self.duration_secs();
+        // Writing to a String cannot fail.
+        let _ = writeln!(out, "path:        {}", self.path.display());
+        let _ = writeln!(out, "start:       {}", time(self.start_time));
+        let _ = writeln!(out, "end:         {}", time(self.end_time));
+        let _ = writeln!(out, "duration:    {:.3}s", duration.unwrap_or_default());
+        let _ = writeln!(out, "messages:    {}", self.message_count);
+        let _ = writeln!(out, "attachments: {}", self.attachment_count);
+        let _ = writeln!(out, "metadata:    {}", self.metadata_count);
+
+        let _ = writeln!(out, "schemas:");
+        for schema in &self.schemas {
+            let _ = writeln!(
+                out,
+                "  ({}) {} [{}]",
+                schema.id, schema.name, schema.encoding
+            );
+        }
+
+        let _ = writeln!(out, "channels:");
+        let topic_width = self
+            .channels
+            .iter()
+            .map(|c| c.topic.len())
+            .max()
+            .unwrap_or_default();
+        for channel in &self.channels {
+            let schema = self
+                .schemas
+                .iter()
+                .find(|s| s.id == channel.schema_id)
+                .map_or("<no schema>", |s| s.name.as_str());
+            #[allow(clippy::cast_precision_loss)]
+            let rate = duration
+                .filter(|&d| d > 0.0)
+                .map(|d| format!(" ({:.2} Hz)", channel.message_count as f64 / d))
+                .unwrap_or_default();
+            let _ = writeln!(
+                out,
+                "  ({}) {:topic_width$}  {} msgs{rate}  [{}] {schema}",
+                channel.id, channel.topic, channel.message_count, channel.message_encoding,
+            );
+        }
+        out
+    }
+}
+
+pub fn main(args: InfoArgs) -> Result<()> {
+    let infos = args
+        .files
+        .iter()
+        .map(|path| Summary::load(path).map(|summary| Info::from(&summary)))
+        .collect::<Result<Vec<_>>>()?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&infos)?);
+    } else {
+        let reports: Vec<_> = infos.iter().map(Info::report).collect();
+        print!("{}", reports.join("\n"));
+    }
+    Ok(())
+}
@@ -0,0 +1,53 @@
+//! Command-line tool for recording, playing and inspecting Foxglove data.
+
+use anyhow::Result;
+use clap::{Args, Parser, Subcommand};
+
+mod info;
+mod play;
+mod playback;
+mod record;
+mod serve;
+mod summary;
+
+#[derive(Debug, Parser)]
+#[command(name = "foxglove", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Record the channels of a Foxglove WebSocket server to an MCAP file.
+    Record(record::RecordArgs),
+    /// Play an MCAP file through a Foxglove WebSocket server.
+    Play(play::PlayArgs),
+    /// Summarize the channels, schemas and messages of MCAP files.
+    Info(info::InfoArgs),
+    /// Serve a directory of MCAP files, which clients can list and play with services.
+    Serve(serve::ServeArgs),
+}
+
+/// Options for the WebSocket server.
+#[derive(Debug, Args)]
+struct ServerArgs {
+    /// Server TCP port.
+    #[arg(short, long, default_value_t = 8765)]
+    port: u16,
+    /// Server IP address.
+    #[arg(long, default_value = "127.0.0.1")]
+    host: String,
+}
+
+fn main() -> Result<()> {
+    let env = env_logger::Env::default().default_filter_or("info");
+    env_logger::init_from_env(env);
+
+    match Cli::parse().command {
+        Command::Record(args) => record::main(args),
+        Command::Play(args) => play::main(args),
+        Command::Info(args) => info::main(args),
+        Command::Serve(args) => serve::main(args),
+    }
+}
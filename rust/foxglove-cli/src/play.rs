@@ -0,0 +1,81 @@
+//! The `play` command, which plays an MCAP file through a WebSocket server.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use foxglove::websocket::Capability;
+use foxglove::{LogContext, WebSocketServer};
+use tracing::info;
+
+use crate::playback::{parse_rate, parse_secs, Playback, PlaybackOptions};
+use crate::summary::Summary;
+use crate::ServerArgs;
+
+#[derive(Debug, Args)]
+pub struct PlayArgs {
+    /// MCAP file to play.
+    file: PathBuf,
+    #[command(flatten)]
+    server: ServerArgs,
+    /// Playback speed, relative to the recording.
+    #[arg(short, long, default_value = "1", value_parser = parse_rate)]
+    rate: f64,
+    /// Offset in seconds from the first message at which to start playing.
+    #[arg(short, long, default_value = "0", value_parser = parse_secs)]
+    start: Duration,
+    /// Play the file repeatedly.
+    #[arg(short, long)]
+    r#loop: bool,
+    /// Seconds to wait for clients to connect before playing.
+    #[arg(long, default_value = "1", value_parser = parse_secs)]
+    delay: Duration,
+}
+
+pub fn main(args: PlayArgs) -> Result<()> {
+    let stop = Arc::new(AtomicBool::default());
+    ctrlc::set_handler({
+        let stop = stop.clone();
+        move || stop.store(true, Ordering::Relaxed)
+    })
+    .context("Failed to set SIGINT handler")?;
+
+    let summary = Summary::load(&args.file)?;
+    let name = args
+        .file
+        .file_name()
+        .map(|n| n.to_string_lossy())
+        .unwrap_or_default();
+    let server = WebSocketServer::new()
+        .name(name)
+        .capabilities([Capability::Time])
+        .bind(&args.server.host, args.server.port)
+        .start_blocking()
+        .context("Failed to start server")?;
+
+    let playback = Playback::new(summary, LogContext::global());
+    let options = PlaybackOptions {
+        rate: args.rate,
+        start: args.start,
+    };
+
+    info!("Waiting for clients");
+    std::thread::sleep(args.delay);
+
+    info!("Playing {}", args.file.display());
+    loop {
+        playback.play(&options, &stop, |time| server.broadcast_time(time))?;
+        if !args.r#loop || stop.load(Ordering::Relaxed) {
+            break;
+        }
+        info!("Looping");
+        server.clear_session(None);
+    }
+
+    drop(playback);
+    server.stop();
+    Ok(())
+}
@@ -0,0 +1,303 @@
+//! Playback of MCAP files to the channels of a log context.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Seek, SeekFrom};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use foxglove::{Channel, ChannelBuilder, LogContext, PartialMetadata};
+use mcap::records::Record;
+use mcap::sans_io::read::{LinearReader, LinearReaderOptions};
+use tracing::warn;
+
+use crate::summary::{advance_reader, Summary};
+
+/// The interval at which the playback time is reported.
+const TIME_INTERVAL: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+/// The longest time to sleep before checking whether playback was stopped.
+const MAX_SLEEP: Duration = Duration::from_millis(100);
+
+/// Options for playing a file.
+#[derive(Debug, Clone)]
+pub struct PlaybackOptions {
+    /// The playback speed, relative to the recording.
+    pub rate: f64,
+    /// The offset from the first message at which to start.
+    pub start: Duration,
+}
+
+impl Default for PlaybackOptions {
+    fn default() -> Self {
+        Self {
+            rate: 1.0,
+            start: Duration::ZERO,
+        }
+    }
+}
+
+/// Parses a playback rate, which must be positive.
+pub fn parse_rate(s: &str) -> Result<f64> {
+    check_rate(s.parse()?)
+}
+
+/// Checks that a playback rate is positive.
+pub fn check_rate(rate: f64) -> Result<f64> {
+    if rate.is_finite() && rate > 0.0 {
+        Ok(rate)
+    } else {
+        Err(anyhow!("rate must be a positive number"))
+    }
+}
+
+/// Parses a duration in seconds.
+pub fn parse_secs(s: &str) -> Result<Duration> {
+    Ok(Duration::try_from_secs_f64(s.parse()?)?)
+}
+
+/// An MCAP file, with a channel for each of its channels.
+///
+/// The channels are removed from the log context when the playback is dropped.
+pub struct Playback {
+    summary: Summary,
+    ctx: Arc<LogContext>,
+    channels: HashMap<u16, Arc<Channel>>,
+}
+
+impl Playback {
+    /// Creates channels in the log context for the channels of the file.
+    ///
+    /// Channels whose topic is already in use are skipped.
+    pub fn new(summary: Summary, ctx: &Arc<LogContext>) -> Self {
+        let mut channels = HashMap::new();
+        for (&id, channel) in &summary.channels {
+            let schema = summary.schemas.get(&channel.schema_id).cloned();
+            match ChannelBuilder::new(&channel.topic)
+                .message_encoding(&channel.message_encoding)
+                .metadata(channel.metadata.clone())
+                .schema(schema)
+                .with_context(ctx)
+                .build()
+            {
+                Ok(channel) => {
+                    channels.insert(id, channel);
+                }
+                Err(e) => warn!("Skipping channel {}: {e}", channel.topic),
+            }
+        }
+        Self {
+            summary,
+            ctx: ctx.clone(),
+            channels,
+        }
+    }
+
+    /// Logs the messages of the file to their channels, at the times they were recorded.
+    ///
+    /// `on_time` is called periodically with the current log time, in nanoseconds. Returns when
+    /// the end of the file is reached, or `stop` is set.
+    pub fn play(
+        &self,
+        options: &PlaybackOptions,
+        stop: &AtomicBool,
+        mut on_time: impl FnMut(u64),
+    ) -> Result<()> {
+        let Some(start_time) = self.summary.start_time() else {
+            return Ok(());
+        };
+        let seek_time =
+            start_time.saturating_add(u64::try_from(options.start.as_nanos()).unwrap_or(u64::MAX));
+
+        let mut file = BufReader::new(File::open(&self.summary.path)?);
+        let mut reader = if let Some(offset) = self.summary.seek_offset(seek_time) {
+            file.seek(SeekFrom::Start(offset))?;
+            LinearReader::new_with_options(
+                LinearReaderOptions::default().with_skip_start_magic(true),
+            )
+        } else {
+            LinearReader::new()
+        };
+
+        let mut clock: Option<Clock> = None;
+        let mut done = false;
+        while !done
+            && !stop.load(Ordering::Relaxed)
+            && advance_reader(&mut reader, &mut file, |record| {
+                match record {
+                    Record::Message { header, data } if header.log_time >= seek_time => {
+                        let clock =
+                            clock.get_or_insert_with(|| Clock::start(seek_time, options.rate));
+                        clock.sleep_until(header.log_time, stop);
+                        if let Some(time) = clock.notify(header.log_time) {
+                            on_time(time);
+                        }
+                        if let Some(channel) = self.channels.get(&header.channel_id) {
+                            channel.log_with_meta(
+                                &data,
+                                PartialMetadata {
+                                    sequence: Some(header.sequence),
+                                    log_time: Some(header.log_time),
+                                    publish_time: Some(header.publish_time),
+                                },
+                            );
+                        }
+                    }
+                    Record::DataEnd(_) => done = true,
+                    _ => (),
+                }
+                Ok(())
+            })?
+        {}
+        Ok(())
+    }
+}
+
+impl Drop for Playback {
+    fn drop(&mut self) {
+        for channel in self.channels.values() {
+            self.ctx.remove_channel_for_topic(channel.topic());
+        }
+    }
+}
+
+/// Relates the log time of the file to the wall clock.
+struct Clock {
+    start: Instant,
+    offset_ns: u64,
+    rate: f64,
+    last_notify: Option<u64>,
+}
+
+impl Clock {
+    /// Starts a clock, at which the wall clock time now corresponds to the given log time.
+    fn start(offset_ns: u64, rate: f64) -> Self {
+        Self {
+            start: Instant::now(),
+            offset_ns,
+            rate,
+            last_notify: None,
+        }
+    }
+
+    /// Returns the time since the clock started at which the given log time is reached.
+    fn elapsed_at(&self, log_time: u64) -> Duration {
+        let log_elapsed = Duration::from_nanos(log_time.saturating_sub(self.offset_ns));
+        log_elapsed.div_f64(self.rate)
+    }
+
+    /// Sleeps until the given log time is reached, or `stop` is set.
+    fn sleep_until(&self, log_time: u64, stop: &AtomicBool) {
+        let deadline = self.start + self.elapsed_at(log_time);
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining < Duration::from_micros(1) || stop.load(Ordering::Relaxed) {
+                break;
+            }
+            std::thread::sleep(remaining.min(MAX_SLEEP));
+        }
+    }
+
+    /// Returns the log time to report, if the reporting interval has passed.
+    fn notify(&mut self, log_time: u64) -> Option<u64> {
+        let interval = u64::try_from(TIME_INTERVAL.as_nanos()).expect("short interval");
+        match self.last_notify {
+            Some(last) if log_time.saturating_sub(last) < interval => None,
+            _ => {
+                self.last_notify = Some(log_time);
+                Some(log_time)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_clock_rate() {
+        let clock = Clock::start(1_000, 2.0);
+        assert_eq!(clock.elapsed_at(500), Duration::ZERO);
+        assert_eq!(clock.elapsed_at(1_000), Duration::ZERO);
+        assert_eq!(clock.elapsed_at(2_000_001_000), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_parse_rate() {
+        assert_eq!(parse_rate("0.5").unwrap(), 0.5);
+        assert!(parse_rate("0").is_err());
+        assert!(parse_rate("-1").is_err());
+        assert!(parse_rate("inf").is_err());
+    }
+
+    #[test]
+    fn test_play_from_offset() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.mcap");
+
+        // Write ten messages, 100ms apart, each in its own chunk.
+        let ctx = Arc::new(LogContext::new());
+        let mcap =
+            foxglove::McapWriter::with_options(mcap::WriteOptions::new().chunk_size(Some(1)))
+                .context(&ctx)
+                .create_new_buffered_file(&path)
+                .unwrap();
+        let channel = ChannelBuilder::new("/test")
+            .message_encoding("json")
+            .with_context(&ctx)
+            .build()
+            .unwrap();
+        for i in 0..10_u64 {
+            let log_time = 1_000_000_000 + i * 100_000_000;
+            channel.log_with_meta(
+                format!("{i}").as_bytes(),
+                PartialMetadata {
+                    log_time: Some(log_time),
+                    ..PartialMetadata::default()
+                },
+            );
+        }
+        mcap.close().unwrap();
+
+        let summary = Summary::load(&path).unwrap();
+        assert_eq!(summary.stats.message_count, 10);
+        assert_eq!(summary.start_time(), Some(1_000_000_000));
+        assert_eq!(summary.stats.message_end_time, 1_900_000_000);
+        assert!(summary.chunk_indexes.len() > 1);
+
+        // Play the file from 0.5s, recording the messages to another file.
+        let ctx = Arc::new(LogContext::new());
+        let output = foxglove::McapWriter::new()
+            .context(&ctx)
+            .create(Cursor::new(Vec::new()))
+            .unwrap();
+        let playback = Playback::new(summary, &ctx);
+        let options = PlaybackOptions {
+            rate: 100.0,
+            start: Duration::from_millis(500),
+        };
+        let mut times = Vec::new();
+        playback
+            .play(&options, &AtomicBool::new(false), |t| times.push(t))
+            .unwrap();
+        drop(playback);
+        assert!(ctx.get_channel_by_topic("/test").is_none());
+        let buf = output.close().unwrap().into_inner();
+
+        let messages: Vec<_> = mcap::MessageStream::new(&buf)
+            .unwrap()
+            .map(|msg| msg.unwrap())
+            .map(|msg| (msg.log_time, String::from_utf8(msg.data.to_vec()).unwrap()))
+            .collect();
+        let expected: Vec<_> = (5..10_u64)
+            .map(|i| (1_000_000_000 + i * 100_000_000, i.to_string()))
+            .collect();
+        assert_eq!(messages, expected);
+        assert_eq!(times.first(), Some(&1_500_000_000));
+    }
+}
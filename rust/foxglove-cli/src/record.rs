@@ -0,0 +1,378 @@
+//! The `record` command, which records the channels of a WebSocket server to an MCAP file.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use base64::prelude::*;
+use bytes::{Buf, Bytes};
+use clap::Args;
+use foxglove::{Channel, ChannelBuilder, FlushPolicy, LogContext, McapWriter, PartialMetadata};
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::json;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{info, warn};
+
+use crate::playback::parse_secs;
+
+/// The subprotocols supported by the recorder, in order of preference.
+const SUBPROTOCOLS: &str = "foxglove.sdk.v1, foxglove.websocket.v1";
+
+/// The binary opcode of a message data message.
+const MESSAGE_DATA: u8 = 1;
+
+#[derive(Debug, Args)]
+pub struct RecordArgs {
+    /// URL of the WebSocket server.
+    #[arg(default_value = "ws://127.0.0.1:8765")]
+    url: String,
+    /// Output MCAP file. Must not exist.
+    #[arg(short, long)]
+    output: PathBuf,
+    /// Topic to record. May be repeated. By default, all topics are recorded.
+    #[arg(short, long)]
+    topic: Vec<String>,
+    /// Stop recording after this many seconds.
+    #[arg(short, long, value_parser = parse_secs)]
+    duration: Option<Duration>,
+}
+
+/// A message from the server, with a JSON payload.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "camelCase", rename_all_fields = "camelCase")]
+enum ServerMessage {
+    ServerInfo {
+        name: String,
+    },
+    Advertise {
+        channels: Vec<Advertisement>,
+    },
+    Unadvertise {
+        channel_ids: Vec<u64>,
+    },
+    Status {
+        message: String,
+    },
+    #[serde(other)]
+    Other,
+}
+
+/// A channel advertised by the server.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Advertisement {
+    id: u64,
+    topic: String,
+    encoding: String,
+    schema_name: String,
+    schema: String,
+    schema_encoding: Option<String>,
+}
+
+impl Advertisement {
+    /// Decodes the schema of the channel, if it has one.
+    fn schema(&self) -> Result<Option<foxglove::Schema>> {
+        if self.schema.is_empty() {
+            return Ok(None);
+        }
+        // If the schema encoding is not given, it is implied by the message encoding.
+        let encoding = self
+            .schema_encoding
+            .as_deref()
+            .unwrap_or(match self.encoding.as_str() {
+                "ros1" => "ros1msg",
+                "cdr" => "ros2msg",
+                "json" => "jsonschema",
+                encoding => encoding,
+            });
+        // Binary schemas are base64-encoded.
+        let data = match encoding {
+            "protobuf" | "flatbuffer" => BASE64_STANDARD
+                .decode(&self.schema)
+                .context("Invalid base64 schema")?,
+            _ => self.schema.clone().into_bytes(),
+        };
+        Ok(Some(foxglove::Schema::new(
+            &self.schema_name,
+            encoding,
+            data,
+        )))
+    }
+}
+
+/// Subscribes to the channels of a server, and logs their messages to channels in a log context.
+struct Recorder {
+    ctx: Arc<LogContext>,
+    topics: Vec<String>,
+    next_subscription_id: u32,
+    // Server channel ID -> subscription ID
+    subscription_ids: HashMap<u64, u32>,
+    // Subscription ID -> channel
+    subscriptions: HashMap<u32, Arc<Channel>>,
+    message_count: u64,
+}
+
+impl Recorder {
+    fn new(ctx: &Arc<LogContext>, topics: Vec<String>) -> Self {
+        Self {
+            ctx: ctx.clone(),
+            topics,
+            next_subscription_id: 0,
+            subscription_ids: HashMap::new(),
+            subscriptions: HashMap::new(),
+            message_count: 0,
+        }
+    }
+
+    /// Handles a JSON message from the server, returning a message to send in reply.
+    fn handle_text(&mut self, text: &str) -> Option<String> {
+        let message = match serde_json::from_str(text) {
+            Ok(message) => message,
+            Err(e) => {
+                warn!("Failed to parse message from server: {e}");
+                return None;
+            }
+        };
+        match message {
+            ServerMessage::ServerInfo { name } => info!("Connected to {name}"),
+            ServerMessage::Advertise { channels } => return self.subscribe(channels),
+            ServerMessage::Unadvertise { channel_ids } => {
+                for id in channel_ids {
+                    let channel = self
+                        .subscription_ids
+                        .remove(&id)
+                        .and_then(|sub_id| self.subscriptions.remove(&sub_id));
+                    if let Some(channel) = channel {
+                        self.ctx.remove_channel_for_topic(channel.topic());
+                    }
+                }
+            }
+            ServerMessage::Status { message } => info!("Server status: {message}"),
+            ServerMessage::Other => (),
+        }
+        None
+    }
+
+    /// Creates channels for the advertised channels, and returns a subscribe message.
+    fn subscribe(&mut self, advertisements: Vec<Advertisement>) -> Option<String> {
+        let mut subscriptions = Vec::new();
+        for adv in advertisements {
+            if self.subscription_ids.contains_key(&adv.id)
+                || !(self.topics.is_empty() || self.topics.contains(&adv.topic))
+            {
+                continue;
+            }
+            let channel = adv.schema().and_then(|schema| {
+                Ok(ChannelBuilder::new(&adv.topic)
+                    .message_encoding(&adv.encoding)
+                    .schema(schema)
+                    .with_context(&self.ctx)
+                    .build()?)
+            });
+            let channel = match channel {
+                Ok(channel) => channel,
+                Err(e) => {
+                    warn!("Skipping channel {}: {e:#}", adv.topic);
+                    continue;
+                }
+            };
+            let sub_id = self.next_subscription_id;
+            self.next_subscription_id += 1;
+            self.subscription_ids.insert(adv.id, sub_id);
+            self.subscriptions.insert(sub_id, channel);
+            subscriptions.push(json!({ "id": sub_id, "channelId": adv.id }));
+            info!("Recording {}", adv.topic);
+        }
+        (!subscriptions.is_empty())
+            .then(|| json!({ "op": "subscribe", "subscriptions": subscriptions }).to_string())
+    }
+
+    /// Handles a binary message from the server.
+    fn handle_binary(&mut self, mut data: Bytes) {
+        // Message data has an opcode, a subscription ID and a log time, followed by the payload.
+        if data.len() < 1 + 4 + 8 || data.get_u8() != MESSAGE_DATA {
+            return;
+        }
+        let sub_id = data.get_u32_le();
+        let log_time = data.get_u64_le();
+        if let Some(channel) = self.subscriptions.get(&sub_id) {
+            channel.log_with_meta(
+                &data,
+                PartialMetadata {
+                    log_time: Some(log_time),
+                    publish_time: Some(log_time),
+                    ..PartialMetadata::default()
+                },
+            );
+            self.message_count += 1;
+        }
+    }
+}
+
+async fn record(args: RecordArgs) -> Result<()> {
+    let mut request = args
+        .url
+        .as_str()
+        .into_client_request()
+        .context("Invalid server URL")?;
+    request.headers_mut().insert(
+        "sec-websocket-protocol",
+        HeaderValue::from_static(SUBPROTOCOLS),
+    );
+    let (mut ws, _) = tokio_tungstenite::connect_async(request)
+        .await
+        .with_context(|| format!("Failed to connect to {}", args.url))?;
+
+    // Flush periodically, so that an interrupted recording can be recovered.
+    let ctx = Arc::new(LogContext::new());
+    let mcap = McapWriter::new()
+        .context(&ctx)
+        .flush_policy(FlushPolicy::new().interval(Duration::from_secs(1)))
+        .create_new_buffered_file(&args.output)
+        .with_context(|| format!("Failed to create {}", args.output.display()))?;
+    let mut recorder = Recorder::new(&ctx, args.topic);
+
+    let stop = async {
+        match args.duration {
+            Some(duration) => tokio::time::sleep(duration).await,
+            None => std::future::pending().await,
+        }
+    };
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(stop, ctrl_c);
+    loop {
+        let message = tokio::select! {
+            () = &mut stop => break,
+            _ = &mut ctrl_c => break,
+            message = ws.next() => message,
+        };
+        let Some(message) = message else {
+            info!("Server closed the connection");
+            break;
+        };
+        match message.context("Connection failed")? {
+            Message::Text(text) => {
+                if let Some(reply) = recorder.handle_text(text.as_str()) {
+                    ws.send(Message::text(reply)).await?;
+                }
+            }
+            Message::Binary(data) => recorder.handle_binary(data),
+            _ => (),
+        }
+    }
+
+    let _ = ws.close(None).await;
+    mcap.close()?;
+    info!(
+        "Recorded {} messages to {}",
+        recorder.message_count,
+        args.output.display()
+    );
+    Ok(())
+}
+
+pub fn main(args: RecordArgs) -> Result<()> {
+    tokio::runtime::Runtime::new()
+        .map_err(|e| anyhow!("Failed to create runtime: {e}"))?
+        .block_on(record(args))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use bytes::BufMut;
+
+    use super::*;
+
+    fn message_data(sub_id: u32, log_time: u64, payload: &[u8]) -> Bytes {
+        let mut buf = Vec::new();
+        buf.put_u8(MESSAGE_DATA);
+        buf.put_u32_le(sub_id);
+        buf.put_u64_le(log_time);
+        buf.put_slice(payload);
+        buf.into()
+    }
+
+    #[test]
+    fn test_recorder() {
+        let ctx = Arc::new(LogContext::new());
+        let mcap = McapWriter::new()
+            .context(&ctx)
+            .create(Cursor::new(Vec::new()))
+            .unwrap();
+        let mut recorder = Recorder::new(&ctx, vec!["/a".to_string(), "/b".to_string()]);
+
+        let advertise = json!({
+            "op": "advertise",
+            "channels": [
+                {
+                    "id": 10,
+                    "topic": "/a",
+                    "encoding": "protobuf",
+                    "schemaName": "pkg.A",
+                    "schema": BASE64_STANDARD.encode(b"\x01\x02"),
+                    "schemaEncoding": "protobuf",
+                },
+                {
+                    "id": 11,
+                    "topic": "/b",
+                    "encoding": "json",
+                    "schemaName": "B",
+                    "schema": "{}",
+                },
+                {
+                    "id": 12,
+                    "topic": "/c",
+                    "encoding": "json",
+                    "schemaName": "C",
+                    "schema": "{}",
+                },
+            ],
+        });
+        let reply = recorder.handle_text(&advertise.to_string()).unwrap();
+        let reply: serde_json::Value = serde_json::from_str(&reply).unwrap();
+        assert_eq!(
+            reply,
+            json!({
+                "op": "subscribe",
+                "subscriptions": [{ "id": 0, "channelId": 10 }, { "id": 1, "channelId": 11 }],
+            })
+        );
+        // Channels which are already subscribed are not subscribed again.
+        assert_eq!(recorder.handle_text(&advertise.to_string()), None);
+
+        recorder.handle_binary(message_data(0, 42, b"a"));
+        recorder.handle_binary(message_data(1, 43, b"{}"));
+        recorder.handle_binary(message_data(2, 44, b"unknown subscription"));
+        recorder.handle_text(r#"{"op": "unadvertise", "channelIds": [10]}"#);
+        recorder.handle_binary(message_data(0, 45, b"unadvertised"));
+        assert_eq!(recorder.message_count, 2);
+        assert!(ctx.get_channel_by_topic("/a").is_none());
+
+        let buf = mcap.close().unwrap().into_inner();
+        let messages: Vec<_> = mcap::MessageStream::new(&buf)
+            .unwrap()
+            .map(|msg| msg.unwrap())
+            .collect();
+        assert_eq!(messages.len(), 2);
+        let schema = messages[0].channel.schema.as_ref().unwrap();
+        assert_eq!(
+            (schema.name.as_str(), schema.encoding.as_str()),
+            ("pkg.A", "protobuf")
+        );
+        assert_eq!(schema.data.as_ref(), b"\x01\x02");
+        assert_eq!(
+            (messages[0].log_time, messages[0].data.as_ref()),
+            (42, &b"a"[..])
+        );
+        let schema = messages[1].channel.schema.as_ref().unwrap();
+        assert_eq!(schema.encoding, "jsonschema");
+        assert_eq!(messages[1].channel.topic, "/b");
+    }
+}
@@ -0,0 +1,317 @@
+//! The `serve` command, which serves a directory of MCAP files through a WebSocket server.
+//!
+//! Clients list the files with the `/recordings/list` service, and play one with the
+//! `/recordings/play` service. Playing a file stops the file which was playing, and clears the
+//! session, so that clients reset their state.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use bytes::Bytes;
+use clap::Args;
+use foxglove::websocket::service::{Request, Service, ServiceSchema};
+use foxglove::websocket::Capability;
+use foxglove::{LogContext, Schema, WebSocketServer, WebSocketServerBlockingHandle};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::playback::{check_rate, Playback, PlaybackOptions};
+use crate::summary::Summary;
+use crate::ServerArgs;
+
+#[derive(Debug, Args)]
+pub struct ServeArgs {
+    /// Directory containing MCAP files.
+    dir: PathBuf,
+    #[command(flatten)]
+    server: ServerArgs,
+}
+
+/// A file in the served directory.
+#[derive(Debug, Serialize, JsonSchema)]
+struct RecordingInfo {
+    /// The file name.
+    name: String,
+    /// The size of the file, in bytes.
+    size: u64,
+    /// Time of the first message, in nanoseconds.
+    start_time: Option<u64>,
+    /// Time of the last message, in nanoseconds.
+    end_time: Option<u64>,
+    message_count: u64,
+}
+
+/// A request to play a file.
+#[derive(Debug, Deserialize, JsonSchema)]
+struct PlayRequest {
+    /// The file name, as returned by `/recordings/list`.
+    name: String,
+    /// The playback speed, relative to the recording. Defaults to 1.
+    rate: Option<f64>,
+    /// Offset in seconds from the first message at which to start playing. Defaults to 0.
+    start: Option<f64>,
+    /// Whether to play the file repeatedly. Defaults to false.
+    #[serde(default)]
+    r#loop: bool,
+}
+
+/// The file which is playing.
+#[derive(Debug, Serialize, JsonSchema)]
+struct PlaybackStatus {
+    /// The name of the file which is playing, if any.
+    playing: Option<String>,
+}
+
+/// A command for the thread which controls playback.
+enum Command {
+    Play(PlayRequest, mpsc::Sender<Result<PlaybackStatus>>),
+    Stop(mpsc::Sender<Result<PlaybackStatus>>),
+    Shutdown,
+}
+
+/// A file being played on a background thread.
+struct Session {
+    name: String,
+    stop: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+impl Session {
+    fn stop(self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if self.thread.join().is_err() {
+            warn!("Playback of {} panicked", self.name);
+        }
+    }
+}
+
+/// Returns the path of a file in the directory, rejecting names which refer to other directories.
+fn resolve(dir: &Path, name: &str) -> Result<PathBuf> {
+    let path = Path::new(name);
+    if path.file_name().is_none_or(|n| n != name) || path.extension().is_none_or(|e| e != "mcap") {
+        return Err(anyhow!("Invalid recording name: {name}"));
+    }
+    Ok(dir.join(path))
+}
+
+/// Lists the MCAP files in the directory, sorted by name.
+fn list(dir: &Path) -> Result<Vec<RecordingInfo>> {
+    let mut recordings = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_file() || path.extension().is_none_or(|e| e != "mcap") {
+            continue;
+        }
+        let summary = match Summary::load(&path) {
+            Ok(summary) => summary,
+            Err(e) => {
+                warn!("Skipping {}: {e:#}", path.display());
+                continue;
+            }
+        };
+        recordings.push(RecordingInfo {
+            name: path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            size: path.metadata()?.len(),
+            start_time: summary.start_time(),
+            end_time: summary.start_time().map(|_| summary.stats.message_end_time),
+            message_count: summary.stats.message_count,
+        });
+    }
+    recordings.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(recordings)
+}
+
+/// Controls playback, in response to commands from the service handlers.
+struct Controller {
+    dir: PathBuf,
+    server: Arc<WebSocketServerBlockingHandle>,
+    session: Option<Session>,
+}
+
+impl Controller {
+    fn status(&self) -> PlaybackStatus {
+        PlaybackStatus {
+            playing: self
+                .session
+                .as_ref()
+                .filter(|s| !s.thread.is_finished())
+                .map(|s| s.name.clone()),
+        }
+    }
+
+    fn stop(&mut self) {
+        if let Some(session) = self.session.take() {
+            session.stop();
+        }
+    }
+
+    fn play(&mut self, request: PlayRequest) -> Result<PlaybackStatus> {
+        let path = resolve(&self.dir, &request.name)?;
+        let summary = Summary::load(&path)?;
+        let options = PlaybackOptions {
+            rate: check_rate(request.rate.unwrap_or(1.0))?,
+            start: Duration::try_from_secs_f64(request.start.unwrap_or_default())?,
+        };
+
+        // Channels are created after the previous playback removes its channels, since files may
+        // have topics in common.
+        self.stop();
+        self.server.clear_session(None);
+        let playback = Playback::new(summary, LogContext::global());
+
+        info!("Playing {}", path.display());
+        let stop = Arc::new(AtomicBool::default());
+        let thread = std::thread::spawn({
+            let stop = stop.clone();
+            let server = self.server.clone();
+            move || loop {
+                if let Err(e) = playback.play(&options, &stop, |time| server.broadcast_time(time)) {
+                    warn!("Failed to play {}: {e:#}", path.display());
+                    break;
+                }
+                if !request.r#loop || stop.load(Ordering::Relaxed) {
+                    break;
+                }
+                server.clear_session(None);
+            }
+        });
+        self.session = Some(Session {
+            name: request.name,
+            stop,
+            thread,
+        });
+        Ok(self.status())
+    }
+
+    /// Handles commands until shutdown.
+    fn run(mut self, commands: &mpsc::Receiver<Command>) {
+        while let Ok(command) = commands.recv() {
+            match command {
+                Command::Play(request, reply) => {
+                    let _ = reply.send(self.play(request));
+                }
+                Command::Stop(reply) => {
+                    self.stop();
+                    let _ = reply.send(Ok(self.status()));
+                }
+                Command::Shutdown => break,
+            }
+        }
+        self.stop();
+    }
+}
+
+/// Sends a command to the controller, and waits for the reply.
+fn call(
+    commands: &mpsc::Sender<Command>,
+    command: impl FnOnce(mpsc::Sender<Result<PlaybackStatus>>) -> Command,
+) -> Result<Bytes> {
+    let (tx, rx) = mpsc::channel();
+    commands
+        .send(command(tx))
+        .map_err(|_| anyhow!("Server is shutting down"))?;
+    let status = rx.recv().context("Server is shutting down")??;
+    Ok(serde_json::to_vec(&status)?.into())
+}
+
+fn services(dir: &Path, commands: &mpsc::Sender<Command>) -> Vec<Service> {
+    let status_schema = || Schema::json_schema::<PlaybackStatus>();
+    vec![
+        Service::builder(
+            "/recordings/list",
+            ServiceSchema::new("/foxglove_cli/ListRecordings")
+                .with_response("json", Schema::json_schema::<Vec<RecordingInfo>>()),
+        )
+        .blocking_handler_fn({
+            let dir = dir.to_owned();
+            move |_| anyhow::Ok(Bytes::from(serde_json::to_vec(&list(&dir)?)?))
+        }),
+        Service::builder(
+            "/recordings/play",
+            ServiceSchema::new("/foxglove_cli/PlayRecording")
+                .with_request("json", Schema::json_schema::<PlayRequest>())
+                .with_response("json", status_schema()),
+        )
+        .blocking_handler_fn({
+            let commands = commands.clone();
+            move |req: Request| {
+                let request: PlayRequest = serde_json::from_slice(req.payload())?;
+                call(&commands, |reply| Command::Play(request, reply))
+            }
+        }),
+        Service::builder(
+            "/recordings/stop",
+            ServiceSchema::new("/foxglove_cli/StopPlayback").with_response("json", status_schema()),
+        )
+        .blocking_handler_fn({
+            let commands = commands.clone();
+            move |_| call(&commands, Command::Stop)
+        }),
+    ]
+}
+
+pub fn main(args: ServeArgs) -> Result<()> {
+    if !args.dir.is_dir() {
+        return Err(anyhow!("Not a directory: {}", args.dir.display()));
+    }
+
+    let (commands, receiver) = mpsc::channel();
+    ctrlc::set_handler({
+        let commands = commands.clone();
+        move || {
+            let _ = commands.send(Command::Shutdown);
+        }
+    })
+    .context("Failed to set SIGINT handler")?;
+
+    let server = WebSocketServer::new()
+        .name(args.dir.to_string_lossy())
+        .capabilities([Capability::Services, Capability::Time])
+        .supported_encodings(["json"])
+        .services(services(&args.dir, &commands))
+        .bind(&args.server.host, args.server.port)
+        .start_blocking()
+        .context("Failed to start server")?;
+    let server = Arc::new(server);
+    info!("Serving {}", args.dir.display());
+
+    Controller {
+        dir: args.dir,
+        server: server.clone(),
+        session: None,
+    }
+    .run(&receiver);
+
+    // The playback thread, which shared the server handle, has stopped.
+    if let Ok(server) = Arc::try_unwrap(server) {
+        server.stop();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let dir = Path::new("/data");
+        assert_eq!(
+            resolve(dir, "a.mcap").unwrap(),
+            PathBuf::from("/data/a.mcap")
+        );
+        assert!(resolve(dir, "../a.mcap").is_err());
+        assert!(resolve(dir, "sub/a.mcap").is_err());
+        assert!(resolve(dir, "/a.mcap").is_err());
+        assert!(resolve(dir, "a.txt").is_err());
+        assert!(resolve(dir, "").is_err());
+    }
+}
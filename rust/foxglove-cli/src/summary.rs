@@ -0,0 +1,177 @@
+//! Reading the summary of an MCAP file.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use bytes::Buf;
+use mcap::records::{Channel, ChunkIndex, Record, SchemaHeader, Statistics};
+use mcap::sans_io::read::{LinearReader, LinearReaderOptions, ReadAction};
+
+/// The length of the footer record's fields, and the magic which ends the file.
+const FOOTER_AND_MAGIC_LEN: i64 = 28;
+
+/// The schemas, channels and statistics of an MCAP file.
+#[derive(Debug)]
+pub struct Summary {
+    pub path: PathBuf,
+    pub schemas: BTreeMap<u16, foxglove::Schema>,
+    pub channels: BTreeMap<u16, Channel>,
+    pub stats: Statistics,
+    /// Chunk indexes, in file order. Empty if the file has no summary section.
+    pub chunk_indexes: Vec<ChunkIndex>,
+}
+
+/// Advances the reader by one action, passing any record to `handle_record`.
+///
+/// Returns false when the reader is done.
+pub fn advance_reader<R, F>(
+    reader: &mut LinearReader,
+    file: &mut R,
+    mut handle_record: F,
+) -> Result<bool>
+where
+    R: Read,
+    F: FnMut(Record<'_>) -> Result<()>,
+{
+    if let Some(action) = reader.next_action() {
+        match action? {
+            ReadAction::NeedMore(count) => {
+                let count = file.read(reader.insert(count))?;
+                reader.set_written(count);
+            }
+            ReadAction::GetRecord { data, opcode } => {
+                let record = mcap::parse_record(opcode, data)?;
+                handle_record(record)?;
+            }
+        }
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+impl Summary {
+    /// Loads the summary of an MCAP file.
+    ///
+    /// If the file has a summary section, only the summary section is read. Otherwise, the whole
+    /// file is scanned to count the messages.
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut file = BufReader::new(
+            File::open(path).with_context(|| format!("Failed to open {}", path.display()))?,
+        );
+        let mut summary = Self {
+            path: path.to_owned(),
+            schemas: BTreeMap::new(),
+            channels: BTreeMap::new(),
+            stats: Statistics::default(),
+            chunk_indexes: Vec::new(),
+        };
+
+        let mut reader = if let Some(summary_start) = summary_start(&mut file)? {
+            file.seek(SeekFrom::Start(summary_start))
+                .context("Failed to seek to summary")?;
+            LinearReader::new_with_options(
+                LinearReaderOptions::default().with_skip_start_magic(true),
+            )
+        } else {
+            file.rewind()?;
+            LinearReader::new()
+        };
+        while advance_reader(&mut reader, &mut file, |record| {
+            summary.handle_record(record);
+            Ok(())
+        })
+        .with_context(|| format!("Failed to read {}", path.display()))?
+        {}
+
+        summary
+            .chunk_indexes
+            .sort_by_key(|index| index.chunk_start_offset);
+        Ok(summary)
+    }
+
+    /// Returns the time of the first message, in nanoseconds, or `None` if there are no messages.
+    pub fn start_time(&self) -> Option<u64> {
+        (self.stats.message_count > 0).then_some(self.stats.message_start_time)
+    }
+
+    /// Returns the offset of the first chunk which may contain messages at or after `log_time`.
+    ///
+    /// Chunks which end before `log_time` contain no messages to play, so they can be skipped.
+    /// Returns `None` if the file has no chunk indexes.
+    pub fn seek_offset(&self, log_time: u64) -> Option<u64> {
+        let index = self
+            .chunk_indexes
+            .iter()
+            .find(|index| index.message_end_time >= log_time)
+            .or(self.chunk_indexes.last())?;
+        Some(index.chunk_start_offset)
+    }
+
+    fn handle_record(&mut self, record: Record<'_>) {
+        match record {
+            Record::Schema { header, data } => self.handle_schema(&header, data),
+            Record::Channel(channel) => {
+                self.channels.entry(channel.id).or_insert(channel);
+            }
+            Record::Statistics(stats) => self.stats = stats,
+            Record::ChunkIndex(index) => self.chunk_indexes.push(index),
+            // Without a summary section, the statistics are counted from the data section.
+            Record::Message { header, .. } => {
+                let stats = &mut self.stats;
+                if stats.message_count == 0 {
+                    stats.message_start_time = header.log_time;
+                    stats.message_end_time = header.log_time;
+                }
+                stats.message_count += 1;
+                stats.message_start_time = stats.message_start_time.min(header.log_time);
+                stats.message_end_time = stats.message_end_time.max(header.log_time);
+                *stats
+                    .channel_message_counts
+                    .entry(header.channel_id)
+                    .or_default() += 1;
+            }
+            Record::Attachment { .. } => self.stats.attachment_count += 1,
+            Record::Metadata(_) => self.stats.metadata_count += 1,
+            _ => (),
+        }
+        self.stats.schema_count = u16::try_from(self.schemas.len()).unwrap_or(u16::MAX);
+        self.stats.channel_count = u32::try_from(self.channels.len()).unwrap_or(u32::MAX);
+    }
+
+    fn handle_schema(&mut self, header: &SchemaHeader, data: Cow<'_, [u8]>) {
+        // Schema ID 0 is reserved to indicate a channel without a schema.
+        if header.id != 0 {
+            self.schemas.entry(header.id).or_insert_with(|| {
+                foxglove::Schema::new(&header.name, &header.encoding, data.into_owned())
+            });
+        }
+    }
+}
+
+/// Reads the footer, and returns the offset of the summary section, or `None` if the file has no
+/// summary section.
+fn summary_start<R: Read + Seek>(file: &mut R) -> Result<Option<u64>> {
+    let len = file.seek(SeekFrom::End(0))?;
+    // The start magic, and the footer with its one-byte opcode and eight-byte length.
+    if len < mcap::MAGIC.len() as u64 + 9 + FOOTER_AND_MAGIC_LEN as u64 {
+        return Err(anyhow!("File is too short to be an MCAP file"));
+    }
+
+    // The footer's fields start with the offset of the summary section.
+    let mut buf = Vec::with_capacity(FOOTER_AND_MAGIC_LEN as usize);
+    file.seek(SeekFrom::End(-FOOTER_AND_MAGIC_LEN))?;
+    file.read_to_end(&mut buf)
+        .context("Failed to read footer")?;
+    if !buf.ends_with(mcap::MAGIC) {
+        return Err(anyhow!(
+            "Missing MCAP magic at end of file; the file may be truncated"
+        ));
+    }
+    let summary_start = buf.as_slice().get_u64_le();
+    Ok((summary_start != 0).then_some(summary_start))
+}
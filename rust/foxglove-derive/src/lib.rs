@@ -0,0 +1,335 @@
+//! Derive macros for the [Foxglove SDK](https://docs.rs/foxglove).
+//!
+//! This crate is re-exported by the `foxglove` crate when its `derive` feature is enabled.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{
+    parse_macro_input, Data, DataEnum, DeriveInput, Expr, ExprLit, Field, Fields, FieldsNamed, Lit,
+    LitInt,
+};
+
+mod packed_point;
+#[cfg(test)]
+mod tests;
+
+/// The largest protobuf field number.
+const MAX_FIELD_NUMBER: u32 = (1 << 29) - 1;
+
+/// Field numbers reserved for the protobuf implementation.
+const RESERVED_FIELD_NUMBERS: std::ops::RangeInclusive<u32> = 19000..=19999;
+
+/// Derives `foxglove::Encode` for a struct, using protobuf encoding.
+///
+/// The schema is a protobuf `FileDescriptorSet` generated from the struct definition. The
+/// protobuf package is derived from the module path of the struct, so `my_crate::sensors::Reading`
+/// is described by the message `my_crate.sensors.Reading`.
+///
+/// # Field numbers
+///
+/// By default, fields are numbered sequentially, starting at 1, in declaration order. **Adding,
+/// removing or reordering fields changes the numbers of the fields after them**, so data
+/// recorded with an older definition is decoded into the wrong fields. To keep the numbers stable
+/// as the struct changes, set them with the `#[foxglove(tag = N)]` attribute. A field without a
+/// tag is numbered one more than the field before it:
+///
+/// ```ignore
+/// #[derive(foxglove::Encode)]
+/// struct Reading {
+///     #[foxglove(tag = 1)]
+///     label: String,
+///     // Numbered 2.
+///     value: f64,
+///     #[foxglove(tag = 10)]
+///     unit: String,
+/// }
+/// ```
+///
+/// # Field types
+///
+/// Fields may be of any type that implements `foxglove::protobuf::ProtobufField`. This includes
+/// `bool`, `f32`, `f64`, `i32`, `i64`, `u32`, `u64`, `String`, `Vec<u8>`, `bytes::Bytes`, the
+/// well-known `foxglove::schemas` types, `Option<T>` and `Vec<T>`, and other types that derive
+/// `Encode`.
+///
+/// # Enums
+///
+/// This macro can also be applied to an enum whose variants have no fields, so that it can be
+/// used as the type of a field. Such enums are encoded as protobuf enums, using the discriminant
+/// of each variant as its value. The first variant must have the value zero, which is the
+/// default value of a protobuf enum.
+#[proc_macro_derive(Encode, attributes(foxglove))]
+pub fn derive_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `foxglove::point_cloud::PackedPoint` for a struct.
+///
+/// Each field becomes a field of the point cloud, with the same name, in declaration order.
+/// Fields must be of a type that implements `foxglove::point_cloud::PointFieldType`: `u8`, `i8`,
+/// `u16`, `i16`, `u32`, `i32`, `f32` or `f64`. Fields are packed without padding.
+#[proc_macro_derive(PackedPoint)]
+pub fn derive_packed_point(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    packed_point::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "Encode cannot be derived for generic types",
+        ));
+    }
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => expand_struct(input, Some(fields)),
+            Fields::Unit => expand_struct(input, None),
+            Fields::Unnamed(_) => Err(syn::Error::new_spanned(
+                input,
+                "Encode can only be derived for structs with named fields",
+            )),
+        },
+        Data::Enum(data) => expand_enum(input, data),
+        Data::Union(_) => Err(syn::Error::new_spanned(
+            input,
+            "Encode cannot be derived for unions",
+        )),
+    }
+}
+
+/// Returns the `#[foxglove(tag = N)]` field number of a field, if it has one.
+fn field_tag(field: &Field) -> syn::Result<Option<LitInt>> {
+    let mut tag = None;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("foxglove")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag") {
+                tag = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported foxglove attribute, expected `tag = N`"))
+            }
+        })?;
+    }
+    Ok(tag)
+}
+
+/// Returns the protobuf field number of each field.
+///
+/// Fields with a `#[foxglove(tag = N)]` attribute use that number, and other fields are numbered
+/// one more than the field before them, starting at 1.
+fn field_numbers(fields: &[&Field]) -> syn::Result<Vec<u32>> {
+    let mut numbers: Vec<u32> = Vec::with_capacity(fields.len());
+    for field in fields {
+        let number = match field_tag(field)? {
+            Some(tag) => {
+                let number: u32 = tag.base10_parse()?;
+                if number == 0 || number > MAX_FIELD_NUMBER {
+                    return Err(syn::Error::new_spanned(
+                        tag,
+                        format!("field number must be between 1 and {MAX_FIELD_NUMBER}"),
+                    ));
+                }
+                if RESERVED_FIELD_NUMBERS.contains(&number) {
+                    return Err(syn::Error::new_spanned(
+                        tag,
+                        "field numbers 19000 to 19999 are reserved by protobuf",
+                    ));
+                }
+                number
+            }
+            None => match numbers.last() {
+                Some(&n) if n >= MAX_FIELD_NUMBER => {
+                    return Err(syn::Error::new_spanned(
+                        field,
+                        "field number is out of range; set it with #[foxglove(tag = N)]",
+                    ));
+                }
+                Some(&n) => n + 1,
+                None => 1,
+            },
+        };
+        if numbers.contains(&number) {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!("field number {number} is used by more than one field"),
+            ));
+        }
+        numbers.push(number);
+    }
+    Ok(numbers)
+}
+
+/// Implements `ProtobufMessage` and `Encode` for a struct.
+fn expand_struct(input: &DeriveInput, fields: Option<&FieldsNamed>) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let name = ident.unraw().to_string();
+    let fields: Vec<_> = fields.map(|f| f.named.iter().collect()).unwrap_or_default();
+    let members: Vec<_> = fields.iter().map(|f| &f.ident).collect();
+    let names = fields
+        .iter()
+        .filter_map(|f| f.ident.as_ref())
+        .map(|i| i.unraw().to_string());
+    let types = fields.iter().map(|f| &f.ty);
+    let numbers = field_numbers(&fields)?;
+
+    Ok(quote! {
+        impl ::foxglove::protobuf::ProtobufMessage for #ident {
+            fn type_ref(
+                set: &mut ::foxglove::protobuf::DescriptorSet,
+            ) -> ::foxglove::protobuf::TypeRef {
+                set.add_message(::core::module_path!(), #name, |message| {
+                    #(message.field::<#types>(#names, #numbers);)*
+                })
+            }
+
+            fn encode_raw(&self, buf: &mut impl ::foxglove::protobuf::BufMut) {
+                #(::foxglove::protobuf::ProtobufField::write_field(&self.#members, #numbers, buf);)*
+            }
+
+            fn encoded_len(&self) -> usize {
+                0 #(+ ::foxglove::protobuf::ProtobufField::field_len(&self.#members, #numbers))*
+            }
+        }
+
+        impl ::foxglove::Encode for #ident {
+            type Error = ::foxglove::protobuf::EncodeError;
+
+            fn get_schema() -> ::core::option::Option<::foxglove::Schema> {
+                ::core::option::Option::Some(::foxglove::protobuf::schema::<Self>())
+            }
+
+            fn get_message_encoding() -> ::std::string::String {
+                ::std::string::String::from("protobuf")
+            }
+
+            fn encode(
+                &self,
+                buf: &mut impl ::foxglove::protobuf::BufMut,
+            ) -> ::core::result::Result<(), Self::Error> {
+                ::foxglove::protobuf::encode(self, buf)
+            }
+
+            fn encoded_len(&self) -> ::core::option::Option<usize> {
+                ::core::option::Option::Some(
+                    ::foxglove::protobuf::ProtobufMessage::encoded_len(self),
+                )
+            }
+        }
+    })
+}
+
+/// Implements `ProtobufEnum`, `ProtobufField` and `ProtobufRepeated` for an enum whose variants
+/// have no fields.
+fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let name = ident.unraw().to_string();
+    if let Some(variant) = data.variants.iter().find(|v| !v.fields.is_empty()) {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "Encode can only be derived for enums whose variants have no fields",
+        ));
+    }
+    if let Some((_, discriminant)) = data.variants.first().and_then(|v| v.discriminant.as_ref()) {
+        let is_zero = match discriminant {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(value),
+                ..
+            }) => value.base10_parse::<i64>().ok() == Some(0),
+            // Other expressions are checked by the assertion below, when they are evaluated.
+            _ => true,
+        };
+        if !is_zero {
+            return Err(syn::Error::new_spanned(
+                discriminant,
+                "the first variant must have the value zero, the default value of a protobuf enum",
+            ));
+        }
+    }
+    let variants: Vec<_> = data.variants.iter().map(|v| &v.ident).collect();
+    let names = variants.iter().map(|v| v.unraw().to_string());
+    let first = variants.first();
+    let first_is_zero = first.map(|first| {
+        quote! {
+            const _: () = ::core::assert!(
+                #ident::#first as i32 == 0,
+                "the first variant must have the value zero, the default value of a protobuf enum",
+            );
+        }
+    });
+
+    Ok(quote! {
+        #first_is_zero
+
+        impl ::foxglove::protobuf::ProtobufEnum for #ident {
+            fn type_ref(
+                set: &mut ::foxglove::protobuf::DescriptorSet,
+            ) -> ::foxglove::protobuf::TypeRef {
+                set.add_enum(
+                    ::core::module_path!(),
+                    #name,
+                    &[#((#names, Self::#variants as i32)),*],
+                )
+            }
+
+            fn value(&self) -> i32 {
+                match self {
+                    #(Self::#variants => Self::#variants as i32,)*
+                }
+            }
+        }
+
+        impl ::foxglove::protobuf::ProtobufField for #ident {
+            fn field_type() -> ::foxglove::protobuf::FieldType {
+                ::foxglove::protobuf::FieldType::Enum
+            }
+
+            fn type_ref(
+                set: &mut ::foxglove::protobuf::DescriptorSet,
+            ) -> ::core::option::Option<::foxglove::protobuf::TypeRef> {
+                ::core::option::Option::Some(
+                    <Self as ::foxglove::protobuf::ProtobufEnum>::type_ref(set),
+                )
+            }
+
+            fn write_field(&self, number: u32, buf: &mut impl ::foxglove::protobuf::BufMut) {
+                let value = ::foxglove::protobuf::ProtobufEnum::value(self);
+                ::foxglove::protobuf::ProtobufField::write_field(&value, number, buf);
+            }
+
+            fn field_len(&self, number: u32) -> usize {
+                let value = ::foxglove::protobuf::ProtobufEnum::value(self);
+                ::foxglove::protobuf::ProtobufField::field_len(&value, number)
+            }
+        }
+
+        impl ::foxglove::protobuf::ProtobufRepeated for #ident {
+            fn write_repeated(
+                values: &[Self],
+                number: u32,
+                buf: &mut impl ::foxglove::protobuf::BufMut,
+            ) {
+                let values: ::std::vec::Vec<i32> = values
+                    .iter()
+                    .map(::foxglove::protobuf::ProtobufEnum::value)
+                    .collect();
+                ::foxglove::protobuf::ProtobufRepeated::write_repeated(&values, number, buf);
+            }
+
+            fn repeated_len(values: &[Self], number: u32) -> usize {
+                let values: ::std::vec::Vec<i32> = values
+                    .iter()
+                    .map(::foxglove::protobuf::ProtobufEnum::value)
+                    .collect();
+                ::foxglove::protobuf::ProtobufRepeated::repeated_len(&values, number)
+            }
+        }
+    })
+}
@@ -0,0 +1,51 @@
+//! Derive macro for `foxglove::point_cloud::PackedPoint`.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{Data, DeriveInput, Fields};
+
+/// Implements `PackedPoint` for a struct with named numeric fields.
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "PackedPoint cannot be derived for generic types",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "PackedPoint can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "PackedPoint can only be derived for structs with named fields",
+        ));
+    };
+
+    let ident = &input.ident;
+    let idents: Vec<_> = fields
+        .named
+        .iter()
+        .filter_map(|f| f.ident.as_ref())
+        .collect();
+    let names = idents.iter().map(|ident| ident.unraw().to_string());
+    let types = fields.named.iter().map(|f| &f.ty);
+
+    Ok(quote! {
+        impl ::foxglove::point_cloud::PackedPoint for #ident {
+            fn fields() -> ::std::vec::Vec<::foxglove::point_cloud::PointField> {
+                ::std::vec![
+                    #(::foxglove::point_cloud::PointField::new::<#types>(#names)),*
+                ]
+            }
+
+            fn write_point(&self, buf: &mut impl ::foxglove::protobuf::BufMut) {
+                #(::foxglove::point_cloud::PointFieldType::write_le(self.#idents, buf);)*
+            }
+        }
+    })
+}
@@ -0,0 +1,140 @@
+use syn::parse_quote;
+
+use super::*;
+
+/// Returns the error message for an input which cannot be derived.
+fn error(input: DeriveInput) -> String {
+    expand(&input).expect_err("Expected an error").to_string()
+}
+
+fn numbers(input: &DeriveInput) -> syn::Result<Vec<u32>> {
+    let Data::Struct(data) = &input.data else {
+        panic!("Expected a struct");
+    };
+    let fields: Vec<_> = data.fields.iter().collect();
+    field_numbers(&fields)
+}
+
+#[test]
+fn test_field_numbers() {
+    let input: DeriveInput = parse_quote! {
+        struct Reading {
+            a: u32,
+            #[foxglove(tag = 5)]
+            b: u32,
+            c: u32,
+            #[foxglove(tag = 2)]
+            d: u32,
+        }
+    };
+    assert_eq!(numbers(&input).unwrap(), [1, 5, 6, 2]);
+}
+
+#[test]
+fn test_invalid_field_numbers() {
+    let message = |input: DeriveInput| numbers(&input).expect_err("Expected an error").to_string();
+    assert_eq!(
+        message(parse_quote! {
+            struct Reading {
+                a: u32,
+                #[foxglove(tag = 1)]
+                b: u32,
+            }
+        }),
+        "field number 1 is used by more than one field"
+    );
+    assert_eq!(
+        message(parse_quote! {
+            struct Reading {
+                #[foxglove(tag = 0)]
+                a: u32,
+            }
+        }),
+        "field number must be between 1 and 536870911"
+    );
+    assert_eq!(
+        message(parse_quote! {
+            struct Reading {
+                #[foxglove(tag = 19000)]
+                a: u32,
+            }
+        }),
+        "field numbers 19000 to 19999 are reserved by protobuf"
+    );
+    assert_eq!(
+        message(parse_quote! {
+            struct Reading {
+                #[foxglove(tag = 536870911)]
+                a: u32,
+                b: u32,
+            }
+        }),
+        "field number is out of range; set it with #[foxglove(tag = N)]"
+    );
+    assert_eq!(
+        message(parse_quote! {
+            struct Reading {
+                #[foxglove(number = 1)]
+                a: u32,
+            }
+        }),
+        "unsupported foxglove attribute, expected `tag = N`"
+    );
+}
+
+#[test]
+fn test_invalid_inputs() {
+    assert_eq!(
+        error(parse_quote! {
+            struct Wrapper<T> {
+                value: T,
+            }
+        }),
+        "Encode cannot be derived for generic types"
+    );
+    assert_eq!(
+        error(parse_quote! {
+            struct Point(f64, f64);
+        }),
+        "Encode can only be derived for structs with named fields"
+    );
+    assert_eq!(
+        error(parse_quote! {
+            enum Shape {
+                Circle(f64),
+            }
+        }),
+        "Encode can only be derived for enums whose variants have no fields"
+    );
+}
+
+#[test]
+fn test_enum_first_variant() {
+    assert_eq!(
+        error(parse_quote! {
+            enum Status {
+                Ok = 1,
+                Degraded,
+            }
+        }),
+        "the first variant must have the value zero, the default value of a protobuf enum"
+    );
+    for input in [
+        parse_quote! {
+            enum Status {
+                Ok,
+                Degraded = 3,
+            }
+        },
+        parse_quote! {
+            enum Status {
+                Ok = 0,
+                Degraded,
+            }
+        },
+    ] {
+        let output = expand(&input).expect("Failed to expand").to_string();
+        // Discriminants which are not literals are checked when they are evaluated.
+        assert!(output.contains("const _ : () = :: core :: assert !"));
+    }
+}
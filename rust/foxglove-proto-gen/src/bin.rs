@@ -10,4 +10,44 @@ fn main() {
         .join("schemas");
 
     foxglove_proto_gen::generate_protos(&proto_path, &out_path).expect("Failed to generate protos");
+
+    let ros1_msg_path = workspace_root.join("schemas").join("ros1");
+    let ros1_out_path = workspace_root
+        .join("rust")
+        .join("foxglove")
+        .join("src")
+        .join("ros1");
+
+    foxglove_proto_gen::generate_ros1(&ros1_msg_path, &ros1_out_path)
+        .expect("Failed to generate ROS 1 encoders");
+
+    let ros2_msg_path = workspace_root.join("schemas").join("ros2");
+    let ros2_out_path = workspace_root
+        .join("rust")
+        .join("foxglove")
+        .join("src")
+        .join("ros2");
+
+    foxglove_proto_gen::generate_ros2(&ros2_msg_path, &ros2_out_path)
+        .expect("Failed to generate ROS 2 encoders");
+
+    let fbs_path = workspace_root.join("schemas").join("flatbuffer");
+    let fbs_out_path = workspace_root
+        .join("rust")
+        .join("foxglove")
+        .join("src")
+        .join("flatbuffer");
+
+    foxglove_proto_gen::generate_flatbuffers(&fbs_path, &fbs_out_path)
+        .expect("Failed to generate FlatBuffers encoders");
+
+    let json_schema_path = workspace_root.join("schemas").join("jsonschema");
+    let json_out_path = workspace_root
+        .join("rust")
+        .join("foxglove")
+        .join("src")
+        .join("json");
+
+    foxglove_proto_gen::generate_json(&json_schema_path, &json_out_path)
+        .expect("Failed to generate JSON schema impls");
 }
@@ -0,0 +1,225 @@
+//! Generates fluent setters for the scene and image annotation schemas.
+
+use std::{fmt::Write as _, fs, path::Path};
+
+use anyhow::Context;
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{DescriptorProto, FieldDescriptorProto, FileDescriptorSet};
+
+use crate::{camel_case_to_constant_case, rust_type_name};
+
+/// Messages for which setters are generated.
+const BUILDER_MESSAGES: &[&str] = &[
+    "ArrowPrimitive",
+    "CircleAnnotation",
+    "CubePrimitive",
+    "CylinderPrimitive",
+    "ImageAnnotations",
+    "LinePrimitive",
+    "ModelPrimitive",
+    "PointsAnnotation",
+    "SceneEntity",
+    "SceneEntityDeletion",
+    "SceneUpdate",
+    "SpherePrimitive",
+    "TextAnnotation",
+    "TextPrimitive",
+    "TriangleListPrimitive",
+];
+
+/// Returns the Rust type for a scalar field, or `None` if the field is not a scalar.
+fn scalar_type(field: &FieldDescriptorProto) -> Option<&'static str> {
+    match field.r#type() {
+        Type::Double => Some("f64"),
+        Type::Float => Some("f32"),
+        Type::Int32 | Type::Sint32 | Type::Sfixed32 => Some("i32"),
+        Type::Int64 | Type::Sint64 | Type::Sfixed64 => Some("i64"),
+        Type::Uint32 | Type::Fixed32 => Some("u32"),
+        Type::Uint64 | Type::Fixed64 => Some("u64"),
+        Type::Bool => Some("bool"),
+        _ => None,
+    }
+}
+
+/// Returns the Rust path of a message or enum type, relative to `crate::schemas`.
+fn type_path(type_name: &str) -> anyhow::Result<String> {
+    match type_name {
+        ".google.protobuf.Timestamp" => return Ok("Timestamp".to_string()),
+        ".google.protobuf.Duration" => return Ok("Duration".to_string()),
+        _ => (),
+    }
+    let Some(path) = type_name.strip_prefix(".foxglove.") else {
+        anyhow::bail!("Unexpected type {type_name}");
+    };
+    // Nested types live in a module named after the snake-cased parent message.
+    Ok(match path.split_once('.') {
+        Some((parent, name)) => format!(
+            "{}::{name}",
+            camel_case_to_constant_case(parent).to_lowercase()
+        ),
+        None => rust_type_name(path).to_string(),
+    })
+}
+
+/// Writes a setter for a field.
+fn write_setter(out: &mut String, field: &FieldDescriptorProto) -> anyhow::Result<()> {
+    let name = field.name();
+    let (ident, param) = if name == "type" {
+        ("r#type", "value")
+    } else {
+        (name, name)
+    };
+    let repeated = field.label() == Label::Repeated;
+    // Intra-doc links cannot refer to raw identifiers.
+    let link = if ident == name {
+        format!("[`{name}`](Self::{name})")
+    } else {
+        format!("`{name}`")
+    };
+    if repeated {
+        writeln!(out, "    /// Sets {link}, replacing any existing values.")?;
+    } else {
+        writeln!(out, "    /// Sets {link}.")?;
+    }
+    out.push_str("    #[must_use]\n");
+
+    let (signature, body) = match (field.r#type(), repeated) {
+        (Type::Message, false) => (
+            format!("{param}: impl Into<{}>", type_path(field.type_name())?),
+            format!("self.{ident} = Some({param}.into());"),
+        ),
+        (Type::Message, true) => {
+            writeln!(
+                out,
+                "    pub fn with_{name}<I>(mut self, {param}: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<{}>,
+    {{
+        self.{ident} = {param}.into_iter().map(Into::into).collect();
+        self
+    }}
+",
+                type_path(field.type_name())?
+            )?;
+            return Ok(());
+        }
+        (Type::Enum, false) => (
+            format!("{param}: {}", type_path(field.type_name())?),
+            format!("self.set_{name}({param});"),
+        ),
+        (Type::String, false) => (
+            format!("{param}: impl Into<String>"),
+            format!("self.{ident} = {param}.into();"),
+        ),
+        (Type::Bytes, false) => (
+            format!("{param}: impl Into<Bytes>"),
+            format!("self.{ident} = {param}.into();"),
+        ),
+        (_, false) => (
+            format!(
+                "{param}: {}",
+                scalar_type(field).context("Unsupported field type")?
+            ),
+            format!("self.{ident} = {param};"),
+        ),
+        (_, true) => (
+            format!(
+                "{param}: impl IntoIterator<Item = {}>",
+                scalar_type(field).context("Unsupported repeated field type")?
+            ),
+            format!("self.{ident} = {param}.into_iter().collect();"),
+        ),
+    };
+    writeln!(
+        out,
+        "    pub fn with_{name}(mut self, {signature}) -> Self {{
+        {body}
+        self
+    }}
+"
+    )?;
+    Ok(())
+}
+
+/// Writes the setters for a message.
+fn write_message(out: &mut String, message: &DescriptorProto) -> anyhow::Result<()> {
+    writeln!(out, "\nimpl {} {{", rust_type_name(message.name()))?;
+    for field in &message.field {
+        write_setter(out, field)
+            .with_context(|| format!("Failed to generate setter for {}", field.name()))?;
+    }
+    // Remove the blank line after the last setter.
+    out.truncate(out.trim_end().len());
+    out.push_str("\n}\n");
+    Ok(())
+}
+
+/// Generates `with_*` setters for the scene and image annotation messages.
+///
+/// Each setter takes the message by value and returns it, so that messages can be built fluently
+/// from their defaults.
+pub(crate) fn generate_builders(out_dir: &Path, fds: &FileDescriptorSet) -> anyhow::Result<()> {
+    let mut out = String::new();
+    out.push_str("// This file is @generated by foxglove-proto-gen\n");
+    out.push_str("use crate::schemas::*;\n");
+    out.push_str("use bytes::Bytes;\n");
+
+    for fd in &fds.file {
+        if fd.package() != "foxglove" {
+            continue;
+        }
+        for message in &fd.message_type {
+            if BUILDER_MESSAGES.contains(&message.name()) {
+                write_message(&mut out, message)
+                    .with_context(|| format!("Failed to generate {}", message.name()))?;
+            }
+        }
+    }
+
+    fs::write(out_dir.join("builders.rs"), out).context("Failed to write builders.rs")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    use prost::Message;
+
+    use super::*;
+
+    /// Regenerating the builders from the committed descriptors must reproduce the committed file.
+    #[test]
+    fn test_builders_up_to_date() {
+        let schemas_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../foxglove/src/schemas")
+            .canonicalize()
+            .expect("Failed to find schemas directory");
+
+        // Each descriptor includes its dependencies, so deduplicate the files by name.
+        let mut files = BTreeMap::new();
+        for entry in fs::read_dir(schemas_dir.join("data")).expect("Failed to read descriptors") {
+            let bin = fs::read(entry.expect("Failed to read entry").path())
+                .expect("Failed to read descriptor");
+            let fds = FileDescriptorSet::decode(bin.as_slice()).expect("Invalid descriptor");
+            for fd in fds.file {
+                files.insert(fd.name().to_string(), fd);
+            }
+        }
+        let fds = FileDescriptorSet {
+            file: files.into_values().collect(),
+        };
+
+        let out_dir = tempfile::tempdir().expect("Failed to create tempdir");
+        generate_builders(out_dir.path(), &fds).expect("Failed to generate builders");
+        let generated =
+            fs::read_to_string(out_dir.path().join("builders.rs")).expect("Failed to read output");
+        let committed = fs::read_to_string(schemas_dir.join("builders.rs"))
+            .expect("Failed to read builders.rs");
+        assert!(
+            generated == committed,
+            "builders.rs is out of date; run foxglove-proto-gen to regenerate it"
+        );
+    }
+}
@@ -0,0 +1,326 @@
+//! Generates binary FlatBuffers schemas and serialization for FlatBuffers schema definitions.
+
+use std::{
+    collections::BTreeSet,
+    env,
+    fmt::Write as _,
+    fs::{self, File},
+    io::Write,
+    path::Path,
+    process::Command,
+};
+
+use anyhow::{bail, Context};
+
+/// Schema files that don't define a message type.
+const SUPPORT_FILES: &[&str] = &["ByteVector", "Duration", "Time"];
+
+/// A field in a FlatBuffers table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Field {
+    name: String,
+    ty: String,
+    default: Option<String>,
+    id: u16,
+}
+
+/// A FlatBuffers table definition.
+#[derive(Debug)]
+struct Table {
+    name: String,
+    fields: Vec<Field>,
+}
+
+/// Parses the tables and enum names in a FlatBuffers schema.
+fn parse_schema(source: &str) -> anyhow::Result<(Vec<Table>, BTreeSet<String>)> {
+    let mut tables = vec![];
+    let mut enums = BTreeSet::new();
+    let mut current: Option<Table> = None;
+    for line in source.lines() {
+        let line = line.trim();
+        if line.starts_with("//") {
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("enum ") {
+            let name = rest.split([' ', ':']).next().unwrap_or_default();
+            enums.insert(name.to_string());
+        } else if let Some(rest) = line.strip_prefix("table ") {
+            let name = rest.trim_end_matches('{').trim();
+            current = Some(Table {
+                name: name.to_string(),
+                fields: vec![],
+            });
+        } else if line == "}" {
+            tables.extend(current.take());
+        } else if let (Some(table), Some((decl, id))) = (current.as_mut(), line.split_once("(id:"))
+        {
+            let Some((name, ty)) = decl.split_once(':') else {
+                bail!("Invalid field: {line}");
+            };
+            let (ty, default) = match ty.split_once('=') {
+                Some((ty, default)) => (ty, Some(default.trim().to_string())),
+                None => (ty, None),
+            };
+            let id = id
+                .trim_end_matches(';')
+                .trim_end_matches(')')
+                .trim()
+                .parse()
+                .with_context(|| format!("Invalid field id: {line}"))?;
+            table.fields.push(Field {
+                name: name.trim().to_string(),
+                ty: ty.trim().to_string(),
+                default,
+                id,
+            });
+        }
+    }
+    Ok((tables, enums))
+}
+
+/// Returns the Rust type that corresponds to a FlatBuffers table.
+fn rust_type_name(name: &str) -> &str {
+    match name {
+        "GeoJSON" => "GeoJson",
+        name => name,
+    }
+}
+
+/// Returns the Rust identifier for a table field.
+fn field_ident(name: &str) -> &str {
+    match name {
+        "type" => "r#type",
+        name => name,
+    }
+}
+
+/// Generates the body of `FlatBufferEncode::build_flatbuffer` for a table.
+///
+/// Strings, vectors and nested tables must be created before the table is started, so the body
+/// is generated in two parts.
+fn generate_build_body(table: &Table, enums: &BTreeSet<String>) -> anyhow::Result<String> {
+    let mut children = String::new();
+    let mut slots = String::new();
+    for field in &table.fields {
+        let name = &field.name;
+        let ident = field_ident(name);
+        let slot = 4 + 2 * field.id;
+        let child = match field.ty.as_str() {
+            "string" => Some(format!("fbb.create_string(&self.{ident})")),
+            "[double]" | "[uint32]" | "[uint8]" => {
+                Some(format!("fbb.create_vector(&self.{ident}[..])"))
+            }
+            ty if ty.starts_with("[foxglove.") => {
+                Some(format!("build_tables(fbb, &self.{ident})?"))
+            }
+            ty if ty.starts_with("foxglove.") => {
+                Some(format!("build_table(fbb, self.{ident}.as_ref())?"))
+            }
+            _ => None,
+        };
+        let slot = match (field.ty.as_str(), child) {
+            (_, Some(child)) => {
+                writeln!(children, "        let {name} = {child};")?;
+                format!("fbb.push_slot_always({slot}, {name});")
+            }
+            // Scalars equal to the schema default are omitted, so the default must match the
+            // schema.
+            ("double", None) => {
+                let default = field.default.as_deref().unwrap_or("0.0");
+                format!("fbb.push_slot::<f64>({slot}, self.{ident}, {default});")
+            }
+            ("uint32", None) => {
+                let default = field.default.as_deref().unwrap_or("0");
+                format!("fbb.push_slot::<u32>({slot}, self.{ident}, {default});")
+            }
+            ("bool", None) => {
+                let default = field.default.as_deref().unwrap_or("false");
+                format!("fbb.push_slot::<bool>({slot}, self.{ident}, {default});")
+            }
+            ("Time", None) => {
+                format!("fbb.push_slot_always({slot}, Time::from(self.{ident}.as_ref()));")
+            }
+            ("Duration", None) => {
+                format!("fbb.push_slot_always({slot}, Duration::from(self.{ident}.as_ref()));")
+            }
+            (ty, None) if enums.contains(ty) => {
+                format!("fbb.push_slot::<u8>({slot}, enum_value(\"{name}\", self.{ident})?, 0);")
+            }
+            (ty, None) => bail!("Unsupported field type: {ty}"),
+        };
+        writeln!(slots, "        {slot}")?;
+    }
+    Ok(format!(
+        "{children}        let start = fbb.start_table();\n{slots}        Ok(fbb.end_table(start))\n"
+    ))
+}
+
+/// Compiles a FlatBuffers schema to a binary schema (`.bfbs`) with `flatc`.
+///
+/// The `flatc` binary is located using the `FLATC` environment variable, or from the `PATH`.
+fn compile_binary_schema(fbs_path: &Path, file: &Path, out_dir: &Path) -> anyhow::Result<()> {
+    let flatc = env::var_os("FLATC").unwrap_or_else(|| "flatc".into());
+    let status = Command::new(&flatc)
+        .arg("--binary")
+        .arg("--schema")
+        .arg("-I")
+        .arg(fbs_path)
+        .arg("-o")
+        .arg(out_dir)
+        .arg(file)
+        .status()
+        .with_context(|| {
+            format!(
+                "Failed to run {}. Install flatc, or set the FLATC environment variable.",
+                flatc.to_string_lossy()
+            )
+        })?;
+    if !status.success() {
+        bail!("flatc failed for {}: {status}", file.display());
+    }
+    Ok(())
+}
+
+/// Generates binary schemas and `FlatBufferEncode` impls for each FlatBuffers schema.
+pub fn generate_flatbuffers(fbs_path: &Path, out_dir: &Path) -> anyhow::Result<()> {
+    let data_dir = out_dir.join("data");
+    if data_dir.exists() {
+        fs::remove_dir_all(&data_dir).context("Failed to remove data directory")?;
+    }
+    fs::create_dir_all(&data_dir).context("Failed to create data directory")?;
+
+    let mut module = File::create(out_dir.join("impls.rs")).context("Failed to create impls.rs")?;
+    let mut result = writeln!(module, "// This file is @generated by foxglove-proto-gen");
+    result = result.and(writeln!(
+        module,
+        "use super::{{build_table, build_tables, enum_value, Duration, EncodeError, FlatBufferEncode, Time}};"
+    ));
+    result = result.and(writeln!(module, "use crate::schemas::*;"));
+    result = result.and(writeln!(
+        module,
+        "use crate::validate::{{validate, ValidationError}};"
+    ));
+    result = result.and(writeln!(
+        module,
+        "use flatbuffers::{{FlatBufferBuilder, TableFinishedWIPOffset, WIPOffset}};"
+    ));
+    result.context("Failed to write impls.rs")?;
+
+    let mut entries = fs::read_dir(fbs_path)
+        .context("Failed to read flatbuffer directory")?
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|e| e.file_name());
+    for entry in entries {
+        let path = entry.path();
+        let Some(stem) = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .filter(|_| path.extension().is_some_and(|ext| ext == "fbs"))
+            .filter(|stem| !SUPPORT_FILES.contains(stem))
+        else {
+            continue;
+        };
+
+        compile_binary_schema(fbs_path, &path, &data_dir)
+            .with_context(|| format!("Failed to compile {}", path.display()))?;
+
+        let source = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let (tables, enums) =
+            parse_schema(&source).with_context(|| format!("Failed to parse {}", path.display()))?;
+        let Some(table) = tables.iter().find(|t| t.name == stem) else {
+            bail!("No table named {stem} in {}", path.display());
+        };
+
+        let rust_name = rust_type_name(stem);
+        let body = generate_build_body(table, &enums)
+            .with_context(|| format!("Failed to generate encoder for {stem}"))?;
+        writeln!(
+            module,
+            "\nimpl FlatBufferEncode for {rust_name} {{
+    const TYPE_NAME: &'static str = \"foxglove.{stem}\";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!(\"data/{stem}.bfbs\");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {{
+{body}    }}
+
+    fn validate(&self) -> Result<(), ValidationError> {{
+        validate(self)
+    }}
+}}"
+        )
+        .context("Failed to write trait impl in impls.rs")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_schema() {
+        let source = "// Generated by https://github.com/foxglove/foxglove-sdk
+
+include \"Time.fbs\";
+
+namespace foxglove;
+
+/// Log level
+enum LogLevel : ubyte {
+  UNKNOWN = 0,
+}
+/// A log message
+table Log {
+  /// Timestamp of log message
+  timestamp:Time (id: 0);
+
+  /// Log level
+  level:LogLevel (id: 1);
+
+  points:[foxglove.Point3] (id: 2);
+
+  scale:double = 1.0 (id: 3);
+}
+
+root_type Log;
+";
+        let (tables, enums) = parse_schema(source).unwrap();
+        assert_eq!(enums.into_iter().collect::<Vec<_>>(), ["LogLevel"]);
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].name, "Log");
+        assert_eq!(
+            tables[0].fields,
+            [
+                Field {
+                    name: "timestamp".into(),
+                    ty: "Time".into(),
+                    default: None,
+                    id: 0
+                },
+                Field {
+                    name: "level".into(),
+                    ty: "LogLevel".into(),
+                    default: None,
+                    id: 1
+                },
+                Field {
+                    name: "points".into(),
+                    ty: "[foxglove.Point3]".into(),
+                    default: None,
+                    id: 2
+                },
+                Field {
+                    name: "scale".into(),
+                    ty: "double".into(),
+                    default: Some("1.0".into()),
+                    id: 3
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! Generates JSON schema impls for the JSON schema definitions.
+
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
+
+use anyhow::Context;
+
+/// Returns the Rust type that corresponds to a JSON schema.
+fn rust_type_name(name: &str) -> &str {
+    match name {
+        "GeoJSON" => "GeoJson",
+        name => name,
+    }
+}
+
+/// Copies each JSON schema, and generates `JsonEncode` impls for the corresponding types.
+pub fn generate_json(schema_path: &Path, out_dir: &Path) -> anyhow::Result<()> {
+    let data_dir = out_dir.join("data");
+    if data_dir.exists() {
+        fs::remove_dir_all(&data_dir).context("Failed to remove data directory")?;
+    }
+    fs::create_dir_all(&data_dir).context("Failed to create data directory")?;
+
+    let mut module = File::create(out_dir.join("impls.rs")).context("Failed to create impls.rs")?;
+    let mut result = writeln!(module, "// This file is @generated by foxglove-proto-gen");
+    result = result.and(writeln!(module, "use super::JsonEncode;"));
+    result = result.and(writeln!(module, "use crate::schemas::*;"));
+    result = result.and(writeln!(
+        module,
+        "use crate::validate::{{validate, ValidationError}};"
+    ));
+    result.context("Failed to write impls.rs")?;
+
+    let mut entries = fs::read_dir(schema_path)
+        .context("Failed to read JSON schema directory")?
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|e| e.file_name());
+    for entry in entries {
+        let path = entry.path();
+        let Some(stem) = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .filter(|_| path.extension().is_some_and(|ext| ext == "json"))
+        else {
+            continue;
+        };
+
+        fs::copy(&path, data_dir.join(format!("{stem}.json")))
+            .with_context(|| format!("Failed to copy {}", path.display()))?;
+
+        let rust_name = rust_type_name(stem);
+        writeln!(
+            module,
+            "\nimpl JsonEncode for {rust_name} {{
+    const TYPE_NAME: &'static str = \"foxglove.{stem}\";
+    const JSON_SCHEMA: &'static str = include_str!(\"data/{stem}.json\");
+
+    fn validate(&self) -> Result<(), ValidationError> {{
+        validate(self)
+    }}
+}}"
+        )
+        .context("Failed to write trait impl in impls.rs")?;
+    }
+
+    Ok(())
+}
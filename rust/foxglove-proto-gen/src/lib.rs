@@ -7,10 +7,20 @@ use std::{
 
 use anyhow::Context;
 use prost::Message;
-use prost_types::{FileDescriptorProto, FileDescriptorSet};
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{DescriptorProto, FileDescriptorProto, FileDescriptorSet};
 use tempfile::NamedTempFile;
 use walkdir::WalkDir;
 
+mod builders;
+mod flatbuffers;
+mod json;
+mod ros;
+
+pub use flatbuffers::generate_flatbuffers;
+pub use json::generate_json;
+pub use ros::{generate_ros1, generate_ros2};
+
 /// Recursively builds a file descriptor set for a file descriptor and its dependencies.
 fn build_fds(
     fd: &FileDescriptorProto,
@@ -128,12 +138,29 @@ fn generate_impls(out_dir: &Path, fds: &FileDescriptorSet) -> anyhow::Result<()>
         module,
         "use crate::schemas::{{descriptors, foxglove::*}};"
     ));
+    result = result.and(writeln!(
+        module,
+        "use crate::protobuf::{{DescriptorSet, ProtobufMessage, TypeRef}};"
+    ));
+    result = result.and(writeln!(
+        module,
+        "use crate::validate::{{Validate, ValidationError, Validator}};"
+    ));
     result = result.and(writeln!(module, "use crate::{{Schema, Encode}};"));
     result = result.and(writeln!(module, "use bytes::BufMut;"));
     result.context("Failed to write impls.rs")?;
 
     for fd in &fds.file {
-        let Some(mut name) = fd
+        if fd.package() == "foxglove" {
+            for message in &fd.message_type {
+                write_validate_impl(&mut module, message)
+                    .context("Failed to write Validate impl in impls.rs")?;
+            }
+        }
+    }
+
+    for fd in &fds.file {
+        let Some(proto_name) = fd
             .name
             .as_ref()
             .and_then(|n| n.strip_prefix("foxglove/"))
@@ -142,6 +169,7 @@ fn generate_impls(out_dir: &Path, fds: &FileDescriptorSet) -> anyhow::Result<()>
         else {
             continue;
         };
+        let mut name = proto_name;
         // Special case for GeoJSON casing
         if name == "GeoJSON" {
             name = "GeoJson";
@@ -169,6 +197,25 @@ fn generate_impls(out_dir: &Path, fds: &FileDescriptorSet) -> anyhow::Result<()>
     }}
 
     fn encoded_len(&self) -> Option<usize> {{ Some(::prost::Message::encoded_len(self)) }}
+
+    fn validate(&self) -> Result<(), ValidationError> {{
+        crate::validate::validate(self)
+    }}
+}}
+
+impl ProtobufMessage for {name} {{
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {{
+        set.add_encoded_files(descriptors::{descriptor_name});
+        TypeRef::new(\"foxglove.{proto_name}\", \"foxglove/{proto_name}.proto\")
+    }}
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {{
+        ::prost::Message::encode_raw(self, buf)
+    }}
+
+    fn encoded_len(&self) -> usize {{
+        ::prost::Message::encoded_len(self)
+    }}
 }}"
         )
         .context("Failed to write trait impl in impls.rs")?;
@@ -177,6 +224,125 @@ fn generate_impls(out_dir: &Path, fds: &FileDescriptorSet) -> anyhow::Result<()>
     Ok(())
 }
 
+/// Messages with hand-written invariants, implemented as `check_invariants` in
+/// `foxglove::validate`.
+const MESSAGES_WITH_INVARIANTS: &[&str] = &[
+    "CameraCalibration",
+    "CompressedImage",
+    "FrameTransform",
+    "GeoJSON",
+    "Grid",
+    "LaserScan",
+    "LocationFix",
+    "PointCloud",
+    "Quaternion",
+    "RawImage",
+    "SceneEntity",
+];
+
+/// Returns the Rust type name for a foxglove message.
+fn rust_type_name(proto_name: &str) -> &str {
+    // Special case for GeoJSON casing
+    if proto_name == "GeoJSON" {
+        "GeoJson"
+    } else {
+        proto_name
+    }
+}
+
+/// Writes an implementation of `Validate` for a foxglove message, which checks the message's own
+/// invariants, and recurses into its nested foxglove messages.
+fn write_validate_impl(module: &mut File, message: &DescriptorProto) -> io::Result<()> {
+    let proto_name = message.name();
+    let name = rust_type_name(proto_name);
+    let mut body = Vec::new();
+    if MESSAGES_WITH_INVARIANTS.contains(&proto_name) {
+        body.push("self.check_invariants(validator);".to_string());
+    }
+    for field in &message.field {
+        if field.r#type() != Type::Message {
+            continue;
+        }
+        // Well-known types such as Timestamp and Duration have no invariants.
+        if field.type_name().strip_prefix(".foxglove.").is_none() {
+            continue;
+        }
+        let field_name = field.name();
+        let ident = if field_name == "type" {
+            "r#type"
+        } else {
+            field_name
+        };
+        if field.label() == Label::Repeated {
+            body.push(format!(
+                "validator.messages(\"{field_name}\", &self.{ident});"
+            ));
+        } else {
+            body.push(format!(
+                "validator.message(\"{field_name}\", self.{ident}.as_ref());"
+            ));
+        }
+    }
+
+    if body.is_empty() {
+        return writeln!(module, "\nimpl Validate for {name} {{}}");
+    }
+    writeln!(module, "\nimpl Validate for {name} {{")?;
+    writeln!(
+        module,
+        "    fn validate_fields(&self, validator: &mut Validator) {{"
+    )?;
+    for line in body {
+        writeln!(module, "        {line}")?;
+    }
+    writeln!(module, "    }}\n}}")
+}
+
+/// Wraps an attribute so that it only applies when the `serde` feature is enabled.
+fn serde_attribute(attr: &str) -> String {
+    format!("#[cfg_attr(feature = \"serde\", {attr})]")
+}
+
+/// Configures serde derives for the generated types, behind the `serde` feature.
+///
+/// The serialized representation matches the JSON schemas: field names are the same as in the
+/// schema definitions, bytes are base64-encoded strings, and absent messages are omitted.
+fn configure_serde(config: &mut prost_build::Config, fds: &FileDescriptorSet) {
+    let derive = serde_attribute("derive(serde::Serialize, serde::Deserialize)");
+    config.message_attribute(".", &derive);
+    config.message_attribute(".", serde_attribute("serde(default)"));
+    config.enum_attribute(".", &derive);
+
+    for fd in &fds.file {
+        for message in &fd.message_type {
+            let message_path = format!(".{}.{}", fd.package(), message.name());
+            for field in &message.field {
+                let path = format!("{message_path}.{}", field.name());
+                // prost converts field names to snake case.
+                if field.name().chars().any(|c| c.is_ascii_uppercase()) {
+                    let rename = format!("serde(rename = \"{}\")", field.name());
+                    config.field_attribute(&path, serde_attribute(&rename));
+                }
+                match (field.r#type(), field.label()) {
+                    (Type::Bytes, _) => {
+                        config.field_attribute(
+                            &path,
+                            serde_attribute("serde(with = \"crate::schemas_wkt::serde::bytes\")"),
+                        );
+                    }
+                    (Type::Message, Label::Optional) => {
+                        config.field_attribute(
+                            &path,
+                            serde_attribute("serde(skip_serializing_if = \"Option::is_none\")"),
+                        );
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+}
+
 /// Generates protobuf structs and descriptors.
 pub fn generate_protos(proto_path: &Path, out_dir: &Path) -> anyhow::Result<()> {
     let proto_path = fs::canonicalize(proto_path).context("Failed to canonicalize proto path")?;
@@ -213,6 +379,10 @@ pub fn generate_protos(proto_path: &Path, out_dir: &Path) -> anyhow::Result<()>
 
     generate_impls(out_dir, &fds).context("Failed to generate impls")?;
 
+    builders::generate_builders(out_dir, &fds).context("Failed to generate builders")?;
+
+    configure_serde(&mut config, &fds);
+
     config
         .compile_fds(fds)
         .context("Failed to compile protos")?;
@@ -0,0 +1,396 @@
+//! Generates ROS 1 and ROS 2 (CDR) serialization for ROS message definitions.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
+
+use anyhow::{bail, Context};
+
+/// Separator between message definitions in a merged `ros1msg` or `ros2msg` schema.
+const SEPARATOR: &str =
+    "================================================================================";
+
+/// Definitions of the `geometry_msgs` types used by Foxglove schemas, in the form produced by the
+/// schema generator.
+const GEOMETRY_MSGS: &[(&str, &str)] = &[
+    ("Point", "float64 x\nfloat64 y\nfloat64 z\n"),
+    (
+        "Pose",
+        "geometry_msgs/Point position\ngeometry_msgs/Quaternion orientation\n",
+    ),
+    ("Quaternion", "float64 x\nfloat64 y\nfloat64 z\nfloat64 w\n"),
+    ("Vector3", "float64 x\nfloat64 y\nfloat64 z\n"),
+];
+
+/// The ROS version to generate code for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RosVersion {
+    Ros1,
+    Ros2,
+}
+
+impl RosVersion {
+    /// Returns the fully-qualified name for a message type.
+    fn full_name(self, ros_name: &str) -> String {
+        match self {
+            RosVersion::Ros1 => ros_name.to_string(),
+            RosVersion::Ros2 => ros_name.replacen('/', "/msg/", 1),
+        }
+    }
+
+    /// Returns the names of the encoding trait, its method, and the writer type.
+    fn encoder_names(self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            RosVersion::Ros1 => ("Ros1Encode", "encode_ros1", "Ros1Writer"),
+            RosVersion::Ros2 => ("CdrEncode", "encode_cdr", "CdrWriter"),
+        }
+    }
+}
+
+/// The array size of a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArrayKind {
+    Unbounded,
+    Fixed(usize),
+}
+
+/// A field in a ROS message definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Field {
+    ty: String,
+    array: Option<ArrayKind>,
+    name: String,
+}
+
+/// A ROS message definition.
+#[derive(Debug)]
+struct MessageDef {
+    /// Package-qualified name, as used to refer to the message in other definitions.
+    name: String,
+    /// The source text of the definition.
+    source: String,
+    fields: Vec<Field>,
+}
+
+/// Parses the fields of a message definition, ignoring comments and constants.
+fn parse_fields(source: &str) -> anyhow::Result<Vec<Field>> {
+    let mut fields = vec![];
+    for line in source.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() || line.contains('=') {
+            continue;
+        }
+        let Some((ty, name)) = line.split_once(char::is_whitespace) else {
+            bail!("Invalid field: {line}");
+        };
+        let (ty, array) = match ty.split_once('[') {
+            None => (ty, None),
+            Some((ty, "]")) => (ty, Some(ArrayKind::Unbounded)),
+            Some((ty, len)) => {
+                let len = len
+                    .strip_suffix(']')
+                    .and_then(|len| len.parse().ok())
+                    .with_context(|| format!("Invalid array length: {line}"))?;
+                (ty, Some(ArrayKind::Fixed(len)))
+            }
+        };
+        fields.push(Field {
+            ty: ty.to_string(),
+            array,
+            name: name.trim().to_string(),
+        });
+    }
+    Ok(fields)
+}
+
+/// Returns the Rust type that corresponds to a ROS message type.
+fn rust_type_name(ros_name: &str) -> anyhow::Result<&str> {
+    match ros_name.split_once('/') {
+        Some(("foxglove_msgs", "GeoJSON")) => Ok("GeoJson"),
+        Some(("geometry_msgs", "Point")) => Ok("Point3"),
+        Some(("foxglove_msgs" | "geometry_msgs", name)) => Ok(name),
+        _ => bail!("Unsupported message type: {ros_name}"),
+    }
+}
+
+/// Returns the Rust identifier for a message field.
+fn field_ident(name: &str) -> String {
+    match name.to_lowercase().as_str() {
+        "type" => "r#type".to_string(),
+        name => name.to_string(),
+    }
+}
+
+/// Collects the dependencies of a message, depth-first and without duplicates.
+fn collect_dependencies<'a>(
+    def: &'a MessageDef,
+    defs: &'a BTreeMap<String, MessageDef>,
+    deps: &mut Vec<&'a MessageDef>,
+) {
+    for field in &def.fields {
+        let Some(dep) = defs.get(&field.ty) else {
+            continue;
+        };
+        if !deps.iter().any(|d| d.name == dep.name) {
+            deps.push(dep);
+        }
+        collect_dependencies(dep, defs, deps);
+    }
+}
+
+/// Builds the merged schema for a message, which includes all of its dependencies.
+fn merged_definition(def: &MessageDef, defs: &BTreeMap<String, MessageDef>) -> String {
+    let mut deps = vec![];
+    collect_dependencies(def, defs, &mut deps);
+    let mut result = def.source.clone();
+    for dep in deps {
+        result.push_str(&format!("{SEPARATOR}\nMSG: {}\n{}", dep.name, dep.source));
+    }
+    result
+}
+
+/// Generates the body of the encoding method for a message.
+fn generate_encode_body(def: &MessageDef, version: RosVersion) -> anyhow::Result<String> {
+    let (_, encode_fn, _) = version.encoder_names();
+    let mut body = String::new();
+    for field in &def.fields {
+        let name = &field.name;
+        let ident = field_ident(name);
+        let stmt = match (field.ty.as_str(), field.array) {
+            ("bool", None) => format!("w.write_bool(self.{ident});"),
+            ("float64", None) => format!("w.write_f64(self.{ident});"),
+            ("uint32", None) => format!("w.write_u32(self.{ident});"),
+            ("string", None) => format!("w.write_string(&self.{ident})?;"),
+            // Foxglove schemas only use scalar uint8 fields for enums.
+            ("uint8", None) => format!("w.write_enum(\"{name}\", self.{ident})?;"),
+            ("uint8", Some(ArrayKind::Unbounded)) => format!("w.write_bytes(&self.{ident})?;"),
+            ("time" | "builtin_interfaces/Time", None) => {
+                format!("w.write_time(\"{name}\", self.{ident}.as_ref())?;")
+            }
+            ("duration" | "builtin_interfaces/Duration", None) => {
+                format!("w.write_duration(self.{ident}.as_ref());")
+            }
+            (ty @ ("float64" | "uint32"), Some(array)) => {
+                let len = match array {
+                    ArrayKind::Unbounded => format!("w.write_len(self.{ident}.len())?;"),
+                    ArrayKind::Fixed(n) => {
+                        format!("w.check_array_len(\"{name}\", self.{ident}.len(), {n})?;")
+                    }
+                };
+                let write = if ty == "float64" {
+                    "write_f64"
+                } else {
+                    "write_u32"
+                };
+                format!(
+                    "{len}
+        for v in &self.{ident} {{
+            w.{write}(*v);
+        }}"
+                )
+            }
+            (ty, None) => {
+                rust_type_name(ty)?;
+                format!("w.write_message(self.{ident}.as_ref())?;")
+            }
+            (ty, Some(ArrayKind::Unbounded)) => {
+                rust_type_name(ty)?;
+                format!(
+                    "w.write_len(self.{ident}.len())?;
+        for v in &self.{ident} {{
+            v.{encode_fn}(w)?;
+        }}"
+                )
+            }
+            (ty, Some(ArrayKind::Fixed(_))) => bail!("Unsupported fixed-size array of {ty}"),
+        };
+        writeln!(body, "        {stmt}")?;
+    }
+    Ok(body)
+}
+
+/// Loads the ROS message definitions for Foxglove schemas and the `geometry_msgs` types they
+/// depend on.
+fn load_definitions(msg_path: &Path) -> anyhow::Result<BTreeMap<String, MessageDef>> {
+    let mut defs = BTreeMap::new();
+    for (name, fields) in GEOMETRY_MSGS {
+        let name = format!("geometry_msgs/{name}");
+        let source = format!(
+            "# {name}\n\n# Generated by https://github.com/foxglove/foxglove-sdk\n\n{fields}"
+        );
+        let fields = parse_fields(&source)?;
+        defs.insert(
+            name.clone(),
+            MessageDef {
+                name,
+                source,
+                fields,
+            },
+        );
+    }
+
+    let mut entries = fs::read_dir(msg_path)
+        .context("Failed to read msg directory")?
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|e| e.file_name());
+    for entry in entries {
+        let path = entry.path();
+        let Some(stem) = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .filter(|_| path.extension().is_some_and(|ext| ext == "msg"))
+        else {
+            continue;
+        };
+        let source = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let fields =
+            parse_fields(&source).with_context(|| format!("Failed to parse {}", path.display()))?;
+        let name = format!("foxglove_msgs/{stem}");
+        defs.insert(
+            name.clone(),
+            MessageDef {
+                name,
+                source,
+                fields,
+            },
+        );
+    }
+    Ok(defs)
+}
+
+/// Generates `Ros1Encode` impls and merged `ros1msg` schemas for each ROS 1 message definition.
+pub fn generate_ros1(msg_path: &Path, out_dir: &Path) -> anyhow::Result<()> {
+    generate_ros(RosVersion::Ros1, msg_path, out_dir)
+}
+
+/// Generates `CdrEncode` impls and merged `ros2msg` schemas for each ROS 2 message definition.
+pub fn generate_ros2(msg_path: &Path, out_dir: &Path) -> anyhow::Result<()> {
+    generate_ros(RosVersion::Ros2, msg_path, out_dir)
+}
+
+fn generate_ros(version: RosVersion, msg_path: &Path, out_dir: &Path) -> anyhow::Result<()> {
+    let defs = load_definitions(msg_path)?;
+    let (encode_trait, encode_fn, writer) = version.encoder_names();
+
+    let data_dir = out_dir.join("data");
+    if data_dir.exists() {
+        fs::remove_dir_all(&data_dir).context("Failed to remove data directory")?;
+    }
+    fs::create_dir_all(&data_dir).context("Failed to create data directory")?;
+
+    let mut module = File::create(out_dir.join("impls.rs")).context("Failed to create impls.rs")?;
+    let mut result = writeln!(module, "// This file is @generated by foxglove-proto-gen");
+    result = result.and(writeln!(
+        module,
+        "use super::{{{encode_trait}, {writer}, EncodeError}};"
+    ));
+    result = result.and(writeln!(module, "use crate::schemas::*;"));
+    result = result.and(writeln!(
+        module,
+        "use crate::validate::{{validate, ValidationError}};"
+    ));
+    result = result.and(writeln!(module, "use bytes::BufMut;"));
+    result.context("Failed to write impls.rs")?;
+
+    for def in defs.values() {
+        let rust_name = rust_type_name(&def.name)?;
+        let file_name = format!("{rust_name}.msg");
+        let type_name = version.full_name(&def.name);
+        let mut definition = merged_definition(def, &defs);
+        if def.name.starts_with("geometry_msgs/") {
+            // Use the fully-qualified name in the header of a top-level geometry_msgs schema.
+            definition = definition.replacen(&def.name, &type_name, 1);
+        }
+        fs::write(data_dir.join(&file_name), definition)
+            .with_context(|| format!("Failed to write {file_name}"))?;
+
+        let body = generate_encode_body(def, version)
+            .with_context(|| format!("Failed to generate encoder for {}", def.name))?;
+        writeln!(
+            module,
+            "\nimpl {encode_trait} for {rust_name} {{
+    const TYPE_NAME: &'static str = \"{type_name}\";
+    const DEFINITION: &'static str = include_str!(\"data/{file_name}\");
+
+    fn {encode_fn}(&self, w: &mut {writer}<impl BufMut>) -> Result<(), EncodeError> {{
+{body}        Ok(())
+    }}
+
+    fn validate(&self) -> Result<(), ValidationError> {{
+        validate(self)
+    }}
+}}"
+        )
+        .context("Failed to write trait impl in impls.rs")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fields() {
+        let source = "# foxglove_msgs/msg/Example
+# An example
+
+uint8 UNKNOWN=0
+uint8 level
+
+# A comment
+float64[9] k
+foxglove_msgs/Color[] colors
+";
+        let fields = parse_fields(source).unwrap();
+        assert_eq!(
+            fields,
+            vec![
+                Field {
+                    ty: "uint8".into(),
+                    array: None,
+                    name: "level".into()
+                },
+                Field {
+                    ty: "float64".into(),
+                    array: Some(ArrayKind::Fixed(9)),
+                    name: "k".into()
+                },
+                Field {
+                    ty: "foxglove_msgs/Color".into(),
+                    array: Some(ArrayKind::Unbounded),
+                    name: "colors".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merged_definition() {
+        let mut defs = BTreeMap::new();
+        for (name, source) in [
+            ("pkg/A", "pkg/B b\npkg/C[] c\n"),
+            ("pkg/B", "pkg/C c\n"),
+            ("pkg/C", "float64 x\n"),
+        ] {
+            defs.insert(
+                name.to_string(),
+                MessageDef {
+                    name: name.to_string(),
+                    source: source.to_string(),
+                    fields: parse_fields(source).unwrap(),
+                },
+            );
+        }
+        assert_eq!(
+            merged_definition(&defs["pkg/A"], &defs),
+            format!("pkg/B b\npkg/C[] c\n{SEPARATOR}\nMSG: pkg/B\npkg/C c\n{SEPARATOR}\nMSG: pkg/C\nfloat64 x\n")
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! CBOR encoding for custom types.
+//!
+//! Types that implement [`Serialize`](serde::Serialize) and [`JsonSchema`](schemars::JsonSchema)
+//! are logged as JSON by default. JSON is verbose for numeric data, so to log such a type as a
+//! `cbor`-encoded message instead, wrap the message type in [`Cbor`]. The channel schema is the
+//! same JSON schema that is derived for JSON channels.
+//!
+//! The choice is made per channel, so JSON and CBOR channels can be used side by side.
+//!
+//! ```no_run
+//! use foxglove::cbor::Cbor;
+//! use foxglove::TypedChannel;
+//!
+//! #[derive(serde::Serialize, schemars::JsonSchema)]
+//! struct Scan {
+//!     ranges: Vec<f32>,
+//! }
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! let channel = TypedChannel::<Cbor<Scan>>::new("/scan")?;
+//! channel.log(&Cbor(Scan {
+//!     ranges: vec![1.0, 2.0, 3.0],
+//! }));
+//! # Ok(()) }
+//! ```
+
+/// An error that can occur when encoding a message as CBOR.
+pub type EncodeError = ciborium::ser::Error<std::io::Error>;
+
+crate::encode::serde_wrapper! {
+    /// A wrapper for logging a message with CBOR encoding.
+    ///
+    /// Use `TypedChannel<Cbor<T>>` to create a channel with message encoding `cbor`, and schema
+    /// encoding `jsonschema`.
+    pub struct Cbor;
+    encoding = "cbor";
+    type Error = EncodeError;
+    |value, writer| ciborium::into_writer(value, writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Encode;
+    use schemars::JsonSchema;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+    struct Scan {
+        frame_id: String,
+        ranges: Vec<f32>,
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Cbor::<Scan>::get_schema().unwrap();
+        assert_eq!(Some(schema), Scan::get_schema());
+        assert_eq!(Cbor::<Scan>::get_message_encoding(), "cbor");
+    }
+
+    #[test]
+    fn test_encode() {
+        let scan = Scan {
+            frame_id: "laser".to_string(),
+            ranges: vec![1.0, 2.5],
+        };
+        let mut buf = vec![];
+        Cbor(&scan).encode(&mut buf).unwrap();
+
+        let value: serde_json::Value = ciborium::from_reader(buf.as_slice()).unwrap();
+        assert_eq!(value["frame_id"], "laser");
+
+        let decoded: Scan = ciborium::from_reader(buf.as_slice()).unwrap();
+        assert_eq!(decoded, scan);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::channel::ChannelId;
 use crate::encode::TypedChannel;
 use crate::log_sink_set::LogSinkSet;
+use crate::validate::ValidationMode;
 use crate::{Channel, Encode, FoxgloveError, LogContext, Schema};
 use std::collections::BTreeMap;
 use std::sync::atomic::Ordering::Relaxed;
@@ -14,6 +15,8 @@ pub struct ChannelBuilder<'a> {
     message_encoding: Option<String>,
     schema: Option<Schema>,
     metadata: BTreeMap<String, String>,
+    namespace: Option<String>,
+    validation: Option<ValidationMode>,
     context: Option<&'a LogContext>,
 }
 
@@ -25,6 +28,8 @@ impl<'a> ChannelBuilder<'a> {
             message_encoding: None,
             schema: None,
             metadata: BTreeMap::new(),
+            namespace: None,
+            validation: None,
             context: None,
         }
     }
@@ -59,36 +64,61 @@ impl<'a> ChannelBuilder<'a> {
         self
     }
 
-    #[doc(hidden)]
+    /// Set the namespace for the channel.
+    ///
+    /// If the channel's topic is relative (it does not begin with `/`), the namespace is prepended
+    /// to it. This lets reusable components log to relative topics, while the caller decides
+    /// where they are placed. The context's [`TopicRemap`](crate::TopicRemap) is applied as
+    /// well; see [`LogContext::set_topic_remap`].
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Set how a [`TypedChannel`] validates messages before logging them.
+    ///
+    /// By default, the channel uses the current mode of its context; see
+    /// [`LogContext::set_validation`].
+    /// Validation has no effect on a [`Channel`], which logs messages that are already encoded.
+    pub fn validation(mut self, mode: ValidationMode) -> Self {
+        self.validation = Some(mode);
+        self
+    }
+
+    /// Set the [`LogContext`] to register the channel with.
+    ///
+    /// The channel will be logged to the sinks registered with this context. By default, channels
+    /// are registered with the [global context](LogContext::global).
     pub fn with_context(mut self, ctx: &'a LogContext) -> Self {
         self.context = Some(ctx);
         self
     }
 
     /// Build the channel and return it in an [`Arc`] as a Result.
-    /// Returns FoxgloveError::DuplicateChannel if a channel with the same topic already exists.
+    /// Returns FoxgloveError::DuplicateChannel if a channel with the same topic already exists
+    /// in the context.
     pub fn build(self) -> Result<Arc<Channel>, FoxgloveError> {
         static CHANNEL_ID: AtomicU64 = AtomicU64::new(1);
+        let context = self.context.unwrap_or_else(|| LogContext::global());
         let channel = Arc::new(Channel {
             sinks: LogSinkSet::new(),
             id: ChannelId::new(CHANNEL_ID.fetch_add(1, Relaxed)),
             message_sequence: AtomicU32::new(1),
-            topic: self.topic,
+            topic: context.resolve_topic(&self.topic, self.namespace.as_deref()),
             message_encoding: self
                 .message_encoding
                 .ok_or_else(|| FoxgloveError::MessageEncodingRequired)?,
             schema: self.schema,
             metadata: self.metadata,
         });
-        self.context
-            .unwrap_or_else(|| LogContext::global())
-            .add_channel(channel.clone())?;
+        context.add_channel(channel.clone())?;
         Ok(channel)
     }
 
     /// Build the channel and return it as a [`TypedChannel`] as a Result.
     /// `T` must implement [`Encode`].
-    /// Returns FoxgloveError::DuplicateChannel if a channel with the same topic already exists.
+    /// Returns FoxgloveError::DuplicateChannel if a channel with the same topic already exists
+    /// in the context.
     pub fn build_typed<T: Encode>(mut self) -> Result<TypedChannel<T>, FoxgloveError> {
         if self.message_encoding.is_none() {
             self.message_encoding = Some(<T as Encode>::get_message_encoding());
@@ -96,7 +126,14 @@ impl<'a> ChannelBuilder<'a> {
         if self.schema.is_none() {
             self.schema = <T as Encode>::get_schema();
         }
+        let context = self.context.unwrap_or_else(|| LogContext::global());
+        let validation = self.validation.take();
+        let context_validation = context.shared_validation();
         let channel = self.build()?;
-        Ok(TypedChannel::from_channel(channel))
+        Ok(TypedChannel::from_channel(
+            channel,
+            validation,
+            context_validation,
+        ))
     }
 }
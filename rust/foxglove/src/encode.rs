@@ -1,10 +1,23 @@
+use crate::validate::{ValidationError, ValidationMode};
 use crate::{Channel, ChannelBuilder, FoxgloveError, PartialMetadata, Schema};
 use bytes::BufMut;
+use parking_lot::RwLock;
 use schemars::{gen::SchemaSettings, JsonSchema};
 use serde::Serialize;
+use std::cell::RefCell;
 use std::{borrow::Cow, sync::Arc};
 
-const STACK_BUFFER_SIZE: usize = 128 * 1024;
+/// Maximum capacity retained by a thread's pooled encode buffer between calls.
+///
+/// If encoding an unusually large message grows the buffer beyond this size, the buffer is
+/// released after the message is logged, so that a single large message doesn't pin memory on
+/// every logging thread.
+const MAX_POOLED_BUFFER_CAPACITY: usize = 16 * 1024 * 1024;
+
+thread_local! {
+    /// A per-thread buffer which is reused across calls to [`TypedChannel::log`].
+    static ENCODE_BUFFER: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
 
 /// A trait representing a message that can be logged to a [`Channel`].
 ///
@@ -34,6 +47,15 @@ pub trait Encode {
     fn encoded_len(&self) -> Option<usize> {
         None
     }
+
+    /// Optional. Checks the invariants of the message.
+    ///
+    /// Called before logging when [validation](crate::validate) is enabled for the channel. The
+    /// default implementation accepts every message. It is overridden for the well-known
+    /// [schema types](crate::schemas).
+    fn validate(&self) -> Result<(), ValidationError> {
+        Ok(())
+    }
 }
 
 /// Automatically implements [`Encode`] for any type that implements [`Serialize`] and
@@ -45,17 +67,7 @@ impl<T: Serialize + JsonSchema> Encode for T {
     type Error = serde_json::Error;
 
     fn get_schema() -> Option<Schema> {
-        let settings = SchemaSettings::draft07().with(|option| {
-            option.inline_subschemas = true;
-        });
-        let generator = settings.into_generator();
-        let json_schema = generator.into_root_schema_for::<T>();
-
-        Some(Schema::new(
-            std::any::type_name::<T>().to_string(),
-            "jsonschema".to_string(),
-            Cow::Owned(serde_json::to_vec(&json_schema).expect("Failed to serialize schema")),
-        ))
+        Some(json_schema::<T>())
     }
 
     fn get_message_encoding() -> String {
@@ -67,11 +79,78 @@ impl<T: Serialize + JsonSchema> Encode for T {
     }
 }
 
+/// Returns the JSON schema for a type, named after the Rust type.
+///
+/// Definitions are inlined since Foxglove does not support external references.
+pub(crate) fn json_schema<T: JsonSchema>() -> Schema {
+    let settings = SchemaSettings::draft07().with(|option| {
+        option.inline_subschemas = true;
+    });
+    let generator = settings.into_generator();
+    let json_schema = generator.into_root_schema_for::<T>();
+
+    Schema::new(
+        std::any::type_name::<T>().to_string(),
+        "jsonschema".to_string(),
+        Cow::Owned(serde_json::to_vec(&json_schema).expect("Failed to serialize schema")),
+    )
+}
+
+/// Defines a wrapper for logging a [`Serialize`] and [`JsonSchema`] message in another serde
+/// format, with the same JSON schema as a JSON channel.
+///
+/// The wrapper implements [`Encode`] with the given message encoding and error type. The closure
+/// serializes a reference to the wrapped value to a `&mut impl Write`.
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+macro_rules! serde_wrapper {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident;
+        encoding = $encoding:literal;
+        type Error = $error:ty;
+        |$value:ident, $writer:ident| $serialize:expr
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Default, Clone, PartialEq)]
+        $vis struct $name<T>(pub T);
+
+        impl<T> From<T> for $name<T> {
+            fn from(msg: T) -> Self {
+                Self(msg)
+            }
+        }
+
+        impl<T: serde::Serialize + schemars::JsonSchema> $crate::Encode for $name<T> {
+            type Error = $error;
+
+            fn get_schema() -> Option<$crate::Schema> {
+                Some($crate::encode::json_schema::<T>())
+            }
+
+            fn get_message_encoding() -> String {
+                $encoding.to_string()
+            }
+
+            fn encode(&self, buf: &mut impl bytes::BufMut) -> Result<(), Self::Error> {
+                let $value = &self.0;
+                let $writer = &mut bytes::BufMut::writer(buf);
+                $serialize
+            }
+        }
+    };
+}
+
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+pub(crate) use serde_wrapper;
+
 /// A typed [`Channel`] for messages that implement [`Encode`].
 ///
 /// Channels are immutable, returned as `Arc<Channel>` and can be shared between threads.
 pub struct TypedChannel<T: Encode> {
     inner: Arc<Channel>,
+    // The mode set with `ChannelBuilder::validation`, which overrides the context's mode.
+    validation: Option<ValidationMode>,
+    context_validation: Arc<RwLock<ValidationMode>>,
     _phantom: std::marker::PhantomData<T>,
 }
 
@@ -83,16 +162,24 @@ impl<T: Encode> TypedChannel<T> {
 }
 
 impl<T: Encode> TypedChannel<T> {
-    /// Constructs a new typed channel with default settings.
+    /// Constructs a new typed channel with default settings, registered with the
+    /// [global context](crate::LogContext::global).
     ///
-    /// If you want to override the channel configuration, use [`ChannelBuilder::build_typed`].
+    /// If you want to override the channel configuration, or register the channel with a
+    /// different context, use [`ChannelBuilder::build_typed`].
     pub fn new(topic: impl Into<String>) -> Result<Self, FoxgloveError> {
         ChannelBuilder::new(topic).build_typed()
     }
 
-    pub(crate) fn from_channel(channel: Arc<Channel>) -> Self {
+    pub(crate) fn from_channel(
+        channel: Arc<Channel>,
+        validation: Option<ValidationMode>,
+        context_validation: Arc<RwLock<ValidationMode>>,
+    ) -> Self {
         Self {
             inner: channel,
+            validation,
+            context_validation,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -103,31 +190,39 @@ impl<T: Encode> TypedChannel<T> {
     }
 
     /// Encodes the message and logs it on the channel with additional metadata.
+    ///
+    /// Messages are encoded into a buffer which is pooled per thread, so logging does not
+    /// allocate once the buffer has grown to fit the channel's messages.
     pub fn log_with_meta(&self, msg: &T, metadata: PartialMetadata) {
-        // Try to avoid heap allocation by using a stack buffer.
-        let mut stack_buf = [0u8; STACK_BUFFER_SIZE];
-        let mut cursor = &mut stack_buf[..];
-
-        match msg.encode(&mut cursor) {
-            Ok(()) => {
-                // Compute the written amount of bytes
-                let written = cursor.as_ptr() as usize - stack_buf.as_ptr() as usize;
-                self.inner.log_with_meta(&stack_buf[..written], metadata);
-            }
-            Err(_) => {
-                // Likely the stack buffer was too small, so fall back to a heap buffer.
-                let mut size = msg.encoded_len().unwrap_or(STACK_BUFFER_SIZE * 2);
-                if size <= STACK_BUFFER_SIZE {
-                    // The estimate in `encoded_len` was too small, fall back to stack buffer size * 2
-                    size = STACK_BUFFER_SIZE * 2;
-                }
-                let mut buf = Vec::with_capacity(size);
-                if let Err(err) = msg.encode(&mut buf) {
-                    tracing::error!("failed to encode message: {:?}", err);
-                }
-                self.inner.log_with_meta(&buf, metadata);
+        ENCODE_BUFFER.with(|cell| match cell.try_borrow_mut() {
+            Ok(mut buf) => self.encode_and_log(&mut buf, msg, metadata),
+            // The pooled buffer is in use further up the stack, which can happen if a sink logs
+            // from within its own `log` method. Fall back to a temporary buffer.
+            Err(_) => self.encode_and_log(&mut Vec::new(), msg, metadata),
+        });
+    }
+
+    fn encode_and_log(&self, buf: &mut Vec<u8>, msg: &T, metadata: PartialMetadata) {
+        let topic = &self.inner.topic;
+        match &self.validation {
+            Some(mode) => mode.check(topic, || msg.validate()),
+            // The lock is released before validating, in case the callback sets the mode.
+            None => {
+                let mode = self.context_validation.read().clone();
+                mode.check(topic, || msg.validate());
             }
         }
+        buf.clear();
+        if let Some(len) = msg.encoded_len() {
+            buf.reserve(len);
+        }
+        match msg.encode(buf) {
+            Ok(()) => self.inner.log_with_meta(buf, metadata),
+            Err(err) => tracing::error!("failed to encode message: {:?}", err),
+        }
+        if buf.capacity() > MAX_POOLED_BUFFER_CAPACITY {
+            *buf = Vec::new();
+        }
     }
 }
 
@@ -141,10 +236,15 @@ impl<T: Encode> TypedChannel<T> {
 ///
 /// The channel is created with the provided visibility and identifier, and the topic and message type.
 ///
+/// By default, the channel is registered with the [global context](crate::LogContext::global).
+/// An optional fourth argument specifies a different context. It is evaluated when the channel is
+/// first used, and must dereference to a [`LogContext`](crate::LogContext).
+///
 /// # Example
 /// ```
-/// use foxglove::static_typed_channel;
+/// use foxglove::{static_typed_channel, LogContext};
 /// use foxglove::schemas::{FrameTransform, SceneUpdate};
+/// use std::sync::{Arc, LazyLock};
 ///
 /// // A locally-scoped typed channel.
 /// static_typed_channel!(TF, "/tf", FrameTransform);
@@ -152,9 +252,14 @@ impl<T: Encode> TypedChannel<T> {
 /// // A pub(crate)-scoped typed channel.
 /// static_typed_channel!(pub(crate) BOXES, "/boxes", SceneUpdate);
 ///
+/// // A typed channel registered with a scoped context.
+/// static ROBOT: LazyLock<Arc<LogContext>> = LazyLock::new(|| Arc::new(LogContext::new()));
+/// static_typed_channel!(ROBOT_TF, "/tf", FrameTransform, ROBOT);
+///
 /// // Usage (you would populate the structs, rather than using `default()`).
 /// TF.log(&FrameTransform::default());
 /// BOXES.log(&SceneUpdate::default());
+/// ROBOT_TF.log(&FrameTransform::default());
 /// ```
 #[macro_export]
 macro_rules! static_typed_channel {
@@ -167,14 +272,28 @@ macro_rules! static_typed_channel {
                 }
             });
     };
+    ($vis:vis $ident: ident, $topic: literal, $ty: ty, $context: expr) => {
+        $vis static $ident: std::sync::LazyLock<$crate::TypedChannel<$ty>> =
+            std::sync::LazyLock::new(|| {
+                match $crate::ChannelBuilder::new($topic)
+                    .with_context(&$context)
+                    .build_typed()
+                {
+                    Ok(channel) => channel,
+                    Err(e) => {
+                        panic!("Failed to create channel for {}: {:?}", $topic, e);
+                    }
+                }
+            });
+    };
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::channel_builder::ChannelBuilder;
-    use crate::testutil::GlobalContextTest;
-    use crate::Schema;
+    use crate::testutil::{GlobalContextTest, RecordingSink};
+    use crate::{LogContext, Schema};
     use prost::bytes::BufMut;
     use serde::Serialize;
     use serde_json::{json, Value};
@@ -229,6 +348,80 @@ mod test {
         assert!(!logs_contain("error logging message"));
     }
 
+    #[test]
+    fn test_log_reuses_pooled_buffer() {
+        let ctx = LogContext::new();
+        let sink = Arc::new(RecordingSink::new());
+        ctx.add_sink(sink.clone());
+        let channel = ChannelBuilder::new("topic")
+            .with_context(&ctx)
+            .build_typed::<TestMessage>()
+            .expect("failed to build channel");
+
+        // A message larger than any reasonable initial capacity, followed by a smaller one, to
+        // make sure the buffer is cleared between messages.
+        let large = TestMessage {
+            msg: "x".repeat(512 * 1024),
+            count: 1,
+        };
+        let small = TestMessage {
+            msg: "y".to_string(),
+            count: 2,
+        };
+        channel.log(&large);
+        let (ptr, capacity) = ENCODE_BUFFER.with(|buf| {
+            let buf = buf.borrow();
+            (buf.as_ptr(), buf.capacity())
+        });
+        assert!(capacity >= large.msg.len());
+        channel.log(&small);
+        // The buffer is reused, rather than reallocated.
+        ENCODE_BUFFER.with(|buf| {
+            let buf = buf.borrow();
+            assert_eq!(buf.as_ptr(), ptr);
+            assert_eq!(buf.capacity(), capacity);
+        });
+
+        let recorded = sink.recorded.lock();
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(recorded[0].msg, serde_json::to_vec(&large).unwrap());
+        assert_eq!(recorded[1].msg, serde_json::to_vec(&small).unwrap());
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_log_encode_error() {
+        struct Unencodable;
+
+        impl Encode for Unencodable {
+            type Error = std::fmt::Error;
+
+            fn get_schema() -> Option<Schema> {
+                None
+            }
+
+            fn get_message_encoding() -> String {
+                "unencodable".to_string()
+            }
+
+            fn encode(&self, _buf: &mut impl BufMut) -> Result<(), Self::Error> {
+                Err(std::fmt::Error)
+            }
+        }
+
+        let ctx = LogContext::new();
+        let sink = Arc::new(RecordingSink::new());
+        ctx.add_sink(sink.clone());
+        let channel = ChannelBuilder::new("topic")
+            .with_context(&ctx)
+            .build_typed::<Unencodable>()
+            .expect("failed to build channel");
+
+        channel.log(&Unencodable);
+        assert!(logs_contain("failed to encode message"));
+        assert!(sink.recorded.lock().is_empty());
+    }
+
     #[test]
     fn test_derived_schema_inlines_enums() {
         #[derive(Serialize, JsonSchema)]
@@ -0,0 +1,343 @@
+//! FlatBuffers encoding for well-known Foxglove schemas.
+//!
+//! The [schema types](crate::schemas) are encoded as protobuf by default. To log them as
+//! `flatbuffer`-encoded messages instead, wrap the message type in [`FlatBuffer`]. The channel
+//! schema is the binary FlatBuffers schema (`.bfbs`) for the message type, so that the data can be
+//! decoded without any additional schema files. The choice is made per channel, so protobuf and
+//! FlatBuffers channels can be used side by side.
+//!
+//! ```no_run
+//! use foxglove::flatbuffer::FlatBuffer;
+//! use foxglove::schemas::{Log, log::Level};
+//! use foxglove::TypedChannel;
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! // Logged with the schema "foxglove.Log".
+//! let channel = TypedChannel::<FlatBuffer<Log>>::new("/log")?;
+//! channel.log(&FlatBuffer(Log {
+//!     level: Level::Info.into(),
+//!     message: "Hello, FlatBuffers!".to_string(),
+//!     ..Default::default()
+//! }));
+//! # Ok(()) }
+//! ```
+
+use crate::schemas::Timestamp;
+use crate::validate::ValidationError;
+use crate::{Encode, Schema};
+use bytes::BufMut;
+use flatbuffers::{
+    FlatBufferBuilder, ForwardsUOffset, Push, TableFinishedWIPOffset, Vector, WIPOffset,
+};
+use std::cell::RefCell;
+
+#[rustfmt::skip]
+mod impls;
+
+/// Maximum size of a finished buffer for which the thread's builder is retained between calls.
+const MAX_POOLED_BUILDER_SIZE: usize = 16 * 1024 * 1024;
+
+thread_local! {
+    /// A per-thread builder which is reused across calls to [`FlatBuffer::encode`].
+    static BUILDER: RefCell<FlatBufferBuilder<'static>> = RefCell::new(FlatBufferBuilder::new());
+}
+
+/// An error that can occur when encoding a message as a FlatBuffer.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum EncodeError {
+    /// A field value cannot be represented by its FlatBuffers type.
+    #[error("Field {0} is out of range for its FlatBuffers type")]
+    OutOfRange(&'static str),
+}
+
+/// A message that can be encoded as a FlatBuffer, with a corresponding binary schema.
+///
+/// This trait is implemented for the well-known [schema types](crate::schemas). Wrap a message in
+/// [`FlatBuffer`] to log it.
+pub trait FlatBufferEncode {
+    /// The fully-qualified FlatBuffers type name, such as `foxglove.Log`.
+    const TYPE_NAME: &'static str;
+
+    /// The binary FlatBuffers schema (`.bfbs`) for the message.
+    const BINARY_SCHEMA: &'static [u8];
+
+    /// Builds the message as a table in the provided builder, and returns its offset.
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError>;
+
+    /// Optional. Checks the invariants of the message; see [`Encode::validate`].
+    fn validate(&self) -> Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+/// A wrapper for logging a message with FlatBuffers encoding.
+///
+/// Use `TypedChannel<FlatBuffer<T>>` to create a channel with message encoding `flatbuffer`, and
+/// schema encoding `flatbuffer`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FlatBuffer<T>(pub T);
+
+impl<T> From<T> for FlatBuffer<T> {
+    fn from(msg: T) -> Self {
+        Self(msg)
+    }
+}
+
+impl<T: FlatBufferEncode> Encode for FlatBuffer<T> {
+    type Error = EncodeError;
+
+    fn get_schema() -> Option<Schema> {
+        Some(Schema::new(T::TYPE_NAME, "flatbuffer", T::BINARY_SCHEMA))
+    }
+
+    fn get_message_encoding() -> String {
+        "flatbuffer".to_string()
+    }
+
+    fn encode(&self, buf: &mut impl BufMut) -> Result<(), Self::Error> {
+        BUILDER.with(|builder| match builder.try_borrow_mut() {
+            Ok(mut fbb) => {
+                let result = finish(&mut fbb, &self.0, buf);
+                if fbb.unfinished_data().len() > MAX_POOLED_BUILDER_SIZE {
+                    *fbb = FlatBufferBuilder::new();
+                }
+                result
+            }
+            // Only reachable if a message is encoded while building another on the same thread.
+            Err(_) => finish(&mut FlatBufferBuilder::new(), &self.0, buf),
+        })
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.0.validate()
+    }
+}
+
+/// Builds a message as the root table of the builder, and copies the result into the buffer.
+fn finish<T: FlatBufferEncode>(
+    fbb: &mut FlatBufferBuilder<'_>,
+    msg: &T,
+    buf: &mut impl BufMut,
+) -> Result<(), EncodeError> {
+    fbb.reset();
+    let root = msg.build_flatbuffer(fbb)?;
+    fbb.finish(root, None);
+    buf.put_slice(fbb.finished_data());
+    Ok(())
+}
+
+/// Builds a nested table. If the message is absent, its default value is written.
+fn build_table<T: FlatBufferEncode + Default>(
+    fbb: &mut FlatBufferBuilder<'_>,
+    value: Option<&T>,
+) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+    match value {
+        Some(value) => value.build_flatbuffer(fbb),
+        None => T::default().build_flatbuffer(fbb),
+    }
+}
+
+/// Builds a vector of tables.
+fn build_tables<'fbb, T: FlatBufferEncode>(
+    fbb: &mut FlatBufferBuilder<'fbb>,
+    values: &[T],
+) -> Result<WIPOffset<Vector<'fbb, ForwardsUOffset<TableFinishedWIPOffset>>>, EncodeError> {
+    let offsets = values
+        .iter()
+        .map(|value| value.build_flatbuffer(fbb))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(fbb.create_vector(&offsets))
+}
+
+/// Converts an enum value to a `ubyte`.
+fn enum_value(field: &'static str, value: i32) -> Result<u8, EncodeError> {
+    u8::try_from(value).map_err(|_| EncodeError::OutOfRange(field))
+}
+
+/// The `foxglove.Time` struct. If the timestamp is absent, zero is written.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Time {
+    sec: u32,
+    nsec: u32,
+}
+
+impl From<Option<&Timestamp>> for Time {
+    fn from(value: Option<&Timestamp>) -> Self {
+        let value = value.copied().unwrap_or_default();
+        Self {
+            sec: value.sec(),
+            nsec: value.nsec(),
+        }
+    }
+}
+
+impl Push for Time {
+    type Output = Self;
+
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        dst[..4].copy_from_slice(&self.sec.to_le_bytes());
+        dst[4..8].copy_from_slice(&self.nsec.to_le_bytes());
+    }
+}
+
+/// The `foxglove.Duration` struct. If the duration is absent, zero is written.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Duration {
+    sec: i32,
+    nsec: i32,
+}
+
+impl From<Option<&crate::schemas::Duration>> for Duration {
+    fn from(value: Option<&crate::schemas::Duration>) -> Self {
+        let value = value.copied().unwrap_or_default();
+        Self {
+            sec: value.sec(),
+            // The nanoseconds are always less than 1e9, so they fit in an `int32`.
+            nsec: value.nsec() as i32,
+        }
+    }
+}
+
+impl Push for Duration {
+    type Output = Self;
+
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        dst[..4].copy_from_slice(&self.sec.to_le_bytes());
+        dst[4..8].copy_from_slice(&self.nsec.to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::{Color, Log, SceneEntity, SceneUpdate};
+    use flatbuffers::{ForwardsUOffset, Table, Vector};
+
+    fn encode<T: FlatBufferEncode>(msg: T) -> Vec<u8> {
+        let mut buf = vec![];
+        FlatBuffer(msg).encode(&mut buf).unwrap();
+        buf
+    }
+
+    /// Returns the root table of a finished buffer.
+    fn root(buf: &[u8]) -> Table<'_> {
+        // SAFETY: the buffer was produced by `FlatBuffer::encode`, and has a table at its root.
+        unsafe { flatbuffers::root_unchecked::<Table>(buf) }
+    }
+
+    /// Returns the vtable offset of a field with the given id.
+    fn slot(id: u16) -> u16 {
+        4 + 2 * id
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = FlatBuffer::<SceneUpdate>::get_schema().unwrap();
+        assert_eq!(schema.name, "foxglove.SceneUpdate");
+        assert_eq!(schema.encoding, "flatbuffer");
+        // Binary schemas have the file identifier "BFBS".
+        assert_eq!(&schema.data[4..8], b"BFBS");
+        assert_eq!(
+            FlatBuffer::<SceneUpdate>::get_message_encoding(),
+            "flatbuffer"
+        );
+    }
+
+    #[test]
+    fn test_encode_scalars_and_strings() {
+        let buf = encode(Log {
+            timestamp: Some(Timestamp::new(1, 2)),
+            level: 3,
+            message: "hi".to_string(),
+            line: 7,
+            ..Default::default()
+        });
+        let table = root(&buf);
+        // SAFETY: the slots match the field types in Log.fbs.
+        unsafe {
+            let time = table.get::<u64>(slot(0), None).unwrap();
+            assert_eq!(time, (2 << 32) | 1);
+            assert_eq!(table.get::<u8>(slot(1), None), Some(3));
+            let message = table.get::<ForwardsUOffset<&str>>(slot(2), None);
+            assert_eq!(message, Some("hi"));
+            assert_eq!(table.get::<ForwardsUOffset<&str>>(slot(3), None), Some(""));
+            assert_eq!(table.get::<u32>(slot(5), None), Some(7));
+        }
+    }
+
+    #[test]
+    fn test_encode_schema_defaults() {
+        // The default value of each field in Color.fbs is 1.0, so zeros must be written.
+        let buf = encode(Color {
+            r: 0.0,
+            g: 0.5,
+            b: 0.0,
+            a: 0.0,
+        });
+        let table = root(&buf);
+        // SAFETY: the slots match the field types in Color.fbs.
+        unsafe {
+            assert_eq!(table.get::<f64>(slot(0), None), Some(0.0));
+            assert_eq!(table.get::<f64>(slot(1), None), Some(0.5));
+            assert_eq!(table.get::<f64>(slot(3), None), Some(0.0));
+        }
+
+        let buf = encode(Color {
+            a: 1.0,
+            ..Default::default()
+        });
+        // SAFETY: as above.
+        unsafe {
+            assert_eq!(root(&buf).get::<f64>(slot(3), None), None);
+        }
+    }
+
+    #[test]
+    fn test_encode_nested_tables() {
+        let buf = encode(SceneUpdate {
+            entities: vec![
+                SceneEntity {
+                    id: "a".to_string(),
+                    ..Default::default()
+                },
+                SceneEntity {
+                    id: "b".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        let table = root(&buf);
+        // SAFETY: the slots match the field types in SceneUpdate.fbs and SceneEntity.fbs.
+        unsafe {
+            let entities = table
+                .get::<ForwardsUOffset<Vector<ForwardsUOffset<Table>>>>(slot(1), None)
+                .unwrap();
+            let ids: Vec<_> = entities
+                .iter()
+                .map(|e| e.get::<ForwardsUOffset<&str>>(slot(2), None).unwrap())
+                .collect();
+            assert_eq!(ids, ["a", "b"]);
+        }
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        let err = FlatBuffer(Log {
+            level: 256,
+            ..Default::default()
+        })
+        .encode(&mut vec![])
+        .unwrap_err();
+        assert!(matches!(err, EncodeError::OutOfRange("level")));
+
+        // The builder is reset after an error.
+        encode(Log::default());
+    }
+}
@@ -0,0 +1,938 @@
+// This file is @generated by foxglove-proto-gen
+use super::{build_table, build_tables, enum_value, Duration, EncodeError, FlatBufferEncode, Time};
+use crate::schemas::*;
+use crate::validate::{validate, ValidationError};
+use flatbuffers::{FlatBufferBuilder, TableFinishedWIPOffset, WIPOffset};
+
+impl FlatBufferEncode for ArrowPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.ArrowPrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/ArrowPrimitive.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let pose = build_table(fbb, self.pose.as_ref())?;
+        let color = build_table(fbb, self.color.as_ref())?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, pose);
+        fbb.push_slot::<f64>(6, self.shaft_length, 0.0);
+        fbb.push_slot::<f64>(8, self.shaft_diameter, 0.0);
+        fbb.push_slot::<f64>(10, self.head_length, 0.0);
+        fbb.push_slot::<f64>(12, self.head_diameter, 0.0);
+        fbb.push_slot_always(14, color);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for CameraCalibration {
+    const TYPE_NAME: &'static str = "foxglove.CameraCalibration";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/CameraCalibration.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let frame_id = fbb.create_string(&self.frame_id);
+        let distortion_model = fbb.create_string(&self.distortion_model);
+        let d = fbb.create_vector(&self.d[..]);
+        let k = fbb.create_vector(&self.k[..]);
+        let r = fbb.create_vector(&self.r[..]);
+        let p = fbb.create_vector(&self.p[..]);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, frame_id);
+        fbb.push_slot::<u32>(8, self.width, 0);
+        fbb.push_slot::<u32>(10, self.height, 0);
+        fbb.push_slot_always(12, distortion_model);
+        fbb.push_slot_always(14, d);
+        fbb.push_slot_always(16, k);
+        fbb.push_slot_always(18, r);
+        fbb.push_slot_always(20, p);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for CircleAnnotation {
+    const TYPE_NAME: &'static str = "foxglove.CircleAnnotation";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/CircleAnnotation.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let position = build_table(fbb, self.position.as_ref())?;
+        let fill_color = build_table(fbb, self.fill_color.as_ref())?;
+        let outline_color = build_table(fbb, self.outline_color.as_ref())?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, position);
+        fbb.push_slot::<f64>(8, self.diameter, 0.0);
+        fbb.push_slot::<f64>(10, self.thickness, 0.0);
+        fbb.push_slot_always(12, fill_color);
+        fbb.push_slot_always(14, outline_color);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for Color {
+    const TYPE_NAME: &'static str = "foxglove.Color";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/Color.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let start = fbb.start_table();
+        fbb.push_slot::<f64>(4, self.r, 1.0);
+        fbb.push_slot::<f64>(6, self.g, 1.0);
+        fbb.push_slot::<f64>(8, self.b, 1.0);
+        fbb.push_slot::<f64>(10, self.a, 1.0);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for CompressedImage {
+    const TYPE_NAME: &'static str = "foxglove.CompressedImage";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/CompressedImage.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let frame_id = fbb.create_string(&self.frame_id);
+        let data = fbb.create_vector(&self.data[..]);
+        let format = fbb.create_string(&self.format);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, frame_id);
+        fbb.push_slot_always(8, data);
+        fbb.push_slot_always(10, format);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for CompressedVideo {
+    const TYPE_NAME: &'static str = "foxglove.CompressedVideo";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/CompressedVideo.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let frame_id = fbb.create_string(&self.frame_id);
+        let data = fbb.create_vector(&self.data[..]);
+        let format = fbb.create_string(&self.format);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, frame_id);
+        fbb.push_slot_always(8, data);
+        fbb.push_slot_always(10, format);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for CubePrimitive {
+    const TYPE_NAME: &'static str = "foxglove.CubePrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/CubePrimitive.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let pose = build_table(fbb, self.pose.as_ref())?;
+        let size = build_table(fbb, self.size.as_ref())?;
+        let color = build_table(fbb, self.color.as_ref())?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, pose);
+        fbb.push_slot_always(6, size);
+        fbb.push_slot_always(8, color);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for CylinderPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.CylinderPrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/CylinderPrimitive.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let pose = build_table(fbb, self.pose.as_ref())?;
+        let size = build_table(fbb, self.size.as_ref())?;
+        let color = build_table(fbb, self.color.as_ref())?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, pose);
+        fbb.push_slot_always(6, size);
+        fbb.push_slot::<f64>(8, self.bottom_scale, 0.0);
+        fbb.push_slot::<f64>(10, self.top_scale, 0.0);
+        fbb.push_slot_always(12, color);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for FrameTransform {
+    const TYPE_NAME: &'static str = "foxglove.FrameTransform";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/FrameTransform.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let parent_frame_id = fbb.create_string(&self.parent_frame_id);
+        let child_frame_id = fbb.create_string(&self.child_frame_id);
+        let translation = build_table(fbb, self.translation.as_ref())?;
+        let rotation = build_table(fbb, self.rotation.as_ref())?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, parent_frame_id);
+        fbb.push_slot_always(8, child_frame_id);
+        fbb.push_slot_always(10, translation);
+        fbb.push_slot_always(12, rotation);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for FrameTransforms {
+    const TYPE_NAME: &'static str = "foxglove.FrameTransforms";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/FrameTransforms.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let transforms = build_tables(fbb, &self.transforms)?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, transforms);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for GeoJson {
+    const TYPE_NAME: &'static str = "foxglove.GeoJSON";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/GeoJSON.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let geojson = fbb.create_string(&self.geojson);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, geojson);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for Grid {
+    const TYPE_NAME: &'static str = "foxglove.Grid";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/Grid.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let frame_id = fbb.create_string(&self.frame_id);
+        let pose = build_table(fbb, self.pose.as_ref())?;
+        let cell_size = build_table(fbb, self.cell_size.as_ref())?;
+        let fields = build_tables(fbb, &self.fields)?;
+        let data = fbb.create_vector(&self.data[..]);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, frame_id);
+        fbb.push_slot_always(8, pose);
+        fbb.push_slot::<u32>(10, self.column_count, 0);
+        fbb.push_slot_always(12, cell_size);
+        fbb.push_slot::<u32>(14, self.row_stride, 0);
+        fbb.push_slot::<u32>(16, self.cell_stride, 0);
+        fbb.push_slot_always(18, fields);
+        fbb.push_slot_always(20, data);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for ImageAnnotations {
+    const TYPE_NAME: &'static str = "foxglove.ImageAnnotations";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/ImageAnnotations.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let circles = build_tables(fbb, &self.circles)?;
+        let points = build_tables(fbb, &self.points)?;
+        let texts = build_tables(fbb, &self.texts)?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, circles);
+        fbb.push_slot_always(6, points);
+        fbb.push_slot_always(8, texts);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for KeyValuePair {
+    const TYPE_NAME: &'static str = "foxglove.KeyValuePair";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/KeyValuePair.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let key = fbb.create_string(&self.key);
+        let value = fbb.create_string(&self.value);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, key);
+        fbb.push_slot_always(6, value);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for LaserScan {
+    const TYPE_NAME: &'static str = "foxglove.LaserScan";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/LaserScan.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let frame_id = fbb.create_string(&self.frame_id);
+        let pose = build_table(fbb, self.pose.as_ref())?;
+        let ranges = fbb.create_vector(&self.ranges[..]);
+        let intensities = fbb.create_vector(&self.intensities[..]);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, frame_id);
+        fbb.push_slot_always(8, pose);
+        fbb.push_slot::<f64>(10, self.start_angle, 0.0);
+        fbb.push_slot::<f64>(12, self.end_angle, 0.0);
+        fbb.push_slot_always(14, ranges);
+        fbb.push_slot_always(16, intensities);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for LinePrimitive {
+    const TYPE_NAME: &'static str = "foxglove.LinePrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/LinePrimitive.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let pose = build_table(fbb, self.pose.as_ref())?;
+        let points = build_tables(fbb, &self.points)?;
+        let color = build_table(fbb, self.color.as_ref())?;
+        let colors = build_tables(fbb, &self.colors)?;
+        let indices = fbb.create_vector(&self.indices[..]);
+        let start = fbb.start_table();
+        fbb.push_slot::<u8>(4, enum_value("type", self.r#type)?, 0);
+        fbb.push_slot_always(6, pose);
+        fbb.push_slot::<f64>(8, self.thickness, 0.0);
+        fbb.push_slot::<bool>(10, self.scale_invariant, false);
+        fbb.push_slot_always(12, points);
+        fbb.push_slot_always(14, color);
+        fbb.push_slot_always(16, colors);
+        fbb.push_slot_always(18, indices);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for LocationFix {
+    const TYPE_NAME: &'static str = "foxglove.LocationFix";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/LocationFix.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let frame_id = fbb.create_string(&self.frame_id);
+        let position_covariance = fbb.create_vector(&self.position_covariance[..]);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, frame_id);
+        fbb.push_slot::<f64>(8, self.latitude, 0.0);
+        fbb.push_slot::<f64>(10, self.longitude, 0.0);
+        fbb.push_slot::<f64>(12, self.altitude, 0.0);
+        fbb.push_slot_always(14, position_covariance);
+        fbb.push_slot::<u8>(16, enum_value("position_covariance_type", self.position_covariance_type)?, 0);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for Log {
+    const TYPE_NAME: &'static str = "foxglove.Log";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/Log.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let message = fbb.create_string(&self.message);
+        let name = fbb.create_string(&self.name);
+        let file = fbb.create_string(&self.file);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot::<u8>(6, enum_value("level", self.level)?, 0);
+        fbb.push_slot_always(8, message);
+        fbb.push_slot_always(10, name);
+        fbb.push_slot_always(12, file);
+        fbb.push_slot::<u32>(14, self.line, 0);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for ModelPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.ModelPrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/ModelPrimitive.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let pose = build_table(fbb, self.pose.as_ref())?;
+        let scale = build_table(fbb, self.scale.as_ref())?;
+        let color = build_table(fbb, self.color.as_ref())?;
+        let url = fbb.create_string(&self.url);
+        let media_type = fbb.create_string(&self.media_type);
+        let data = fbb.create_vector(&self.data[..]);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, pose);
+        fbb.push_slot_always(6, scale);
+        fbb.push_slot_always(8, color);
+        fbb.push_slot::<bool>(10, self.override_color, false);
+        fbb.push_slot_always(12, url);
+        fbb.push_slot_always(14, media_type);
+        fbb.push_slot_always(16, data);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for PackedElementField {
+    const TYPE_NAME: &'static str = "foxglove.PackedElementField";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/PackedElementField.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let name = fbb.create_string(&self.name);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, name);
+        fbb.push_slot::<u32>(6, self.offset, 0);
+        fbb.push_slot::<u8>(8, enum_value("type", self.r#type)?, 0);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for Point2 {
+    const TYPE_NAME: &'static str = "foxglove.Point2";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/Point2.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let start = fbb.start_table();
+        fbb.push_slot::<f64>(4, self.x, 0.0);
+        fbb.push_slot::<f64>(6, self.y, 0.0);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for Point3 {
+    const TYPE_NAME: &'static str = "foxglove.Point3";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/Point3.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let start = fbb.start_table();
+        fbb.push_slot::<f64>(4, self.x, 0.0);
+        fbb.push_slot::<f64>(6, self.y, 0.0);
+        fbb.push_slot::<f64>(8, self.z, 0.0);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for PointCloud {
+    const TYPE_NAME: &'static str = "foxglove.PointCloud";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/PointCloud.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let frame_id = fbb.create_string(&self.frame_id);
+        let pose = build_table(fbb, self.pose.as_ref())?;
+        let fields = build_tables(fbb, &self.fields)?;
+        let data = fbb.create_vector(&self.data[..]);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, frame_id);
+        fbb.push_slot_always(8, pose);
+        fbb.push_slot::<u32>(10, self.point_stride, 0);
+        fbb.push_slot_always(12, fields);
+        fbb.push_slot_always(14, data);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for PointsAnnotation {
+    const TYPE_NAME: &'static str = "foxglove.PointsAnnotation";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/PointsAnnotation.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let points = build_tables(fbb, &self.points)?;
+        let outline_color = build_table(fbb, self.outline_color.as_ref())?;
+        let outline_colors = build_tables(fbb, &self.outline_colors)?;
+        let fill_color = build_table(fbb, self.fill_color.as_ref())?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot::<u8>(6, enum_value("type", self.r#type)?, 0);
+        fbb.push_slot_always(8, points);
+        fbb.push_slot_always(10, outline_color);
+        fbb.push_slot_always(12, outline_colors);
+        fbb.push_slot_always(14, fill_color);
+        fbb.push_slot::<f64>(16, self.thickness, 0.0);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for Pose {
+    const TYPE_NAME: &'static str = "foxglove.Pose";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/Pose.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let position = build_table(fbb, self.position.as_ref())?;
+        let orientation = build_table(fbb, self.orientation.as_ref())?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, position);
+        fbb.push_slot_always(6, orientation);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for PoseInFrame {
+    const TYPE_NAME: &'static str = "foxglove.PoseInFrame";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/PoseInFrame.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let frame_id = fbb.create_string(&self.frame_id);
+        let pose = build_table(fbb, self.pose.as_ref())?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, frame_id);
+        fbb.push_slot_always(8, pose);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for PosesInFrame {
+    const TYPE_NAME: &'static str = "foxglove.PosesInFrame";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/PosesInFrame.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let frame_id = fbb.create_string(&self.frame_id);
+        let poses = build_tables(fbb, &self.poses)?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, frame_id);
+        fbb.push_slot_always(8, poses);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for Quaternion {
+    const TYPE_NAME: &'static str = "foxglove.Quaternion";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/Quaternion.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let start = fbb.start_table();
+        fbb.push_slot::<f64>(4, self.x, 0.0);
+        fbb.push_slot::<f64>(6, self.y, 0.0);
+        fbb.push_slot::<f64>(8, self.z, 0.0);
+        fbb.push_slot::<f64>(10, self.w, 1.0);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for RawImage {
+    const TYPE_NAME: &'static str = "foxglove.RawImage";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/RawImage.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let frame_id = fbb.create_string(&self.frame_id);
+        let encoding = fbb.create_string(&self.encoding);
+        let data = fbb.create_vector(&self.data[..]);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, frame_id);
+        fbb.push_slot::<u32>(8, self.width, 0);
+        fbb.push_slot::<u32>(10, self.height, 0);
+        fbb.push_slot_always(12, encoding);
+        fbb.push_slot::<u32>(14, self.step, 0);
+        fbb.push_slot_always(16, data);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for SceneEntity {
+    const TYPE_NAME: &'static str = "foxglove.SceneEntity";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/SceneEntity.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let frame_id = fbb.create_string(&self.frame_id);
+        let id = fbb.create_string(&self.id);
+        let metadata = build_tables(fbb, &self.metadata)?;
+        let arrows = build_tables(fbb, &self.arrows)?;
+        let cubes = build_tables(fbb, &self.cubes)?;
+        let spheres = build_tables(fbb, &self.spheres)?;
+        let cylinders = build_tables(fbb, &self.cylinders)?;
+        let lines = build_tables(fbb, &self.lines)?;
+        let triangles = build_tables(fbb, &self.triangles)?;
+        let texts = build_tables(fbb, &self.texts)?;
+        let models = build_tables(fbb, &self.models)?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, frame_id);
+        fbb.push_slot_always(8, id);
+        fbb.push_slot_always(10, Duration::from(self.lifetime.as_ref()));
+        fbb.push_slot::<bool>(12, self.frame_locked, false);
+        fbb.push_slot_always(14, metadata);
+        fbb.push_slot_always(16, arrows);
+        fbb.push_slot_always(18, cubes);
+        fbb.push_slot_always(20, spheres);
+        fbb.push_slot_always(22, cylinders);
+        fbb.push_slot_always(24, lines);
+        fbb.push_slot_always(26, triangles);
+        fbb.push_slot_always(28, texts);
+        fbb.push_slot_always(30, models);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for SceneEntityDeletion {
+    const TYPE_NAME: &'static str = "foxglove.SceneEntityDeletion";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/SceneEntityDeletion.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let id = fbb.create_string(&self.id);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot::<u8>(6, enum_value("type", self.r#type)?, 0);
+        fbb.push_slot_always(8, id);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for SceneUpdate {
+    const TYPE_NAME: &'static str = "foxglove.SceneUpdate";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/SceneUpdate.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let deletions = build_tables(fbb, &self.deletions)?;
+        let entities = build_tables(fbb, &self.entities)?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, deletions);
+        fbb.push_slot_always(6, entities);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for SpherePrimitive {
+    const TYPE_NAME: &'static str = "foxglove.SpherePrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/SpherePrimitive.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let pose = build_table(fbb, self.pose.as_ref())?;
+        let size = build_table(fbb, self.size.as_ref())?;
+        let color = build_table(fbb, self.color.as_ref())?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, pose);
+        fbb.push_slot_always(6, size);
+        fbb.push_slot_always(8, color);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for TextAnnotation {
+    const TYPE_NAME: &'static str = "foxglove.TextAnnotation";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/TextAnnotation.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let position = build_table(fbb, self.position.as_ref())?;
+        let text = fbb.create_string(&self.text);
+        let text_color = build_table(fbb, self.text_color.as_ref())?;
+        let background_color = build_table(fbb, self.background_color.as_ref())?;
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, Time::from(self.timestamp.as_ref()));
+        fbb.push_slot_always(6, position);
+        fbb.push_slot_always(8, text);
+        fbb.push_slot::<f64>(10, self.font_size, 12.0);
+        fbb.push_slot_always(12, text_color);
+        fbb.push_slot_always(14, background_color);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for TextPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.TextPrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/TextPrimitive.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let pose = build_table(fbb, self.pose.as_ref())?;
+        let color = build_table(fbb, self.color.as_ref())?;
+        let text = fbb.create_string(&self.text);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, pose);
+        fbb.push_slot::<bool>(6, self.billboard, false);
+        fbb.push_slot::<f64>(8, self.font_size, 0.0);
+        fbb.push_slot::<bool>(10, self.scale_invariant, false);
+        fbb.push_slot_always(12, color);
+        fbb.push_slot_always(14, text);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for TriangleListPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.TriangleListPrimitive";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/TriangleListPrimitive.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let pose = build_table(fbb, self.pose.as_ref())?;
+        let points = build_tables(fbb, &self.points)?;
+        let color = build_table(fbb, self.color.as_ref())?;
+        let colors = build_tables(fbb, &self.colors)?;
+        let indices = fbb.create_vector(&self.indices[..]);
+        let start = fbb.start_table();
+        fbb.push_slot_always(4, pose);
+        fbb.push_slot_always(6, points);
+        fbb.push_slot_always(8, color);
+        fbb.push_slot_always(10, colors);
+        fbb.push_slot_always(12, indices);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for Vector2 {
+    const TYPE_NAME: &'static str = "foxglove.Vector2";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/Vector2.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let start = fbb.start_table();
+        fbb.push_slot::<f64>(4, self.x, 1.0);
+        fbb.push_slot::<f64>(6, self.y, 1.0);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl FlatBufferEncode for Vector3 {
+    const TYPE_NAME: &'static str = "foxglove.Vector3";
+    const BINARY_SCHEMA: &'static [u8] = include_bytes!("data/Vector3.bfbs");
+
+    fn build_flatbuffer<'fbb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fbb>,
+    ) -> Result<WIPOffset<TableFinishedWIPOffset>, EncodeError> {
+        let start = fbb.start_table();
+        fbb.push_slot::<f64>(4, self.x, 1.0);
+        fbb.push_slot::<f64>(6, self.y, 1.0);
+        fbb.push_slot::<f64>(8, self.z, 1.0);
+        Ok(fbb.end_table(start))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
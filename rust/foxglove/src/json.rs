@@ -0,0 +1,186 @@
+//! JSON encoding for well-known Foxglove schemas.
+//!
+//! The [schema types](crate::schemas) are encoded as protobuf by default. When the `serde`
+//! feature is enabled, they implement [`Serialize`] and [`Deserialize`](serde::Deserialize), and
+//! can be logged as `json`-encoded messages by wrapping the message type in [`Json`]. The channel
+//! schema is the corresponding Foxglove JSON schema, and the serialized representation matches
+//! it:
+//!
+//! - Field names are the same as in the schema, for example `K` for [`CameraCalibration::k`].
+//! - Timestamps and durations are objects with `sec` and `nsec` fields.
+//! - Enums are represented by their integer values.
+//! - Bytes are base64-encoded strings.
+//! - Absent nested messages are omitted.
+//!
+//! The choice is made per channel, so protobuf and JSON channels can be used side by side.
+//!
+//! [`CameraCalibration::k`]: crate::schemas::CameraCalibration::k
+//!
+//! ```no_run
+//! use foxglove::json::Json;
+//! use foxglove::schemas::{Log, log::Level};
+//! use foxglove::TypedChannel;
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! // Logged with the schema "foxglove.Log".
+//! let channel = TypedChannel::<Json<Log>>::new("/log")?;
+//! channel.log(&Json(Log {
+//!     level: Level::Info.into(),
+//!     message: "Hello, JSON!".to_string(),
+//!     ..Default::default()
+//! }));
+//! # Ok(()) }
+//! ```
+
+use crate::validate::ValidationError;
+use crate::{Encode, Schema};
+use bytes::BufMut;
+use serde::Serialize;
+
+#[rustfmt::skip]
+mod impls;
+
+/// A message that can be encoded as JSON, with a corresponding JSON schema.
+///
+/// This trait is implemented for the well-known [schema types](crate::schemas). Wrap a message in
+/// [`Json`] to log it.
+pub trait JsonEncode: Serialize {
+    /// The schema name, such as `foxglove.Log`.
+    const TYPE_NAME: &'static str;
+
+    /// The JSON schema for the message.
+    const JSON_SCHEMA: &'static str;
+
+    /// Optional. Checks the invariants of the message; see [`Encode::validate`].
+    fn validate(&self) -> Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+/// A wrapper for logging a message with JSON encoding.
+///
+/// Use `TypedChannel<Json<T>>` to create a channel with message encoding `json`, and schema
+/// encoding `jsonschema`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Json<T>(pub T);
+
+impl<T> From<T> for Json<T> {
+    fn from(msg: T) -> Self {
+        Self(msg)
+    }
+}
+
+impl<T: JsonEncode> Encode for Json<T> {
+    type Error = serde_json::Error;
+
+    fn get_schema() -> Option<Schema> {
+        Some(Schema::new(
+            T::TYPE_NAME,
+            "jsonschema",
+            T::JSON_SCHEMA.as_bytes(),
+        ))
+    }
+
+    fn get_message_encoding() -> String {
+        "json".to_string()
+    }
+
+    fn encode(&self, buf: &mut impl BufMut) -> Result<(), Self::Error> {
+        serde_json::to_writer(buf.writer(), &self.0)
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.0.validate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::{CameraCalibration, CompressedImage, Log, SceneEntity, Timestamp};
+    use serde_json::{json, Value};
+
+    fn encode<T: JsonEncode>(msg: T) -> Value {
+        let mut buf = vec![];
+        Json(msg).encode(&mut buf).unwrap();
+        serde_json::from_slice(&buf).unwrap()
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Json::<Log>::get_schema().unwrap();
+        assert_eq!(schema.name, "foxglove.Log");
+        assert_eq!(schema.encoding, "jsonschema");
+        let value: Value = serde_json::from_slice(&schema.data).unwrap();
+        assert_eq!(value["title"], "foxglove.Log");
+        assert_eq!(Json::<Log>::get_message_encoding(), "json");
+    }
+
+    #[test]
+    fn test_encode_matches_schema() {
+        let value = encode(Log {
+            timestamp: Some(Timestamp::new(1, 2)),
+            level: 2,
+            message: "hi".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(
+            value,
+            json!({
+                "timestamp": {"sec": 1, "nsec": 2},
+                "level": 2,
+                "message": "hi",
+                "name": "",
+                "file": "",
+                "line": 0,
+            })
+        );
+
+        // Absent messages are omitted.
+        let value = encode(Log::default());
+        assert!(value.get("timestamp").is_none());
+
+        // Field names match the schema.
+        let value = encode(CameraCalibration {
+            k: vec![1.0; 9],
+            ..Default::default()
+        });
+        assert_eq!(value["K"], json!(vec![1.0; 9]));
+
+        // Bytes are base64-encoded.
+        let value = encode(CompressedImage {
+            data: vec![1, 2, 3].into(),
+            ..Default::default()
+        });
+        assert_eq!(value["data"], "AQID");
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let image: CompressedImage = serde_json::from_value(json!({
+            "timestamp": {"sec": 1, "nsec": 2},
+            "data": "AQID",
+            "format": "png",
+        }))
+        .unwrap();
+        assert_eq!(
+            image,
+            CompressedImage {
+                timestamp: Some(Timestamp::new(1, 2)),
+                data: vec![1, 2, 3].into(),
+                format: "png".to_string(),
+                ..Default::default()
+            }
+        );
+
+        // Missing fields take their default values.
+        let entity: SceneEntity = serde_json::from_value(json!({"id": "a"})).unwrap();
+        assert_eq!(
+            entity,
+            SceneEntity {
+                id: "a".to_string(),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,346 @@
+// This file is @generated by foxglove-proto-gen
+use super::JsonEncode;
+use crate::schemas::*;
+use crate::validate::{validate, ValidationError};
+
+impl JsonEncode for ArrowPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.ArrowPrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("data/ArrowPrimitive.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for CameraCalibration {
+    const TYPE_NAME: &'static str = "foxglove.CameraCalibration";
+    const JSON_SCHEMA: &'static str = include_str!("data/CameraCalibration.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for CircleAnnotation {
+    const TYPE_NAME: &'static str = "foxglove.CircleAnnotation";
+    const JSON_SCHEMA: &'static str = include_str!("data/CircleAnnotation.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for Color {
+    const TYPE_NAME: &'static str = "foxglove.Color";
+    const JSON_SCHEMA: &'static str = include_str!("data/Color.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for CompressedImage {
+    const TYPE_NAME: &'static str = "foxglove.CompressedImage";
+    const JSON_SCHEMA: &'static str = include_str!("data/CompressedImage.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for CompressedVideo {
+    const TYPE_NAME: &'static str = "foxglove.CompressedVideo";
+    const JSON_SCHEMA: &'static str = include_str!("data/CompressedVideo.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for CubePrimitive {
+    const TYPE_NAME: &'static str = "foxglove.CubePrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("data/CubePrimitive.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for CylinderPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.CylinderPrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("data/CylinderPrimitive.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for FrameTransform {
+    const TYPE_NAME: &'static str = "foxglove.FrameTransform";
+    const JSON_SCHEMA: &'static str = include_str!("data/FrameTransform.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for FrameTransforms {
+    const TYPE_NAME: &'static str = "foxglove.FrameTransforms";
+    const JSON_SCHEMA: &'static str = include_str!("data/FrameTransforms.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for GeoJson {
+    const TYPE_NAME: &'static str = "foxglove.GeoJSON";
+    const JSON_SCHEMA: &'static str = include_str!("data/GeoJSON.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for Grid {
+    const TYPE_NAME: &'static str = "foxglove.Grid";
+    const JSON_SCHEMA: &'static str = include_str!("data/Grid.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for ImageAnnotations {
+    const TYPE_NAME: &'static str = "foxglove.ImageAnnotations";
+    const JSON_SCHEMA: &'static str = include_str!("data/ImageAnnotations.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for KeyValuePair {
+    const TYPE_NAME: &'static str = "foxglove.KeyValuePair";
+    const JSON_SCHEMA: &'static str = include_str!("data/KeyValuePair.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for LaserScan {
+    const TYPE_NAME: &'static str = "foxglove.LaserScan";
+    const JSON_SCHEMA: &'static str = include_str!("data/LaserScan.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for LinePrimitive {
+    const TYPE_NAME: &'static str = "foxglove.LinePrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("data/LinePrimitive.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for LocationFix {
+    const TYPE_NAME: &'static str = "foxglove.LocationFix";
+    const JSON_SCHEMA: &'static str = include_str!("data/LocationFix.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for Log {
+    const TYPE_NAME: &'static str = "foxglove.Log";
+    const JSON_SCHEMA: &'static str = include_str!("data/Log.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for ModelPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.ModelPrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("data/ModelPrimitive.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for PackedElementField {
+    const TYPE_NAME: &'static str = "foxglove.PackedElementField";
+    const JSON_SCHEMA: &'static str = include_str!("data/PackedElementField.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for Point2 {
+    const TYPE_NAME: &'static str = "foxglove.Point2";
+    const JSON_SCHEMA: &'static str = include_str!("data/Point2.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for Point3 {
+    const TYPE_NAME: &'static str = "foxglove.Point3";
+    const JSON_SCHEMA: &'static str = include_str!("data/Point3.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for PointCloud {
+    const TYPE_NAME: &'static str = "foxglove.PointCloud";
+    const JSON_SCHEMA: &'static str = include_str!("data/PointCloud.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for PointsAnnotation {
+    const TYPE_NAME: &'static str = "foxglove.PointsAnnotation";
+    const JSON_SCHEMA: &'static str = include_str!("data/PointsAnnotation.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for Pose {
+    const TYPE_NAME: &'static str = "foxglove.Pose";
+    const JSON_SCHEMA: &'static str = include_str!("data/Pose.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for PoseInFrame {
+    const TYPE_NAME: &'static str = "foxglove.PoseInFrame";
+    const JSON_SCHEMA: &'static str = include_str!("data/PoseInFrame.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for PosesInFrame {
+    const TYPE_NAME: &'static str = "foxglove.PosesInFrame";
+    const JSON_SCHEMA: &'static str = include_str!("data/PosesInFrame.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for Quaternion {
+    const TYPE_NAME: &'static str = "foxglove.Quaternion";
+    const JSON_SCHEMA: &'static str = include_str!("data/Quaternion.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for RawImage {
+    const TYPE_NAME: &'static str = "foxglove.RawImage";
+    const JSON_SCHEMA: &'static str = include_str!("data/RawImage.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for SceneEntity {
+    const TYPE_NAME: &'static str = "foxglove.SceneEntity";
+    const JSON_SCHEMA: &'static str = include_str!("data/SceneEntity.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for SceneEntityDeletion {
+    const TYPE_NAME: &'static str = "foxglove.SceneEntityDeletion";
+    const JSON_SCHEMA: &'static str = include_str!("data/SceneEntityDeletion.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for SceneUpdate {
+    const TYPE_NAME: &'static str = "foxglove.SceneUpdate";
+    const JSON_SCHEMA: &'static str = include_str!("data/SceneUpdate.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for SpherePrimitive {
+    const TYPE_NAME: &'static str = "foxglove.SpherePrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("data/SpherePrimitive.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for TextAnnotation {
+    const TYPE_NAME: &'static str = "foxglove.TextAnnotation";
+    const JSON_SCHEMA: &'static str = include_str!("data/TextAnnotation.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for TextPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.TextPrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("data/TextPrimitive.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for TriangleListPrimitive {
+    const TYPE_NAME: &'static str = "foxglove.TriangleListPrimitive";
+    const JSON_SCHEMA: &'static str = include_str!("data/TriangleListPrimitive.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for Vector2 {
+    const TYPE_NAME: &'static str = "foxglove.Vector2";
+    const JSON_SCHEMA: &'static str = include_str!("data/Vector2.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl JsonEncode for Vector3 {
+    const TYPE_NAME: &'static str = "foxglove.Vector3";
+    const JSON_SCHEMA: &'static str = include_str!("data/Vector3.json");
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
@@ -46,6 +46,31 @@
 //! conjunction with [`TypedChannel`] for type-safe logging, which ensures at compile time that
 //! messages logged to a channel all share a common schema.
 //!
+//! These types are encoded as protobuf by default. The [`ros1`] and [`ros2`] modules provide
+//! alternative encodings, for compatibility with ROS 1 and ROS 2 tooling. With the `flatbuffers`
+//! feature, the `flatbuffer` module provides FlatBuffers encoding. With the `serde` feature, the
+//! types also implement `Serialize` and `Deserialize`, and the `json` module provides JSON
+//! encoding.
+//!
+//! With the `nalgebra` or `glam` features, the geometry types such as [`Pose`](schemas::Pose),
+//! [`Vector3`](schemas::Vector3) and [`Quaternion`](schemas::Quaternion) convert to and from the
+//! corresponding types in those crates, with [`From`] and [`Into`]. Basic operations, such as
+//! [`Quaternion::from_euler`](schemas::Quaternion::from_euler) and
+//! [`Pose::compose`](schemas::Pose::compose), are available without additional dependencies.
+//!
+//! The [`point_cloud`] module provides a builder and reader for [`PointCloud`](schemas::PointCloud)
+//! messages, which store points as packed binary data.
+//!
+//! With the `image` feature, images from the `image` crate convert to
+//! [`RawImage`](schemas::RawImage) with [`From`], and can be encoded as a
+//! [`CompressedImage`](schemas::CompressedImage) with `CompressedImage::from_image`.
+//!
+//! With the `geo` feature, `geo-types` geometries and `geojson` values convert to
+//! [`GeoJson`](schemas::GeoJson) with [`From`], and GeoJSON strings can be parsed and checked with
+//! [`FromStr`](std::str::FromStr). [`LocationFix::new`](schemas::LocationFix::new) and
+//! [`LocationFix::with_diagonal_covariance`](schemas::LocationFix::with_diagonal_covariance) are
+//! available without additional dependencies.
+//!
 //! ### Custom data
 //!
 //! You can also define your own custom data types by implementing the [`Encode`] trait. This
@@ -69,6 +94,17 @@
 //! # Ok(()) }
 //! ```
 //!
+//! JSON can be bulky for large messages. With the `msgpack` or `cbor` features, the same types can
+//! be logged with MessagePack or CBOR encoding instead, by wrapping them in `msgpack::MsgPack` or
+//! `cbor::Cbor`. The channel schema is the same JSON schema.
+//!
+//! With the `derive` feature, you can instead derive [`Encode`] to log a struct with protobuf
+//! encoding. The protobuf schema is generated from the struct definition, so no `.proto` files
+//! are needed. See the [`protobuf`] module for details.
+//!
+//! If you already have `.proto` files compiled with `prost`, use [`impl_prost_encode!`] to log the
+//! generated types with their original schemas.
+//!
 //! ### Static Channels
 //!
 //! A common pattern is to create the channels once as static variables, and then use them
@@ -114,6 +150,13 @@
 //! # Ok(()) }
 //! ```
 //!
+//! If the process exits without closing the handle, the file is left without a summary, and the
+//! chunk in progress is lost. A [`FlushPolicy`] bounds how far the file on disk lags behind the
+//! logged messages, and [`recover_mcap`] rewrites such a file as a valid MCAP file.
+//!
+//! To record to an output which does not support seeking, such as a pipe or a socket, use
+//! [`McapWriter::create_streaming`]. To record only some topics, use [`McapWriter::topics`].
+//!
 //! ### Live visualization server
 //!
 //! You can use the SDK to publish messages to the Foxglove app.
@@ -129,6 +172,10 @@
 //! Note that the server remains running until the process exits, even if the handle is dropped.
 //! Use [`stop`](`WebSocketServerHandle::stop`) to shut down the server explicitly.
 //!
+//! To let clients start and stop MCAP recordings on the server, register
+//! [`RecordingServices`](websocket::RecordingServices) with
+//! [`WebSocketServer::recording_services`].
+//!
 //! [app-connect]: https://docs.foxglove.dev/docs/connecting-to-data/frameworks/custom#connect
 //!
 //! ```no_run
@@ -153,45 +200,148 @@
 //! your application to use tokio.
 //!
 //! [tokio]: https://docs.rs/tokio/latest/tokio/
+//!
+//! # Log contexts
+//!
+//! Channels and sinks are connected through a [`LogContext`]. By default, everything is
+//! registered with the [global context](LogContext::global), so every channel is logged to every
+//! sink.
+//!
+//! To log independent streams of data within one process, create a scoped context, and attach
+//! channels, MCAP writers, and servers to it explicitly. Topic names only need to be unique within
+//! a context.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use foxglove::{ChannelBuilder, LogContext, WebSocketServer};
+//! use foxglove::schemas::FrameTransform;
+//!
+//! # async fn func() -> Result<(), foxglove::FoxgloveError> {
+//! let robot = Arc::new(LogContext::new());
+//! let server = WebSocketServer::new()
+//!     .context(&robot)
+//!     .bind("127.0.0.1", 8766)
+//!     .start()
+//!     .await?;
+//! let tf = ChannelBuilder::new("/tf")
+//!     .with_context(&robot)
+//!     .build_typed::<FrameTransform>()?;
+//! # Ok(()) }
+//! ```
+//!
+//! ## Topic namespaces
+//!
+//! Reusable components can log to relative topics (without a leading `/`), and leave it to the
+//! application to decide where those topics are placed. A [`TopicRemap`] installed on a context
+//! namespaces and remaps topics as channels are created, and
+//! [`ChannelBuilder::namespace`] places a single channel in a namespace.
+//!
+//! ```no_run
+//! use foxglove::{ChannelBuilder, LogContext};
+//! use foxglove::schemas::RawImage;
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! let remap = "camera/image:=camera/image_raw".parse()?;
+//! LogContext::global().set_topic_remap(remap);
+//!
+//! // Logs to "/left/camera/image_raw" and "/right/camera/image_raw".
+//! let left = ChannelBuilder::new("camera/image")
+//!     .namespace("/left")
+//!     .build_typed::<RawImage>()?;
+//! let right = ChannelBuilder::new("camera/image")
+//!     .namespace("/right")
+//!     .build_typed::<RawImage>()?;
+//! # Ok(()) }
+//! ```
+//!
+//! ## Message validation
+//!
+//! Well-known messages which violate their schema's invariants, such as a point cloud whose data
+//! is not a whole number of points, are logged without error, but do not render in the Foxglove
+//! app. During development, [`LogContext::set_validation`] checks messages before they are logged,
+//! and reports violations with `tracing` or a callback. See the [`validate`] module for details.
+//!
+//! # Transforms
+//!
+//! The [`transform_tree`] module maintains a tree of coordinate frames, built from static and
+//! timestamped [`FrameTransform`](schemas::FrameTransform)s. It looks up the transform between any
+//! two frames, and can publish the transforms on a `/tf` channel in the background.
+//!
+//! # Scenes
+//!
+//! A [`SceneManager`](scene_manager::SceneManager) tracks the entities published in
+//! [`SceneUpdate`](schemas::SceneUpdate) messages, deletes the ones which are no longer present,
+//! and batches changes into one update per tick. See the [`scene_manager`] module for details.
+//!
+//! # Video
+//!
+//! An [`AnnexBFramer`](video::AnnexBFramer) splits an H.264 or H.265 byte stream into
+//! [`CompressedVideo`](schemas::CompressedVideo) messages of one frame each, and inserts the
+//! parameter sets needed to decode each keyframe. See the [`video`] module for details.
 
 #![warn(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
+// Allows code generated by `foxglove-derive` to refer to this crate in tests.
+#[cfg(test)]
+extern crate self as foxglove;
+
 use thiserror::Error;
 
+#[cfg(feature = "cbor")]
+pub mod cbor;
 mod channel;
 mod channel_builder;
 mod collection;
 pub mod convert;
 mod cow_vec;
 mod encode;
+#[cfg(feature = "flatbuffers")]
+pub mod flatbuffer;
+#[cfg(feature = "serde")]
+pub mod json;
 mod log_context;
 mod log_sink;
 mod log_sink_set;
 mod mcap_writer;
 mod metadata;
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
+pub mod point_cloud;
+pub mod protobuf;
+mod ros;
+pub mod ros1;
+pub mod ros2;
 mod runtime;
+pub mod scene_manager;
 pub mod schemas;
+mod schemas_ext;
 mod schemas_wkt;
 #[cfg(test)]
 mod tests;
 #[cfg(test)]
 mod testutil;
 mod time;
+mod topic_remap;
+pub mod transform_tree;
+pub mod validate;
+pub mod video;
 pub mod websocket;
 mod websocket_server;
 
 pub use channel::{Channel, Schema};
 pub use channel_builder::ChannelBuilder;
 pub use encode::{Encode, TypedChannel};
-#[doc(hidden)]
+#[cfg(feature = "derive")]
+pub use foxglove_derive::Encode;
 pub use log_context::LogContext;
 pub use log_sink::LogSink;
-pub use mcap_writer::{McapWriter, McapWriterHandle};
+pub use mcap_writer::{recover_mcap, FlushPolicy, McapWriter, McapWriterHandle, RecoveryReport};
 pub use metadata::{Metadata, PartialMetadata};
 pub(crate) use runtime::get_runtime_handle;
 pub use runtime::shutdown_runtime;
 pub(crate) use time::nanoseconds_since_epoch;
+pub use topic_remap::TopicRemap;
 pub use websocket_server::{WebSocketServer, WebSocketServerBlockingHandle, WebSocketServerHandle};
 
 /// An error type for errors generated by this crate.
@@ -219,6 +369,9 @@ pub enum FoxgloveError {
     /// A channel for the same topic has already been registered.
     #[error("Channel for topic {0} already exists in registry")]
     DuplicateChannel(String),
+    /// A topic remapping rule is invalid.
+    #[error("Invalid topic remapping: {0}")]
+    InvalidTopicRemap(String),
     /// A service with the same name is already registered.
     #[error("Service {0} already exists in registry")]
     DuplicateService(String),
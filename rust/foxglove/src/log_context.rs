@@ -1,32 +1,115 @@
 use crate::log_sink_set::LogSinkSet;
-use crate::{Channel, FoxgloveError, LogSink};
+use crate::validate::ValidationMode;
+use crate::{Channel, FoxgloveError, LogSink, TopicRemap};
 use parking_lot::RwLock;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::{Arc, OnceLock};
 
-/// A thread-safe wrapper around one or more Sinks, that writes to all of them.
+/// A collection of channels and the sinks that their messages are logged to.
+///
+/// Every channel belongs to exactly one context, and is connected to every sink registered with
+/// that context. Topics are unique within a context, but the same topic may be used in different
+/// contexts.
+///
+/// Most applications only need the [global context](LogContext::global), which is used by
+/// default by [`TypedChannel::new`](crate::TypedChannel::new), [`ChannelBuilder`],
+/// [`McapWriter`](crate::McapWriter), and [`WebSocketServer`](crate::WebSocketServer).
+///
+/// Applications that host several independent sources of data in the same process, such as
+/// several simulated robots, can create a scoped context for each source. Channels created with
+/// [`ChannelBuilder::with_context`], writers created with
+/// [`McapWriter::context`](crate::McapWriter::context), and servers created with
+/// [`WebSocketServer::context`](crate::WebSocketServer::context) are isolated to that context.
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use foxglove::{ChannelBuilder, LogContext, McapWriter};
+/// use foxglove::schemas::Log;
+///
+/// # fn func() -> Result<(), foxglove::FoxgloveError> {
+/// let left = Arc::new(LogContext::new());
+/// let right = Arc::new(LogContext::new());
+///
+/// // Both robots can log to the same topic, each to its own file.
+/// let left_mcap = McapWriter::new().context(&left).create_new_buffered_file("left.mcap")?;
+/// let right_mcap = McapWriter::new().context(&right).create_new_buffered_file("right.mcap")?;
+/// let left_log = ChannelBuilder::new("/log").with_context(&left).build_typed::<Log>()?;
+/// let right_log = ChannelBuilder::new("/log").with_context(&right).build_typed::<Log>()?;
+/// # Ok(()) }
+/// ```
+///
+/// [`ChannelBuilder`]: crate::ChannelBuilder
+/// [`ChannelBuilder::with_context`]: crate::ChannelBuilder::with_context
 pub struct LogContext {
     // Map of channels by topic.
     channels: RwLock<HashMap<String, Arc<Channel>>>,
     sinks: LogSinkSet,
+    topic_remap: RwLock<TopicRemap>,
+    validation: Arc<RwLock<ValidationMode>>,
 }
 
 impl LogContext {
-    /// Instantiates a new log context.
+    /// Instantiates a new log context, with no channels and no sinks.
+    ///
+    /// Writers and servers keep their context alive, so the context is wrapped in an [`Arc`] and
+    /// shared with them.
     pub fn new() -> Self {
         Self {
             channels: RwLock::new(HashMap::new()),
             sinks: LogSinkSet::new(),
+            topic_remap: RwLock::default(),
+            validation: Arc::default(),
         }
     }
 
     /// Returns a reference to the global log context.
     ///
     /// If there is no global log context, this function instantiates one.
-    pub fn global() -> &'static LogContext {
-        static DEFAULT_CONTEXT: OnceLock<LogContext> = OnceLock::new();
-        DEFAULT_CONTEXT.get_or_init(LogContext::new)
+    pub fn global() -> &'static Arc<LogContext> {
+        static DEFAULT_CONTEXT: OnceLock<Arc<LogContext>> = OnceLock::new();
+        DEFAULT_CONTEXT.get_or_init(|| Arc::new(LogContext::new()))
+    }
+
+    /// Sets the rules used to resolve the topics of channels created in this context.
+    ///
+    /// The rules are applied by [`ChannelBuilder`](crate::ChannelBuilder) when a channel is
+    /// built, so they do not affect channels that already exist. See [`TopicRemap`] for details.
+    pub fn set_topic_remap(&self, remap: TopicRemap) {
+        *self.topic_remap.write() = remap;
+    }
+
+    /// Returns the rules used to resolve the topics of channels created in this context.
+    pub fn topic_remap(&self) -> TopicRemap {
+        self.topic_remap.read().clone()
+    }
+
+    /// Sets how typed channels in this context validate messages before logging them.
+    ///
+    /// The mode applies to every typed channel in the context, including channels that already
+    /// exist, unless the channel was built with its own mode by
+    /// [`ChannelBuilder::validation`](crate::ChannelBuilder::validation).
+    /// Validation is off by default. See [`validate`](crate::validate) for details.
+    pub fn set_validation(&self, mode: ValidationMode) {
+        *self.validation.write() = mode;
+    }
+
+    /// Returns how typed channels in this context validate messages.
+    pub fn validation(&self) -> ValidationMode {
+        self.validation.read().clone()
+    }
+
+    /// Returns the validation mode of the context, shared with the typed channels which read it
+    /// when logging.
+    pub(crate) fn shared_validation(&self) -> Arc<RwLock<ValidationMode>> {
+        self.validation.clone()
+    }
+
+    /// Resolves the topic for a new channel, within an optional channel namespace.
+    pub(crate) fn resolve_topic(&self, topic: &str, namespace: Option<&str>) -> String {
+        self.topic_remap
+            .read()
+            .resolve_with_namespace(topic, namespace)
     }
 
     /// Returns the channel for the specified topic, if there is one.
@@ -145,6 +228,14 @@ impl Default for LogContext {
     }
 }
 
+impl std::fmt::Debug for LogContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LogContext")
+            .field("channels", &self.channels.read().keys())
+            .finish_non_exhaustive()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::channel::ChannelId;
@@ -152,7 +243,7 @@ mod tests {
     use crate::log_context::*;
     use crate::log_sink_set::ERROR_LOGGING_MESSAGE;
     use crate::testutil::{ErrorSink, MockSink, RecordingSink};
-    use crate::{nanoseconds_since_epoch, Channel, PartialMetadata, Schema};
+    use crate::{nanoseconds_since_epoch, Channel, ChannelBuilder, PartialMetadata, Schema};
     use std::sync::atomic::AtomicU32;
     use std::sync::Arc;
     use tracing_test::traced_test;
@@ -282,6 +373,43 @@ mod tests {
         assert_eq!(metadata.publish_time, opts.publish_time.unwrap());
     }
 
+    #[test]
+    fn test_topic_remap_applies_to_new_channels() {
+        let ctx = LogContext::new();
+        let builder = |topic: &str| {
+            ChannelBuilder::new(topic)
+                .message_encoding("json")
+                .with_context(&ctx)
+        };
+
+        let before = builder("camera/image").build().unwrap();
+        assert_eq!(before.topic(), "camera/image");
+
+        ctx.set_topic_remap(
+            TopicRemap::new()
+                .namespace("/robot")
+                .remap("camera/image", "camera/image_raw"),
+        );
+        let left = builder("camera/image").namespace("left").build().unwrap();
+        let right = builder("camera/image").namespace("right").build().unwrap();
+        let tf = builder("/tf").namespace("left").build().unwrap();
+        assert_eq!(left.topic(), "/robot/left/camera/image_raw");
+        assert_eq!(right.topic(), "/robot/right/camera/image_raw");
+        assert_eq!(tf.topic(), "/tf");
+        assert!(ctx
+            .get_channel_by_topic("/robot/left/camera/image_raw")
+            .is_some());
+
+        // Existing channels are not renamed.
+        assert!(ctx.get_channel_by_topic("camera/image").is_some());
+
+        // Topics are still unique after resolution.
+        assert!(matches!(
+            builder("camera/image").namespace("left").build(),
+            Err(FoxgloveError::DuplicateChannel(topic)) if topic == "/robot/left/camera/image_raw"
+        ));
+    }
+
     #[traced_test]
     #[test]
     fn test_log_msg_no_sinks() {
@@ -1,25 +1,126 @@
 //! MCAP writer
 
+use std::collections::HashSet;
 use std::fs::File;
 use std::io::{BufWriter, Seek};
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
 use std::{fmt::Debug, io::Write};
 
+use crate::runtime::get_runtime_handle;
 use crate::{FoxgloveError, LogContext, LogSink};
+use mcap::write::NoSeek;
 use mcap::WriteOptions;
+use tokio::task::JoinHandle;
 
 mod mcap_sink;
+mod recovery;
 use mcap_sink::McapSink;
+pub use recovery::{recover_mcap, RecoveryReport};
+
+/// A policy for flushing an MCAP recording to the writer while it is in progress.
+///
+/// By default, data is written as each chunk is completed, and the summary is written when the
+/// recording is closed. If the process exits without closing the recording, the chunk in progress
+/// is lost, and the file has no summary. Such a file can be repaired with [`recover_mcap`].
+///
+/// Flushing more frequently bounds the amount of data that can be lost. Flushing after an
+/// [interval](FlushPolicy::interval) or a [number of bytes](FlushPolicy::max_unflushed_bytes)
+/// completes the chunk in progress, so frequent flushes result in smaller chunks, which compress
+/// less well.
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use foxglove::{FlushPolicy, McapWriter};
+///
+/// let handle = McapWriter::new()
+///     .flush_policy(
+///         FlushPolicy::new()
+///             .interval(Duration::from_secs(5))
+///             .sync(true),
+///     )
+///     .create_new_buffered_file("recording.mcap")?;
+/// # Ok::<(), foxglove::FoxgloveError>(())
+/// ```
+#[must_use]
+#[derive(Debug, Clone, Default)]
+pub struct FlushPolicy {
+    interval: Option<Duration>,
+    max_unflushed_bytes: Option<u64>,
+    chunks: Option<u64>,
+    sync: bool,
+}
+
+impl FlushPolicy {
+    /// Creates a policy which flushes only when each chunk is completed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flushes messages within the given interval of being logged.
+    ///
+    /// Panics if the interval is zero.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        assert!(!interval.is_zero(), "flush interval must be non-zero");
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Flushes when the given number of bytes of message data have been logged since the last
+    /// flush.
+    ///
+    /// Since chunks also contain record headers, and may be compressed, this does not correspond
+    /// to the chunk size of the [`WriteOptions`]. To flush after each chunk, use
+    /// [`chunks`](Self::chunks).
+    pub fn max_unflushed_bytes(mut self, bytes: u64) -> Self {
+        self.max_unflushed_bytes = Some(bytes);
+        self
+    }
+
+    /// Flushes when the given number of chunks have been completed since the last flush.
+    ///
+    /// Unlike the other conditions, this does not complete the chunk in progress, so it does not
+    /// affect the size of chunks. It ensures that completed chunks do not remain in a buffer,
+    /// such as that of [`McapWriter::create_new_buffered_file`].
+    ///
+    /// Panics if the number of chunks is zero.
+    pub fn chunks(mut self, chunks: u64) -> Self {
+        assert!(chunks > 0, "number of chunks must be non-zero");
+        self.chunks = Some(chunks);
+        self
+    }
+
+    /// Whether to synchronize the file to disk after each flush, so that the data survives a
+    /// crash of the operating system, as well as the process.
+    ///
+    /// This applies to files created with [`McapWriter::create_new_buffered_file`]. Other writers
+    /// are flushed with [`Write::flush`], which may synchronize the data itself.
+    pub fn sync(mut self, sync: bool) -> Self {
+        self.sync = sync;
+        self
+    }
+}
 
 /// An MCAP writer for logging events.
 #[must_use]
 #[derive(Debug, Clone)]
-pub struct McapWriter(WriteOptions);
+pub struct McapWriter {
+    options: WriteOptions,
+    flush_policy: FlushPolicy,
+    topics: Option<HashSet<String>>,
+    context: Arc<LogContext>,
+}
 
 impl From<WriteOptions> for McapWriter {
     fn from(value: WriteOptions) -> Self {
-        Self(value.library(format!("foxglove-sdk-rs-{}", env!("CARGO_PKG_VERSION"))))
+        Self {
+            options: value.library(format!("foxglove-sdk-rs-{}", env!("CARGO_PKG_VERSION"))),
+            flush_policy: FlushPolicy::default(),
+            topics: None,
+            context: LogContext::global().clone(),
+        }
     }
 }
 
@@ -40,6 +141,29 @@ impl McapWriter {
         options.into()
     }
 
+    /// Sets the [`LogContext`] whose channels are logged to the MCAP file.
+    ///
+    /// By default, the writer is registered with the [global context](LogContext::global). The
+    /// writer keeps the context alive until the recording is closed.
+    pub fn context(mut self, ctx: &Arc<LogContext>) -> Self {
+        self.context = ctx.clone();
+        self
+    }
+
+    /// Sets the policy for flushing the recording to the writer while it is in progress.
+    pub fn flush_policy(mut self, policy: FlushPolicy) -> Self {
+        self.flush_policy = policy;
+        self
+    }
+
+    /// Sets the topics to log. Messages on other topics are not logged.
+    ///
+    /// By default, messages on all topics are logged.
+    pub fn topics(mut self, topics: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.topics = Some(topics.into_iter().map(Into::into).collect());
+        self
+    }
+
     /// Begins logging events to the specified writer.
     ///
     /// Returns a handle. When the handle is dropped, the recording will be flushed to the writer
@@ -49,9 +173,57 @@ impl McapWriter {
     where
         W: Write + Seek + Send + 'static,
     {
-        let writer = McapSink::new(writer, self.0)?;
-        LogContext::global().add_sink(writer.clone());
-        Ok(McapWriterHandle(writer))
+        self.create_with_sync_file(writer, None)
+    }
+
+    /// Begins logging events to a writer which does not support seeking, such as a pipe, standard
+    /// output, or a socket.
+    ///
+    /// Each chunk is buffered in memory until it is complete, instead of being written in place,
+    /// and the summary is written when the recording is closed. The writer can be recovered from
+    /// the [`NoSeek`] wrapper returned by [`McapWriterHandle::close`].
+    ///
+    /// ```no_run
+    /// # fn func() -> Result<(), foxglove::FoxgloveError> {
+    /// let mcap = foxglove::McapWriter::new().create_streaming(std::io::stdout())?;
+    /// # Ok(()) }
+    /// ```
+    pub fn create_streaming<W>(
+        mut self,
+        writer: W,
+    ) -> Result<McapWriterHandle<NoSeek<W>>, FoxgloveError>
+    where
+        W: Write + Send + 'static,
+    {
+        self.options = self.options.disable_seeking(true);
+        self.create(NoSeek::new(writer))
+    }
+
+    fn create_with_sync_file<W>(
+        self,
+        writer: W,
+        sync_file: Option<File>,
+    ) -> Result<McapWriterHandle<W>, FoxgloveError>
+    where
+        W: Write + Seek + Send + 'static,
+    {
+        let interval = self.flush_policy.interval;
+        let sink = McapSink::new(
+            writer,
+            self.options,
+            self.flush_policy,
+            self.topics,
+            sync_file,
+        )?;
+        let flush_task = interval.map(|interval| {
+            get_runtime_handle().spawn(flush_periodically(Arc::downgrade(&sink), interval))
+        });
+        self.context.add_sink(sink.clone());
+        Ok(McapWriterHandle {
+            sink,
+            context: self.context,
+            flush_task,
+        })
     }
 
     /// Creates a new write-only buffered file, and begins logging events to it.
@@ -69,8 +241,36 @@ impl McapWriter {
         P: AsRef<Path>,
     {
         let file = File::create_new(path)?;
+        let sync_file = if self.flush_policy.sync {
+            Some(file.try_clone()?)
+        } else {
+            None
+        };
         let writer = BufWriter::new(file);
-        self.create(writer)
+        self.create_with_sync_file(writer, sync_file)
+    }
+}
+
+/// Flushes the sink at the given interval, until it is closed.
+async fn flush_periodically<W>(sink: Weak<McapSink<W>>, interval: Duration)
+where
+    W: Write + Seek + Send + 'static,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    // The first tick completes immediately.
+    ticker.tick().await;
+    loop {
+        ticker.tick().await;
+        let Some(sink) = sink.upgrade() else {
+            break;
+        };
+        // Flushing performs blocking I/O.
+        match tokio::task::spawn_blocking(move || sink.flush()).await {
+            Ok(Ok(())) => (),
+            Ok(Err(FoxgloveError::SinkClosed)) | Err(_) => break,
+            Ok(Err(e)) => tracing::warn!("Failed to flush MCAP recording: {e}"),
+        }
     }
 }
 
@@ -79,7 +279,11 @@ impl McapWriter {
 /// When this handle is dropped, the writer will stop logging events, and flush any buffered data
 /// to the writer.
 #[must_use]
-pub struct McapWriterHandle<W: Write + Seek + Send + 'static>(Arc<McapSink<W>>);
+pub struct McapWriterHandle<W: Write + Seek + Send + 'static> {
+    sink: Arc<McapSink<W>>,
+    context: Arc<LogContext>,
+    flush_task: Option<JoinHandle<()>>,
+}
 
 impl<W: Write + Seek + Send + 'static> Debug for McapWriterHandle<W> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -88,6 +292,14 @@ impl<W: Write + Seek + Send + 'static> Debug for McapWriterHandle<W> {
 }
 
 impl<W: Write + Seek + Send + 'static> McapWriterHandle<W> {
+    /// Flushes the messages logged so far to the writer, without closing the recording.
+    ///
+    /// This completes the chunk in progress. The file does not have a summary until the
+    /// recording is closed.
+    pub fn flush(&self) -> Result<(), FoxgloveError> {
+        self.sink.flush()
+    }
+
     /// Stops logging events, flushes buffered data, and returns the writer.
     pub fn close(self) -> Result<W, FoxgloveError> {
         // It's safe to unwrap the `Option<W>` because `McapWriterHandle` doesn't implement clone,
@@ -96,9 +308,12 @@ impl<W: Write + Seek + Send + 'static> McapWriterHandle<W> {
     }
 
     fn finish(&self) -> Result<Option<W>, FoxgloveError> {
-        let sink = self.0.clone() as Arc<dyn LogSink>;
-        LogContext::global().remove_sink(&sink);
-        self.0.finish()
+        if let Some(task) = &self.flush_task {
+            task.abort();
+        }
+        let sink = self.sink.clone() as Arc<dyn LogSink>;
+        self.context.remove_sink(&sink);
+        self.sink.finish()
     }
 }
 
@@ -109,3 +324,188 @@ impl<W: Write + Seek + Send + 'static> Drop for McapWriterHandle<W> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ChannelBuilder;
+
+    /// A writer which does not implement [`Seek`].
+    struct Pipe(Vec<u8>);
+
+    impl Write for Pipe {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_create_streaming() {
+        let ctx = Arc::new(LogContext::new());
+        let mcap = McapWriter::new()
+            .context(&ctx)
+            .flush_policy(FlushPolicy::new().max_unflushed_bytes(16))
+            .create_streaming(Pipe(Vec::new()))
+            .expect("Failed to create writer");
+        let channel = ChannelBuilder::new("/test")
+            .message_encoding("json")
+            .with_context(&ctx)
+            .build()
+            .expect("Failed to create channel");
+        for i in 0..10 {
+            channel.log(format!(r#"{{"i": {i}}}"#).as_bytes());
+        }
+        let buf = mcap.close().expect("Failed to close writer").into_inner().0;
+
+        let messages = mcap::MessageStream::new(&buf)
+            .expect("Failed to create message stream")
+            .collect::<Result<Vec<_>, _>>()
+            .expect("Failed to read messages");
+        assert_eq!(messages.len(), 10);
+        let summary = mcap::Summary::read(&buf)
+            .expect("Failed to read summary")
+            .expect("Missing summary");
+        assert_eq!(summary.stats.expect("Missing statistics").message_count, 10);
+        assert_eq!(summary.chunk_indexes.len(), 5);
+    }
+
+    #[test]
+    fn test_topics() {
+        let ctx = Arc::new(LogContext::new());
+        let mcap = McapWriter::new()
+            .context(&ctx)
+            .topics(["/a"])
+            .create(std::io::Cursor::new(Vec::new()))
+            .expect("Failed to create writer");
+        for topic in ["/a", "/b"] {
+            ChannelBuilder::new(topic)
+                .message_encoding("json")
+                .with_context(&ctx)
+                .build()
+                .expect("Failed to create channel")
+                .log(b"{}");
+        }
+        let buf = mcap.close().expect("Failed to close writer").into_inner();
+
+        let topics = mcap::MessageStream::new(&buf)
+            .expect("Failed to create message stream")
+            .map(|msg| msg.expect("Failed to read message").channel.topic.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(topics, ["/a"]);
+    }
+
+    /// A writer which counts the number of times it is flushed.
+    struct CountFlushes<W> {
+        inner: W,
+        flushes: usize,
+    }
+
+    impl<W: Write> Write for CountFlushes<W> {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.inner.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.flushes += 1;
+            self.inner.flush()
+        }
+    }
+
+    impl<W: Seek> Seek for CountFlushes<W> {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    #[test]
+    fn test_flush_chunks() {
+        fn log_messages(ctx: &Arc<LogContext>) {
+            let channel = ChannelBuilder::new("/test")
+                .message_encoding("json")
+                .with_context(ctx)
+                .build()
+                .expect("Failed to create channel");
+            // Each message is larger than the chunk size, so each is in its own chunk.
+            for i in 0..10 {
+                channel.log(format!(r#"{{"i": {i}, "padding": "{:64}"}}"#, "").as_bytes());
+            }
+        }
+        let writer = || {
+            McapWriter::with_options(WriteOptions::new().chunk_size(Some(64)))
+                .flush_policy(FlushPolicy::new().chunks(3))
+        };
+
+        // Chunks are written in place, and completed by rewriting their headers.
+        let ctx = Arc::new(LogContext::new());
+        let mcap = writer()
+            .context(&ctx)
+            .create(CountFlushes {
+                inner: std::io::Cursor::new(Vec::new()),
+                flushes: 0,
+            })
+            .expect("Failed to create writer");
+        log_messages(&ctx);
+        let out = mcap.close().expect("Failed to close writer");
+        let summary = mcap::Summary::read(out.inner.get_ref())
+            .expect("Failed to read summary")
+            .expect("Missing summary");
+        assert_eq!(summary.chunk_indexes.len(), 10);
+        // Nine chunks are completed while logging, and the recording is flushed when it is closed.
+        assert_eq!(out.flushes, 3 + 1);
+
+        // Chunks are buffered, and written when they are completed.
+        let ctx = Arc::new(LogContext::new());
+        let mcap = writer()
+            .context(&ctx)
+            .create_streaming(CountFlushes {
+                inner: Pipe(Vec::new()),
+                flushes: 0,
+            })
+            .expect("Failed to create writer");
+        log_messages(&ctx);
+        let out = mcap.close().expect("Failed to close writer").into_inner();
+        assert_eq!(out.flushes, 3 + 1);
+    }
+
+    #[test]
+    fn test_flush_interval() {
+        let dir = tempfile::tempdir().expect("Failed to create tempdir");
+        let path = dir.path().join("test.mcap");
+        let ctx = Arc::new(LogContext::new());
+        let mcap = McapWriter::new()
+            .context(&ctx)
+            .flush_policy(
+                FlushPolicy::new()
+                    .interval(Duration::from_millis(10))
+                    .sync(true),
+            )
+            .create_new_buffered_file(&path)
+            .expect("Failed to create writer");
+        let channel = ChannelBuilder::new("/test")
+            .message_encoding("json")
+            .with_context(&ctx)
+            .build()
+            .expect("Failed to create channel");
+        channel.log(br#"{"i": 0}"#);
+
+        // The message is flushed to the file while the recording is in progress.
+        let mut messages = 0;
+        for _ in 0..100 {
+            std::thread::sleep(Duration::from_millis(10));
+            let buf = std::fs::read(&path).expect("Failed to read file");
+            let report = recover_mcap(buf.as_slice(), std::io::Cursor::new(Vec::new()))
+                .expect("Failed to recover");
+            assert!(!report.complete);
+            messages = report.messages;
+            if messages > 0 {
+                break;
+            }
+        }
+        assert_eq!(messages, 1);
+        drop(mcap);
+    }
+}
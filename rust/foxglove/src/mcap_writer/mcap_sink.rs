@@ -3,28 +3,122 @@ use crate::channel::Channel;
 use crate::channel::ChannelId;
 use crate::log_sink::LogSink;
 use crate::metadata::Metadata;
+use crate::FlushPolicy;
 use crate::FoxgloveError;
 use mcap::WriteOptions;
 use parking_lot::Mutex;
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
-use std::io::{Seek, Write};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{Seek, SeekFrom, Write};
 use std::sync::Arc;
 
+/// The opcode of a chunk record.
+const CHUNK_OPCODE: u8 = 0x06;
+
+/// The writer of an MCAP sink, which counts the chunks written by the MCAP writer, so that it can
+/// be flushed after every [`FlushPolicy::chunks`] chunks.
+///
+/// When seeking is enabled, a chunk is written in place, and completed by seeking back to rewrite
+/// its header, then seeking to the end of the file. Otherwise, a chunk is buffered, and written
+/// as a single record when it is completed.
+///
+/// The MCAP writer does not report when it completes a chunk, or expose its chunk size, so this
+/// depends on how the `mcap` crate writes chunks. The crate is pinned to an exact version in the
+/// workspace manifest, and `test_flush_chunks` checks the count for both modes.
+struct Output<W: Write + Seek> {
+    inner: W,
+    // A handle to the file being written, which is synchronized to disk after each flush.
+    sync_file: Option<File>,
+    // The number of completed chunks after which to flush
+    flush_chunks: Option<u64>,
+    // Chunks completed since the last flush
+    chunks: u64,
+    // Bytes remaining of a buffered chunk which is being written
+    chunk_remaining: u64,
+}
+
+impl<W: Write + Seek> Output<W> {
+    fn chunk_completed(&mut self) -> std::io::Result<()> {
+        self.chunks += 1;
+        if self.flush_chunks.is_some_and(|n| self.chunks >= n) {
+            self.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the length of a chunk record, if the buffer holds exactly one chunk record.
+fn buffered_chunk_len(buf: &[u8]) -> Option<u64> {
+    let (&opcode, rest) = buf.split_first()?;
+    let len = u64::from_le_bytes(rest.get(..8)?.try_into().ok()?);
+    (opcode == CHUNK_OPCODE && len == rest.len() as u64 - 8).then_some(buf.len() as u64)
+}
+
+impl<W: Write + Seek> Write for Output<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.chunk_remaining == 0 {
+            self.chunk_remaining = buffered_chunk_len(buf).unwrap_or_default();
+        }
+        let written = self.inner.write(buf)?;
+        if self.chunk_remaining > 0 {
+            self.chunk_remaining = self.chunk_remaining.saturating_sub(written as u64);
+            if self.chunk_remaining == 0 {
+                self.chunk_completed()?;
+            }
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()?;
+        if let Some(file) = &self.sync_file {
+            file.sync_data()?;
+        }
+        self.chunks = 0;
+        Ok(())
+    }
+}
+
+impl<W: Write + Seek> Seek for Output<W> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let offset = self.inner.seek(pos)?;
+        // The chunk in place is complete once its header has been rewritten.
+        if pos == SeekFrom::End(0) {
+            self.chunk_completed()?;
+        }
+        Ok(offset)
+    }
+}
+
 struct WriterState<W: Write + Seek> {
-    writer: mcap::Writer<W>,
+    writer: mcap::Writer<Output<W>>,
     // ChannelId -> mcap file channel id
     channel_map: HashMap<ChannelId, u16>,
+    // Bytes of message data logged since the last flush
+    unflushed_bytes: u64,
+    // Whether any messages have been logged since the last flush
+    dirty: bool,
 }
 
 impl<W: Write + Seek> WriterState<W> {
-    fn new(writer: mcap::Writer<W>) -> Self {
+    fn new(writer: mcap::Writer<Output<W>>) -> Self {
         Self {
             writer,
             channel_map: HashMap::new(),
+            unflushed_bytes: 0,
+            dirty: false,
         }
     }
 
+    /// Completes the chunk in progress, and flushes it to the writer.
+    fn flush(&mut self) -> Result<(), FoxgloveError> {
+        self.writer.flush()?;
+        self.unflushed_bytes = 0;
+        self.dirty = false;
+        Ok(())
+    }
+
     fn log(
         &mut self,
         channel: &Channel,
@@ -68,38 +162,88 @@ impl<W: Write + Seek> WriterState<W> {
                 },
                 msg,
             )
-            .map_err(FoxgloveError::from)
+            .map_err(FoxgloveError::from)?;
+        self.unflushed_bytes += msg.len() as u64;
+        self.dirty = true;
+        Ok(())
     }
 }
 
-pub struct McapSink<W: Write + Seek>(Mutex<Option<WriterState<W>>>);
+pub struct McapSink<W: Write + Seek> {
+    state: Mutex<Option<WriterState<W>>>,
+    policy: FlushPolicy,
+    // The topics to log, or None to log all topics
+    topics: Option<HashSet<String>>,
+}
 
 impl<W: Write + Seek> McapSink<W> {
     /// Creates a new MCAP writer log sink.
-    pub fn new(writer: W, options: WriteOptions) -> Result<Arc<McapSink<W>>, FoxgloveError> {
-        let mcap_writer = options.create(writer).map_err(FoxgloveError::from)?;
-        let writer = Arc::new(Self(Mutex::new(Some(WriterState::new(mcap_writer)))));
+    pub fn new(
+        writer: W,
+        options: WriteOptions,
+        policy: FlushPolicy,
+        topics: Option<HashSet<String>>,
+        sync_file: Option<File>,
+    ) -> Result<Arc<McapSink<W>>, FoxgloveError> {
+        let output = Output {
+            inner: writer,
+            sync_file,
+            flush_chunks: policy.chunks,
+            chunks: 0,
+            chunk_remaining: 0,
+        };
+        let mcap_writer = options.create(output).map_err(FoxgloveError::from)?;
+        let writer = Arc::new(Self {
+            state: Mutex::new(Some(WriterState::new(mcap_writer))),
+            policy,
+            topics,
+        });
         Ok(writer)
     }
 
+    /// Flushes the messages logged since the last flush to the writer.
+    pub fn flush(&self) -> Result<(), FoxgloveError> {
+        let mut guard = self.state.lock();
+        let state = guard.as_mut().ok_or(FoxgloveError::SinkClosed)?;
+        if state.dirty {
+            state.flush()?;
+        }
+        Ok(())
+    }
+
     /// Finalizes the MCAP recording and flushes it to the file.
     ///
     /// Returns the inner writer that was passed to [`McapWriter::new`].
     pub fn finish(&self) -> Result<Option<W>, FoxgloveError> {
-        let Some(mut writer) = self.0.lock().take() else {
+        let Some(mut writer) = self.state.lock().take() else {
             return Ok(None);
         };
+        // Finishing the recording flushes the writer.
         writer.writer.finish()?;
-        Ok(Some(writer.writer.into_inner()))
+        Ok(Some(writer.writer.into_inner().inner))
     }
 }
 
 impl<W: Write + Seek + Send> LogSink for McapSink<W> {
     fn log(&self, channel: &Channel, msg: &[u8], metadata: &Metadata) -> Result<(), FoxgloveError> {
-        _ = metadata;
-        let mut guard = self.0.lock();
-        let writer = guard.as_mut().ok_or(FoxgloveError::SinkClosed)?;
-        writer.log(channel, msg, metadata)
+        if self
+            .topics
+            .as_ref()
+            .is_some_and(|topics| !topics.contains(channel.topic()))
+        {
+            return Ok(());
+        }
+        let mut guard = self.state.lock();
+        let state = guard.as_mut().ok_or(FoxgloveError::SinkClosed)?;
+        state.log(channel, msg, metadata)?;
+        if self
+            .policy
+            .max_unflushed_bytes
+            .is_some_and(|max| state.unflushed_bytes >= max)
+        {
+            state.flush()?;
+        }
+        Ok(())
     }
 }
 
@@ -187,8 +331,14 @@ mod tests {
         let mut ch2_meta_iter = ch2_meta.iter();
 
         // Log two messages to each channel, interleaved
-        let writer =
-            McapSink::new(&temp_file, WriteOptions::default()).expect("failed to create writer");
+        let writer = McapSink::new(
+            &temp_file,
+            WriteOptions::default(),
+            FlushPolicy::default(),
+            None,
+            None,
+        )
+        .expect("failed to create writer");
         writer
             .log(&ch1, b"msg1", &ch1_meta[0])
             .expect("failed to log to channel 1");
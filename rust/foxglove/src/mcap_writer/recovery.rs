@@ -0,0 +1,408 @@
+//! Recovery of truncated MCAP files.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{BufReader, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::rc::Rc;
+
+use mcap::read::ChunkReader;
+use mcap::records::Record;
+use mcap::{McapError, McapResult, WriteOptions};
+
+use crate::FoxgloveError;
+
+/// A summary of the data salvaged by [`recover_mcap`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecoveryReport {
+    /// The number of complete chunks which were read.
+    pub chunks: usize,
+    /// The number of messages which were written to the recovered file.
+    pub messages: u64,
+    /// The number of attachments which were written to the recovered file.
+    pub attachments: usize,
+    /// The number of metadata records which were written to the recovered file.
+    pub metadata: usize,
+    /// Whether the end of the data section was reached. If false, the input was truncated or
+    /// corrupt, and the records after the last complete record were discarded.
+    pub complete: bool,
+}
+
+/// The result of copying a record.
+enum Copied {
+    /// The record was copied or skipped.
+    Record,
+    /// The record is the end of the data section.
+    DataEnd,
+    /// The record is corrupt, so it and the records which follow it are discarded.
+    Corrupt,
+}
+
+/// The first error from an output, shared between the output and the recovery.
+type SharedError = Rc<RefCell<Option<std::io::Error>>>;
+
+/// An output which discards writes after the first error.
+///
+/// The MCAP writer panics if it is dropped after failing to write, since it tries to finish the
+/// file again. Since the writer never sees an error from this output, it can be finished and
+/// dropped as usual, and the error is returned by [`recover_mcap`] instead. The position and length
+/// of the output are tracked, so that seeks remain consistent after the error.
+struct LatchedOutput<W> {
+    inner: W,
+    error: SharedError,
+    position: u64,
+    len: u64,
+}
+
+impl<W: Write + Seek> LatchedOutput<W> {
+    fn new(mut inner: W, error: SharedError) -> std::io::Result<Self> {
+        let position = inner.stream_position()?;
+        Ok(Self {
+            inner,
+            error,
+            position,
+            len: position,
+        })
+    }
+
+    /// Returns the result of an operation on the inner output, or `None` if it has failed.
+    fn latch<T>(&mut self, op: impl FnOnce(&mut W) -> std::io::Result<T>) -> Option<T> {
+        if self.error.borrow().is_some() {
+            return None;
+        }
+        match op(&mut self.inner) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                *self.error.borrow_mut() = Some(e);
+                None
+            }
+        }
+    }
+}
+
+impl<W: Write + Seek> Write for LatchedOutput<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.latch(|w| w.write(buf)).unwrap_or(buf.len());
+        self.position += written as u64;
+        self.len = self.len.max(self.position);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.latch(W::flush);
+        Ok(())
+    }
+}
+
+impl<W: Write + Seek> Seek for LatchedOutput<W> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match self.latch(|w| w.seek(pos)) {
+            Some(position) => position,
+            None => match pos {
+                SeekFrom::Start(offset) => Some(offset),
+                SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+                SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+            }
+            .ok_or_else(|| std::io::Error::from(ErrorKind::InvalidInput))?,
+        };
+        self.position = position;
+        self.len = self.len.max(position);
+        Ok(position)
+    }
+}
+
+/// Writes the records of an input file to a new file, remapping schema and channel IDs.
+struct Recovery<W: Write + Seek> {
+    writer: mcap::Writer<LatchedOutput<W>>,
+    error: SharedError,
+    // Input schema ID -> output schema ID
+    schemas: HashMap<u16, u16>,
+    // Input channel ID -> output channel ID
+    channels: HashMap<u16, u16>,
+    report: RecoveryReport,
+}
+
+impl<W: Write + Seek> Recovery<W> {
+    /// Copies records to the output until the end of the data section, the first incomplete or
+    /// corrupt record, or the first error from the output.
+    fn copy_records(&mut self, input: &mut impl Read, buf: &mut Vec<u8>) -> McapResult<()> {
+        while let Some(opcode) = read_record(input, buf)? {
+            let Ok(record) = mcap::parse_record(opcode, buf) else {
+                break;
+            };
+            match self.copy(record)? {
+                Copied::Record => (),
+                Copied::DataEnd => {
+                    self.report.complete = true;
+                    break;
+                }
+                Copied::Corrupt => break,
+            }
+            if self.error.borrow().is_some() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies a record to the output.
+    ///
+    /// Corrupt input is reported with [`Copied::Corrupt`].
+    fn copy(&mut self, record: Record<'_>) -> McapResult<Copied> {
+        match record {
+            Record::Schema { header, data } => {
+                let id = self
+                    .writer
+                    .add_schema(&header.name, &header.encoding, &data)?;
+                self.schemas.insert(header.id, id);
+            }
+            Record::Channel(channel) => {
+                // Schema ID 0 indicates a channel without a schema.
+                let schema_id = match channel.schema_id {
+                    0 => Some(0),
+                    id => self.schemas.get(&id).copied(),
+                };
+                if let Some(schema_id) = schema_id {
+                    let id = self.writer.add_channel(
+                        schema_id,
+                        &channel.topic,
+                        &channel.message_encoding,
+                        &channel.metadata,
+                    )?;
+                    self.channels.insert(channel.id, id);
+                }
+            }
+            Record::Message { mut header, data } => {
+                if let Some(&id) = self.channels.get(&header.channel_id) {
+                    header.channel_id = id;
+                    self.writer.write_to_known_channel(&header, &data)?;
+                    self.report.messages += 1;
+                }
+            }
+            Record::Chunk { header, data } => {
+                // Copy the records of the chunk, stopping at the first invalid record.
+                let Ok(records) = ChunkReader::new(header, &data) else {
+                    return Ok(Copied::Corrupt);
+                };
+                for record in records {
+                    let Ok(record) = record else {
+                        return Ok(Copied::Corrupt);
+                    };
+                    self.copy(record)?;
+                }
+                self.report.chunks += 1;
+            }
+            Record::Attachment { header, data } => {
+                self.writer.attach(&mcap::Attachment {
+                    log_time: header.log_time,
+                    create_time: header.create_time,
+                    name: header.name,
+                    media_type: header.media_type,
+                    data,
+                })?;
+                self.report.attachments += 1;
+            }
+            Record::Metadata(metadata) => {
+                self.writer.write_metadata(&metadata)?;
+                self.report.metadata += 1;
+            }
+            // The summary section is regenerated when the output is finished.
+            Record::DataEnd(_) | Record::Footer(_) => return Ok(Copied::DataEnd),
+            _ => (),
+        }
+        Ok(Copied::Record)
+    }
+}
+
+/// Reads the next record from the input into the buffer, and returns its opcode.
+///
+/// Returns `None` at the end of the input, or if the record is incomplete. The records are read
+/// directly, rather than with [`mcap::sans_io::read::LinearReader`], since a chunk which is being
+/// written has a placeholder length, which is larger than the file, and which that reader cannot
+/// handle. The data is read without reserving that length up front.
+fn read_record(input: &mut impl Read, buf: &mut Vec<u8>) -> std::io::Result<Option<u8>> {
+    // Each record starts with a one-byte opcode, and an eight-byte length.
+    let mut prefix = [0; 9];
+    match input.read_exact(&mut prefix) {
+        Ok(()) => (),
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    let len = u64::from_le_bytes(prefix[1..].try_into().expect("eight bytes"));
+    buf.clear();
+    input.take(len).read_to_end(buf)?;
+    Ok((buf.len() as u64 == len).then_some(prefix[0]))
+}
+
+/// Recovers the data from an MCAP file which was not closed, for example because the process
+/// which was writing it crashed, and writes it to a new, valid MCAP file.
+///
+/// Such a file has no summary section, and may end with an incomplete record. The records are
+/// read in order, and copied to the output, until the end of the data section, or the first
+/// incomplete or corrupt record. The output has a summary section, with indexes and statistics.
+/// The input is streamed, so only one record is held in memory at a time.
+///
+/// The messages of a chunk which was being written when the file was truncated are lost. To
+/// bound the data lost, use a [`FlushPolicy`](crate::FlushPolicy) when recording.
+///
+/// ```no_run
+/// use std::fs::File;
+///
+/// let input = File::open("truncated.mcap")?;
+/// let report = foxglove::recover_mcap(input, File::create_new("recovered.mcap")?)?;
+/// println!("Recovered {} messages", report.messages);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn recover_mcap<R: Read, W: Write + Seek>(
+    input: R,
+    writer: W,
+) -> Result<RecoveryReport, FoxgloveError> {
+    let mut input = BufReader::new(input);
+    let mut magic = [0; mcap::MAGIC.len()];
+    match input.read_exact(&mut magic) {
+        Ok(()) if magic == mcap::MAGIC => (),
+        Err(e) if e.kind() != ErrorKind::UnexpectedEof => return Err(e.into()),
+        _ => return Err(McapError::BadMagic.into()),
+    }
+
+    let mut buf = Vec::new();
+    let profile = match read_record(&mut input, &mut buf)? {
+        Some(opcode) => match mcap::parse_record(opcode, &buf)? {
+            Record::Header(header) => header.profile,
+            _ => String::new(),
+        },
+        None => String::new(),
+    };
+    let options = WriteOptions::default()
+        .profile(profile)
+        .library(format!("foxglove-sdk-rs-{}", env!("CARGO_PKG_VERSION")));
+    let error = SharedError::default();
+    let mut recovery = Recovery {
+        writer: options.create(LatchedOutput::new(writer, error.clone())?)?,
+        error,
+        schemas: HashMap::new(),
+        channels: HashMap::new(),
+        report: RecoveryReport::default(),
+    };
+    let result = recovery.copy_records(&mut input, &mut buf);
+    // The output never fails from the writer's point of view, so it can always be finished.
+    recovery.writer.finish()?;
+    result?;
+    if let Some(e) = recovery.error.take() {
+        return Err(e.into());
+    }
+    Ok(recovery.report)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{ChannelBuilder, FlushPolicy, LogContext, McapWriter};
+
+    fn read_messages(buf: &[u8]) -> Vec<Vec<u8>> {
+        mcap::MessageStream::new(buf)
+            .expect("Failed to create message stream")
+            .map(|msg| msg.expect("Failed to read message").data.to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_recover_truncated() {
+        let ctx = Arc::new(LogContext::new());
+        // Flush after each message, so that each message is in its own chunk.
+        let mcap = McapWriter::new()
+            .context(&ctx)
+            .flush_policy(FlushPolicy::new().max_unflushed_bytes(1))
+            .create(Cursor::new(Vec::new()))
+            .expect("Failed to create writer");
+        let channel = ChannelBuilder::new("/test")
+            .message_encoding("json")
+            .with_context(&ctx)
+            .build()
+            .expect("Failed to create channel");
+        let expected: Vec<Vec<u8>> = (0..5)
+            .map(|i| format!(r#"{{"i": {i}}}"#).into_bytes())
+            .collect();
+        for msg in &expected {
+            channel.log(msg);
+        }
+        let buf = mcap.close().expect("Failed to close writer").into_inner();
+
+        let mut out = Cursor::new(Vec::new());
+        let report = recover_mcap(buf.as_slice(), &mut out).expect("Failed to recover");
+        assert_eq!(
+            report,
+            RecoveryReport {
+                chunks: 5,
+                messages: 5,
+                complete: true,
+                ..Default::default()
+            }
+        );
+        assert_eq!(read_messages(out.get_ref()), expected);
+
+        // Every prefix of the file can be recovered, and yields a prefix of the messages.
+        let mut last_count = 0;
+        for len in mcap::MAGIC.len()..buf.len() {
+            let mut out = Cursor::new(Vec::new());
+            let report = recover_mcap(&buf[..len], &mut out).expect("Failed to recover");
+            let messages = read_messages(out.get_ref());
+            assert_eq!(messages.len() as u64, report.messages);
+            assert_eq!(messages, expected[..messages.len()]);
+            assert!(messages.len() >= last_count);
+            last_count = messages.len();
+        }
+        assert_eq!(last_count, expected.len());
+    }
+
+    /// A writer which fails once it has written a number of bytes.
+    struct FailingWriter(Cursor<Vec<u8>>, usize);
+
+    impl Write for FailingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            if self.0.get_ref().len() + buf.len() > self.1 {
+                return Err(std::io::Error::other("disk full"));
+            }
+            self.0.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Seek for FailingWriter {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            self.0.seek(pos)
+        }
+    }
+
+    #[test]
+    fn test_output_error() {
+        let ctx = Arc::new(LogContext::new());
+        let mcap = McapWriter::new()
+            .context(&ctx)
+            .create(Cursor::new(Vec::new()))
+            .expect("Failed to create writer");
+        let channel = ChannelBuilder::new("/test")
+            .message_encoding("json")
+            .with_context(&ctx)
+            .build()
+            .expect("Failed to create channel");
+        channel.log(b"{}");
+        let buf = mcap.close().expect("Failed to close writer").into_inner();
+
+        let mut out = Cursor::new(Vec::new());
+        recover_mcap(buf.as_slice(), &mut out).expect("Failed to recover");
+        let len = out.get_ref().len();
+
+        // Errors writing the output are returned wherever they occur, rather than ending the
+        // recovery early, or panicking when the MCAP writer is dropped.
+        for limit in 0..len {
+            let out = FailingWriter(Cursor::new(Vec::new()), limit);
+            assert!(recover_mcap(buf.as_slice(), out).is_err(), "{limit}");
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! MessagePack encoding for custom types.
+//!
+//! Types that implement [`Serialize`](serde::Serialize) and [`JsonSchema`](schemars::JsonSchema)
+//! are logged as JSON by default. JSON is verbose for numeric data, so to log such a type as a
+//! `msgpack`-encoded message instead, wrap the message type in [`MsgPack`]. The channel schema is
+//! the same JSON schema that is derived for JSON channels. Structs are serialized as maps with
+//! named fields, so that messages match the schema.
+//!
+//! The choice is made per channel, so JSON and MessagePack channels can be used side by side.
+//!
+//! ```no_run
+//! use foxglove::msgpack::MsgPack;
+//! use foxglove::TypedChannel;
+//!
+//! #[derive(serde::Serialize, schemars::JsonSchema)]
+//! struct Scan {
+//!     ranges: Vec<f32>,
+//! }
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! let channel = TypedChannel::<MsgPack<Scan>>::new("/scan")?;
+//! channel.log(&MsgPack(Scan {
+//!     ranges: vec![1.0, 2.0, 3.0],
+//! }));
+//! # Ok(()) }
+//! ```
+
+crate::encode::serde_wrapper! {
+    /// A wrapper for logging a message with MessagePack encoding.
+    ///
+    /// Use `TypedChannel<MsgPack<T>>` to create a channel with message encoding `msgpack`, and
+    /// schema encoding `jsonschema`.
+    pub struct MsgPack;
+    encoding = "msgpack";
+    type Error = rmp_serde::encode::Error;
+    |value, writer| rmp_serde::encode::write_named(writer, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Encode;
+    use schemars::JsonSchema;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+    struct Scan {
+        frame_id: String,
+        ranges: Vec<f32>,
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = MsgPack::<Scan>::get_schema().unwrap();
+        assert_eq!(Some(schema), Scan::get_schema());
+        assert_eq!(MsgPack::<Scan>::get_message_encoding(), "msgpack");
+    }
+
+    #[test]
+    fn test_encode() {
+        let scan = Scan {
+            frame_id: "laser".to_string(),
+            ranges: vec![1.0, 2.5],
+        };
+        let mut buf = vec![];
+        MsgPack(&scan).encode(&mut buf).unwrap();
+
+        // Fields are encoded by name.
+        let value: serde_json::Value = rmp_serde::from_slice(&buf).unwrap();
+        assert_eq!(value["frame_id"], "laser");
+
+        let decoded: Scan = rmp_serde::from_slice(&buf).unwrap();
+        assert_eq!(decoded, scan);
+    }
+}
@@ -0,0 +1,711 @@
+//! Building and reading [`PointCloud`] messages.
+//!
+//! A [`PointCloud`] stores points as packed binary data, described by a list of
+//! [`PackedElementField`]s. [`PointCloudBuilder`] computes the field offsets and point stride,
+//! and packs the data, from either a slice of points or a set of columns. [`PointCloudReader`]
+//! validates the layout of an existing point cloud, and iterates over the values of a field.
+//!
+//! Points can be described by implementing [`PackedPoint`]. With the `derive` feature, it can be
+//! derived for a struct whose fields are numeric primitives:
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use foxglove::point_cloud::{PackedPoint, PointCloudBuilder, PointCloudReader};
+//!
+//! #[derive(PackedPoint)]
+//! struct LidarPoint {
+//!     x: f32,
+//!     y: f32,
+//!     z: f32,
+//!     intensity: u8,
+//! }
+//!
+//! let points = [
+//!     LidarPoint { x: 1.0, y: 2.0, z: 3.0, intensity: 10 },
+//!     LidarPoint { x: 4.0, y: 5.0, z: 6.0, intensity: 20 },
+//! ];
+//! let cloud = PointCloudBuilder::new()
+//!     .frame_id("lidar")
+//!     .points(&points)
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(cloud.point_stride, 13);
+//!
+//! let reader = PointCloudReader::new(&cloud).unwrap();
+//! let intensity: Vec<u8> = reader.field("intensity").unwrap().collect();
+//! assert_eq!(intensity, [10, 20]);
+//! # }
+//! ```
+//!
+//! Alternatively, build the point cloud from columns of equal length:
+//!
+//! ```
+//! use foxglove::point_cloud::PointCloudBuilder;
+//!
+//! let cloud = PointCloudBuilder::new()
+//!     .column("x", &[1.0f32, 4.0])
+//!     .column("y", &[2.0f32, 5.0])
+//!     .column("z", &[3.0f32, 6.0])
+//!     .rgba(&[[255, 0, 0, 255], [0, 0, 255, 255]])
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(cloud.fields.len(), 7);
+//! ```
+
+use std::marker::PhantomData;
+
+use bytes::BufMut;
+
+use crate::schemas::packed_element_field::NumericType;
+use crate::schemas::{PackedElementField, Point3, PointCloud, Pose, Timestamp};
+
+#[cfg(feature = "derive")]
+pub use foxglove_derive::PackedPoint;
+
+/// An error that can occur when building or reading a point cloud.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum PointCloudError {
+    /// A column has a different length than the preceding columns.
+    #[error("Column {name} has {actual} values, but {expected} were expected")]
+    ColumnLength {
+        /// The name of the column.
+        name: String,
+        /// The length of the preceding columns.
+        expected: usize,
+        /// The length of the column.
+        actual: usize,
+    },
+    /// Both points and columns were provided to the builder.
+    #[error("Points and columns cannot be combined")]
+    PointsAndColumns,
+    /// A point did not write the number of bytes described by its fields.
+    #[error("Point wrote {actual} bytes, but its fields describe {expected}")]
+    PointSize {
+        /// The size described by the fields.
+        expected: usize,
+        /// The number of bytes written.
+        actual: usize,
+    },
+    /// Two fields have the same name.
+    #[error("Duplicate field {0}")]
+    DuplicateField(String),
+    /// The requested field does not exist.
+    #[error("Field {0} not found")]
+    FieldNotFound(String),
+    /// The field does not have the requested type.
+    #[error("Field {name} has type {actual:?}, but {expected:?} was requested")]
+    FieldType {
+        /// The name of the field.
+        name: String,
+        /// The requested type.
+        expected: NumericType,
+        /// The type of the field.
+        actual: NumericType,
+    },
+    /// The field has an unknown numeric type.
+    #[error("Field {0} has an unknown numeric type")]
+    UnknownFieldType(String),
+    /// The field extends past the end of the point.
+    #[error("Field {name} at offset {offset} does not fit in the point stride of {stride}")]
+    FieldOutOfBounds {
+        /// The name of the field.
+        name: String,
+        /// The offset of the field.
+        offset: u32,
+        /// The point stride.
+        stride: u32,
+    },
+    /// The data is not a whole number of points.
+    #[error("Data length {len} is not a multiple of the point stride {stride}")]
+    DataLength {
+        /// The length of the data.
+        len: usize,
+        /// The point stride.
+        stride: u32,
+    },
+    /// The point cloud is too large to describe.
+    #[error("Point of {0} bytes is too large")]
+    TooLarge(usize),
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A numeric type that can be stored in a point cloud field.
+///
+/// Values are stored in little-endian byte order. This trait is sealed, and implemented for the
+/// types that correspond to [`NumericType`].
+pub trait PointFieldType: Copy + sealed::Sealed {
+    /// The numeric type of the field.
+    const NUMERIC_TYPE: NumericType;
+
+    /// The size of the value in bytes.
+    const SIZE: usize;
+
+    /// Writes the value in little-endian byte order.
+    fn write_le(self, buf: &mut impl BufMut);
+
+    /// Reads a value from the first [`SIZE`](Self::SIZE) bytes of the slice.
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_point_field_type {
+    ($ty:ty, $numeric_type:ident, $put:ident) => {
+        impl sealed::Sealed for $ty {}
+
+        impl PointFieldType for $ty {
+            const NUMERIC_TYPE: NumericType = NumericType::$numeric_type;
+            const SIZE: usize = std::mem::size_of::<$ty>();
+
+            fn write_le(self, buf: &mut impl BufMut) {
+                buf.$put(self);
+            }
+
+            fn read_le(bytes: &[u8]) -> Self {
+                let bytes = bytes[..Self::SIZE]
+                    .try_into()
+                    .expect("slice has SIZE bytes");
+                <$ty>::from_le_bytes(bytes)
+            }
+        }
+    };
+}
+
+impl_point_field_type!(u8, Uint8, put_u8);
+impl_point_field_type!(i8, Int8, put_i8);
+impl_point_field_type!(u16, Uint16, put_u16_le);
+impl_point_field_type!(i16, Int16, put_i16_le);
+impl_point_field_type!(u32, Uint32, put_u32_le);
+impl_point_field_type!(i32, Int32, put_i32_le);
+impl_point_field_type!(f32, Float32, put_f32_le);
+impl_point_field_type!(f64, Float64, put_f64_le);
+
+/// Returns the size in bytes of a numeric type, or `None` if it is unknown.
+pub(crate) fn numeric_type_size(numeric_type: NumericType) -> Option<usize> {
+    match numeric_type {
+        NumericType::Unknown => None,
+        NumericType::Uint8 | NumericType::Int8 => Some(1),
+        NumericType::Uint16 | NumericType::Int16 => Some(2),
+        NumericType::Uint32 | NumericType::Int32 | NumericType::Float32 => Some(4),
+        NumericType::Float64 => Some(8),
+    }
+}
+
+/// The name and type of a field of a [`PackedPoint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointField {
+    /// The name of the field, such as `x` or `intensity`.
+    pub name: &'static str,
+    /// The numeric type of the field.
+    pub numeric_type: NumericType,
+}
+
+impl PointField {
+    /// Creates a field with the numeric type of `T`.
+    pub fn new<T: PointFieldType>(name: &'static str) -> Self {
+        Self {
+            name,
+            numeric_type: T::NUMERIC_TYPE,
+        }
+    }
+}
+
+/// A point with a fixed layout, which can be packed into a [`PointCloud`].
+///
+/// With the `derive` feature, this trait can be derived for a struct whose fields implement
+/// [`PointFieldType`], with `#[derive(PackedPoint)]`. The fields of the struct become the fields
+/// of the point cloud, in declaration order.
+pub trait PackedPoint {
+    /// Returns the fields of the point, in the order they are written.
+    fn fields() -> Vec<PointField>;
+
+    /// Writes the value of each field, in order, without padding.
+    fn write_point(&self, buf: &mut impl BufMut);
+}
+
+impl PackedPoint for Point3 {
+    fn fields() -> Vec<PointField> {
+        vec![
+            PointField::new::<f64>("x"),
+            PointField::new::<f64>("y"),
+            PointField::new::<f64>("z"),
+        ]
+    }
+
+    fn write_point(&self, buf: &mut impl BufMut) {
+        self.x.write_le(buf);
+        self.y.write_le(buf);
+        self.z.write_le(buf);
+    }
+}
+
+/// Lays out fields consecutively, and returns the corresponding packed element fields and the
+/// point stride.
+fn layout(
+    fields: impl IntoIterator<Item = (String, NumericType)>,
+) -> Result<(Vec<PackedElementField>, usize), PointCloudError> {
+    let mut packed: Vec<PackedElementField> = Vec::new();
+    let mut offset = 0;
+    for (name, numeric_type) in fields {
+        if packed.iter().any(|f| f.name == name) {
+            return Err(PointCloudError::DuplicateField(name));
+        }
+        let size = numeric_type_size(numeric_type)
+            .ok_or(PointCloudError::UnknownFieldType(name.clone()))?;
+        packed.push(PackedElementField {
+            offset: u32::try_from(offset).map_err(|_| PointCloudError::TooLarge(offset))?,
+            name,
+            r#type: numeric_type.into(),
+        });
+        offset += size;
+    }
+    Ok((packed, offset))
+}
+
+/// A column of values, which are interleaved with the other columns when the point cloud is built.
+struct Column {
+    name: String,
+    numeric_type: NumericType,
+    size: usize,
+    len: usize,
+    data: Vec<u8>,
+}
+
+/// A builder for a [`PointCloud`].
+///
+/// The points are provided either as a slice of [`PackedPoint`]s, with
+/// [`points`](Self::points), or as columns of equal length, with [`column`](Self::column) and
+/// [`rgba`](Self::rgba).
+#[must_use]
+#[derive(Default)]
+pub struct PointCloudBuilder {
+    timestamp: Option<Timestamp>,
+    frame_id: String,
+    pose: Option<Pose>,
+    points: Option<(Vec<PackedElementField>, usize, Vec<u8>)>,
+    columns: Vec<Column>,
+    error: Option<PointCloudError>,
+}
+
+impl PointCloudBuilder {
+    /// Creates a new point cloud builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the timestamp of the point cloud.
+    pub fn timestamp(mut self, timestamp: impl Into<Timestamp>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Sets the frame of reference.
+    pub fn frame_id(mut self, frame_id: impl Into<String>) -> Self {
+        self.frame_id = frame_id.into();
+        self
+    }
+
+    /// Sets the origin of the point cloud relative to the frame of reference.
+    pub fn pose(mut self, pose: impl Into<Pose>) -> Self {
+        self.pose = Some(pose.into());
+        self
+    }
+
+    /// Sets the points. The fields of the point cloud are the fields of the point type.
+    pub fn points<P: PackedPoint>(mut self, points: &[P]) -> Self {
+        let fields = P::fields()
+            .into_iter()
+            .map(|f| (f.name.to_string(), f.numeric_type));
+        match layout(fields) {
+            Ok((fields, stride)) => {
+                let mut data = Vec::with_capacity(stride * points.len());
+                for point in points {
+                    let start = data.len();
+                    point.write_point(&mut data);
+                    let actual = data.len() - start;
+                    if actual != stride {
+                        self.error.get_or_insert(PointCloudError::PointSize {
+                            expected: stride,
+                            actual,
+                        });
+                        break;
+                    }
+                }
+                self.points = Some((fields, stride, data));
+            }
+            Err(err) => {
+                self.error.get_or_insert(err);
+            }
+        }
+        self
+    }
+
+    /// Adds a field to the point cloud, with a value for each point.
+    ///
+    /// Every column must have the same length. Fields are laid out in the order they are added.
+    pub fn column<T: PointFieldType>(mut self, name: impl Into<String>, values: &[T]) -> Self {
+        let name = name.into();
+        if let Some(first) = self.columns.first() {
+            if first.len != values.len() {
+                self.error.get_or_insert(PointCloudError::ColumnLength {
+                    name: name.clone(),
+                    expected: first.len,
+                    actual: values.len(),
+                });
+            }
+        }
+        let mut data = Vec::with_capacity(T::SIZE * values.len());
+        for value in values {
+            value.write_le(&mut data);
+        }
+        self.columns.push(Column {
+            name,
+            numeric_type: T::NUMERIC_TYPE,
+            size: T::SIZE,
+            len: values.len(),
+            data,
+        });
+        self
+    }
+
+    /// Adds `red`, `green`, `blue` and `alpha` fields to the point cloud, with a color for each
+    /// point.
+    pub fn rgba(self, colors: &[[u8; 4]]) -> Self {
+        let channel = |i: usize| colors.iter().map(|c| c[i]).collect::<Vec<_>>();
+        self.column("red", &channel(0))
+            .column("green", &channel(1))
+            .column("blue", &channel(2))
+            .column("alpha", &channel(3))
+    }
+
+    /// Builds the point cloud.
+    ///
+    /// Returns an error if the columns have different lengths, if both points and columns were
+    /// provided, or if field names are duplicated.
+    pub fn build(self) -> Result<PointCloud, PointCloudError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        let (fields, stride, data) = match (self.points, self.columns.is_empty()) {
+            (Some(_), false) => return Err(PointCloudError::PointsAndColumns),
+            (Some(points), true) => points,
+            (None, _) => interleave(self.columns)?,
+        };
+        Ok(PointCloud {
+            timestamp: self.timestamp,
+            frame_id: self.frame_id,
+            pose: self.pose,
+            point_stride: u32::try_from(stride).map_err(|_| PointCloudError::TooLarge(stride))?,
+            fields,
+            data: data.into(),
+        })
+    }
+}
+
+/// Interleaves columns into packed points.
+fn interleave(
+    columns: Vec<Column>,
+) -> Result<(Vec<PackedElementField>, usize, Vec<u8>), PointCloudError> {
+    let (fields, stride) = layout(columns.iter().map(|c| (c.name.clone(), c.numeric_type)))?;
+    let len = columns.first().map_or(0, |c| c.len);
+    let mut data = Vec::with_capacity(stride * len);
+    for i in 0..len {
+        for column in &columns {
+            data.extend_from_slice(&column.data[i * column.size..(i + 1) * column.size]);
+        }
+    }
+    Ok((fields, stride, data))
+}
+
+/// Reads the fields of a [`PointCloud`].
+///
+/// The layout of the point cloud is validated when the reader is created: each field must have a
+/// known type and fit within the point stride, and the data must contain a whole number of
+/// points.
+#[derive(Debug, Clone, Copy)]
+pub struct PointCloudReader<'a> {
+    cloud: &'a PointCloud,
+    stride: usize,
+    len: usize,
+}
+
+impl<'a> PointCloudReader<'a> {
+    /// Creates a reader for a point cloud, after validating its layout.
+    pub fn new(cloud: &'a PointCloud) -> Result<Self, PointCloudError> {
+        let stride = cloud.point_stride as usize;
+        for field in &cloud.fields {
+            let size = NumericType::try_from(field.r#type)
+                .ok()
+                .and_then(numeric_type_size)
+                .ok_or_else(|| PointCloudError::UnknownFieldType(field.name.clone()))?;
+            if field.offset as usize + size > stride {
+                return Err(PointCloudError::FieldOutOfBounds {
+                    name: field.name.clone(),
+                    offset: field.offset,
+                    stride: cloud.point_stride,
+                });
+            }
+        }
+        let len = match stride {
+            0 if cloud.data.is_empty() => 0,
+            0 => {
+                return Err(PointCloudError::DataLength {
+                    len: cloud.data.len(),
+                    stride: 0,
+                })
+            }
+            _ if !cloud.data.len().is_multiple_of(stride) => {
+                return Err(PointCloudError::DataLength {
+                    len: cloud.data.len(),
+                    stride: cloud.point_stride,
+                })
+            }
+            _ => cloud.data.len() / stride,
+        };
+        Ok(Self { cloud, stride, len })
+    }
+
+    /// Returns the number of points.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the point cloud has no points.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over the values of a field, for each point.
+    ///
+    /// Returns an error if there is no field with the name, or if it does not have type `T`.
+    pub fn field<T: PointFieldType>(
+        &self,
+        name: &str,
+    ) -> Result<FieldIter<'a, T>, PointCloudError> {
+        let field = self
+            .cloud
+            .fields
+            .iter()
+            .find(|f| f.name == name)
+            .ok_or_else(|| PointCloudError::FieldNotFound(name.to_string()))?;
+        let actual = NumericType::try_from(field.r#type).unwrap_or(NumericType::Unknown);
+        if actual != T::NUMERIC_TYPE {
+            return Err(PointCloudError::FieldType {
+                name: name.to_string(),
+                expected: T::NUMERIC_TYPE,
+                actual,
+            });
+        }
+        Ok(FieldIter {
+            data: &self.cloud.data,
+            offset: field.offset as usize,
+            stride: self.stride,
+            index: 0,
+            len: self.len,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+/// An iterator over the values of a point cloud field. See [`PointCloudReader::field`].
+#[derive(Debug, Clone)]
+pub struct FieldIter<'a, T> {
+    data: &'a [u8],
+    offset: usize,
+    stride: usize,
+    index: usize,
+    len: usize,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: PointFieldType> Iterator for FieldIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.index == self.len {
+            return None;
+        }
+        let start = self.index * self.stride + self.offset;
+        self.index += 1;
+        Some(T::read_le(&self.data[start..]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: PointFieldType> ExactSizeIterator for FieldIter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(foxglove_derive::PackedPoint)]
+    struct LidarPoint {
+        x: f32,
+        y: f32,
+        z: f32,
+        intensity: u16,
+        ring: u8,
+    }
+
+    #[test]
+    fn test_build_from_points() {
+        let points = [
+            LidarPoint {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+                intensity: 500,
+                ring: 1,
+            },
+            LidarPoint {
+                x: -1.0,
+                y: -2.0,
+                z: -3.0,
+                intensity: 600,
+                ring: 2,
+            },
+        ];
+        let cloud = PointCloudBuilder::new()
+            .frame_id("lidar")
+            .timestamp(Timestamp::new(1, 2))
+            .points(&points)
+            .build()
+            .unwrap();
+        assert_eq!(cloud.frame_id, "lidar");
+        assert_eq!(cloud.timestamp, Some(Timestamp::new(1, 2)));
+        assert_eq!(cloud.point_stride, 15);
+        let fields: Vec<_> = cloud
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.offset, f.r#type()))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("x", 0, NumericType::Float32),
+                ("y", 4, NumericType::Float32),
+                ("z", 8, NumericType::Float32),
+                ("intensity", 12, NumericType::Uint16),
+                ("ring", 14, NumericType::Uint8),
+            ]
+        );
+        assert_eq!(cloud.data.len(), 30);
+        assert_eq!(&cloud.data[12..15], &[0xf4, 0x01, 0x01]);
+
+        let reader = PointCloudReader::new(&cloud).unwrap();
+        assert_eq!(reader.len(), 2);
+        let x: Vec<f32> = reader.field("x").unwrap().collect();
+        assert_eq!(x, [1.0, -1.0]);
+        let intensity: Vec<u16> = reader.field("intensity").unwrap().collect();
+        assert_eq!(intensity, [500, 600]);
+    }
+
+    #[test]
+    fn test_build_from_columns() {
+        let cloud = PointCloudBuilder::new()
+            .column("x", &[1.0f64, 2.0, 3.0])
+            .column("y", &[4.0f64, 5.0, 6.0])
+            .rgba(&[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]])
+            .build()
+            .unwrap();
+        assert_eq!(cloud.point_stride, 20);
+        let names: Vec<_> = cloud.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["x", "y", "red", "green", "blue", "alpha"]);
+
+        let reader = PointCloudReader::new(&cloud).unwrap();
+        let y: Vec<f64> = reader.field("y").unwrap().collect();
+        assert_eq!(y, [4.0, 5.0, 6.0]);
+        let alpha: Vec<u8> = reader.field("alpha").unwrap().collect();
+        assert_eq!(alpha, [4, 8, 12]);
+
+        // The same layout is produced from points.
+        let points = [Point3::new(1.0, 2.0, 3.0)];
+        let cloud = PointCloudBuilder::new().points(&points).build().unwrap();
+        let reader = PointCloudReader::new(&cloud).unwrap();
+        assert_eq!(reader.field::<f64>("z").unwrap().collect::<Vec<_>>(), [3.0]);
+    }
+
+    #[test]
+    fn test_build_errors() {
+        let err = PointCloudBuilder::new()
+            .column("x", &[1.0f32, 2.0])
+            .column("y", &[1.0f32])
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            PointCloudError::ColumnLength {
+                expected: 2,
+                actual: 1,
+                ..
+            }
+        ));
+
+        let err = PointCloudBuilder::new()
+            .column("x", &[1.0f32])
+            .column("x", &[1.0f32])
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, PointCloudError::DuplicateField(name) if name == "x"));
+
+        let err = PointCloudBuilder::new()
+            .points(&[Point3::default()])
+            .column("intensity", &[1u8])
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, PointCloudError::PointsAndColumns));
+    }
+
+    #[test]
+    fn test_reader_errors() {
+        let cloud = PointCloudBuilder::new()
+            .column("x", &[1.0f32, 2.0])
+            .build()
+            .unwrap();
+        let reader = PointCloudReader::new(&cloud).unwrap();
+        assert!(matches!(
+            reader.field::<f32>("y"),
+            Err(PointCloudError::FieldNotFound(_))
+        ));
+        assert!(matches!(
+            reader.field::<f64>("x"),
+            Err(PointCloudError::FieldType {
+                expected: NumericType::Float64,
+                actual: NumericType::Float32,
+                ..
+            })
+        ));
+
+        let mut invalid = cloud.clone();
+        invalid.point_stride = 3;
+        assert!(matches!(
+            PointCloudReader::new(&invalid),
+            Err(PointCloudError::FieldOutOfBounds {
+                offset: 0,
+                stride: 3,
+                ..
+            })
+        ));
+
+        let mut invalid = cloud.clone();
+        invalid.data = invalid.data.slice(..7);
+        assert!(matches!(
+            PointCloudReader::new(&invalid),
+            Err(PointCloudError::DataLength { len: 7, stride: 4 })
+        ));
+
+        let mut invalid = cloud;
+        invalid.fields[0].r#type = 42;
+        assert!(matches!(
+            PointCloudReader::new(&invalid),
+            Err(PointCloudError::UnknownFieldType(_))
+        ));
+    }
+}
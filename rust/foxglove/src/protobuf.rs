@@ -0,0 +1,1071 @@
+//! Protobuf encoding for custom types.
+//!
+//! The traits in this module describe how Rust types map to protobuf messages, enums and fields.
+//! They are used to generate a protobuf `FileDescriptorSet` schema, and to encode messages,
+//! without writing `.proto` files.
+//!
+//! With the `derive` feature, use `#[derive(foxglove::Encode)]` to implement these traits and
+//! [`Encode`](crate::Encode) for a struct. Fields may be `bool`, `f32`, `f64`, `i32`, `i64`, `u32`,
+//! `u64`, `String`, `Vec<u8>`, [`Bytes`](bytes::Bytes), the well-known
+//! [schema types](crate::schemas), `Option<T>`, `Vec<T>`, and other types that derive `Encode`:
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # mod example {
+//! use foxglove::schemas::{Pose, Timestamp};
+//!
+//! #[derive(foxglove::Encode)]
+//! enum Status {
+//!     Ok,
+//!     Degraded,
+//! }
+//!
+//! #[derive(foxglove::Encode)]
+//! struct Measurement {
+//!     label: String,
+//!     values: Vec<f64>,
+//! }
+//!
+//! #[derive(foxglove::Encode)]
+//! struct Reading {
+//!     timestamp: Option<Timestamp>,
+//!     pose: Option<Pose>,
+//!     status: Status,
+//!     measurements: Vec<Measurement>,
+//! }
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! let channel = foxglove::TypedChannel::<Reading>::new("/reading")?;
+//! # Ok(()) }
+//! # }
+//! ```
+//!
+//! Fields are numbered sequentially, starting at 1, in declaration order, so adding, removing or
+//! reordering fields changes the numbers of the fields after them. To keep the numbers stable, set
+//! them with `#[foxglove(tag = N)]`; a field without a tag is numbered one more than the field
+//! before it. Each message and enum is defined in its own file, and its protobuf package is
+//! derived from its module path.
+//!
+//! # Messages compiled with prost
+//!
+//! Messages generated from `.proto` files by `prost-build` can be logged using their original
+//! definitions. Configure `prost-build` to implement [`prost::Name`] and to write the encoded
+//! `FileDescriptorSet` for the compiled files:
+//!
+//! ```ignore
+//! // build.rs
+//! let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
+//! prost_build::Config::new()
+//!     .enable_type_names()
+//!     .file_descriptor_set_path(out_dir.join("descriptors.bin"))
+//!     .compile_protos(&["proto/robot.proto"], &["proto"])?;
+//! ```
+//!
+//! Then use [`impl_prost_encode!`](crate::impl_prost_encode) to implement [`ProstMessage`] and
+//! [`Encode`](crate::Encode) for the generated types:
+//!
+//! ```ignore
+//! pub mod robot {
+//!     include!(concat!(env!("OUT_DIR"), "/robot.rs"));
+//! }
+//!
+//! const DESCRIPTORS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/descriptors.bin"));
+//!
+//! foxglove::impl_prost_encode!(DESCRIPTORS => robot::Status, robot::Command);
+//! ```
+//!
+//! The schema for each message contains only the file that defines it, and that file's
+//! dependencies. Types that implement [`ProstMessage`] can also be used as fields of types that
+//! derive `Encode`.
+
+use std::collections::{HashMap, HashSet};
+
+use prost::encoding::{self, encode_key, encode_varint, encoded_len_varint, key_len, WireType};
+use prost::Message;
+use prost_types::field_descriptor_proto::Label;
+use prost_types::{
+    DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto,
+    FileDescriptorProto, FileDescriptorSet,
+};
+
+use crate::schemas::{Duration, Timestamp};
+use crate::Schema;
+
+pub use bytes::BufMut;
+pub use prost_types::field_descriptor_proto::Type as FieldType;
+
+/// An error that can occur when encoding a message as protobuf.
+#[derive(Debug, thiserror::Error)]
+#[error("Insufficient buffer capacity: {required} bytes required, but {remaining} remaining")]
+pub struct EncodeError {
+    required: usize,
+    remaining: usize,
+}
+
+/// A reference to a protobuf message or enum type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeRef {
+    /// The fully-qualified type name, such as `foxglove.Log`.
+    pub name: String,
+    /// The name of the file that defines the type, such as `foxglove/Log.proto`.
+    pub file: String,
+}
+
+impl TypeRef {
+    /// Creates a new type reference.
+    pub fn new(name: impl Into<String>, file: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            file: file.into(),
+        }
+    }
+
+    /// Returns the reference for a type defined by a Rust item.
+    ///
+    /// The package is derived from the module path, and the type is defined in its own file.
+    fn for_item(module_path: &str, name: &str) -> Self {
+        let package = module_path.replace("::", ".");
+        let file = format!("{}/{name}.proto", package.replace('.', "/"));
+        Self::new(format!("{package}.{name}"), file)
+    }
+
+    /// Returns the package of the type.
+    fn package(&self) -> &str {
+        self.name
+            .rsplit_once('.')
+            .map_or("", |(package, _)| package)
+    }
+}
+
+/// A set of protobuf file descriptors, used to build the schema for a message.
+///
+/// Files are added after their dependencies.
+#[derive(Debug, Default)]
+pub struct DescriptorSet {
+    files: Vec<FileDescriptorProto>,
+    names: HashSet<String>,
+}
+
+impl DescriptorSet {
+    /// Creates an empty descriptor set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a file, unless a file with the same name has already been added.
+    pub fn add_file(&mut self, file: FileDescriptorProto) {
+        if self.names.insert(file.name().to_string()) {
+            self.files.push(file);
+        }
+    }
+
+    /// Adds the files in an encoded `FileDescriptorSet`.
+    pub(crate) fn add_encoded_files(&mut self, data: &[u8]) {
+        let fds = FileDescriptorSet::decode(data).expect("valid file descriptor set");
+        for file in fds.file {
+            self.add_file(file);
+        }
+    }
+
+    /// Adds a message type, defined by a Rust struct, in its own file.
+    ///
+    /// The `fields` callback describes the fields of the message. If the message has already been
+    /// added, or is being added because it refers to itself, the callback is not invoked.
+    pub fn add_message(
+        &mut self,
+        module_path: &str,
+        name: &str,
+        fields: impl FnOnce(&mut MessageBuilder<'_>),
+    ) -> TypeRef {
+        let type_ref = TypeRef::for_item(module_path, name);
+        // Reserve the file name before describing the fields, in case the message is recursive.
+        if !self.names.insert(type_ref.file.clone()) {
+            return type_ref;
+        }
+        let mut builder = MessageBuilder {
+            set: self,
+            file: &type_ref.file,
+            message: DescriptorProto {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            dependencies: Vec::new(),
+        };
+        fields(&mut builder);
+        let MessageBuilder {
+            message,
+            dependencies,
+            ..
+        } = builder;
+        self.files.push(FileDescriptorProto {
+            name: Some(type_ref.file.clone()),
+            package: Some(type_ref.package().to_string()),
+            dependency: dependencies,
+            message_type: vec![message],
+            syntax: Some("proto3".to_string()),
+            ..Default::default()
+        });
+        type_ref
+    }
+
+    /// Adds an enum type, defined by a Rust enum, in its own file.
+    pub fn add_enum(&mut self, module_path: &str, name: &str, values: &[(&str, i32)]) -> TypeRef {
+        let type_ref = TypeRef::for_item(module_path, name);
+        let value = values
+            .iter()
+            .map(|&(name, number)| EnumValueDescriptorProto {
+                name: Some(name.to_string()),
+                number: Some(number),
+                ..Default::default()
+            })
+            .collect();
+        self.add_file(FileDescriptorProto {
+            name: Some(type_ref.file.clone()),
+            package: Some(type_ref.package().to_string()),
+            enum_type: vec![EnumDescriptorProto {
+                name: Some(name.to_string()),
+                value,
+                ..Default::default()
+            }],
+            syntax: Some("proto3".to_string()),
+            ..Default::default()
+        });
+        type_ref
+    }
+
+    /// Returns the file descriptor set.
+    pub fn into_file_descriptor_set(self) -> FileDescriptorSet {
+        FileDescriptorSet { file: self.files }
+    }
+}
+
+/// Describes the fields of a message. See [`DescriptorSet::add_message`].
+pub struct MessageBuilder<'a> {
+    set: &'a mut DescriptorSet,
+    file: &'a str,
+    message: DescriptorProto,
+    dependencies: Vec<String>,
+}
+
+impl MessageBuilder<'_> {
+    /// Adds a field to the message.
+    pub fn field<T: ProtobufField>(&mut self, name: &str, number: u32) {
+        let type_ref = T::type_ref(self.set);
+        if let Some(type_ref) = &type_ref {
+            if type_ref.file != self.file && !self.dependencies.contains(&type_ref.file) {
+                self.dependencies.push(type_ref.file.clone());
+            }
+        }
+        let label = if T::repeated() {
+            Label::Repeated
+        } else {
+            Label::Optional
+        };
+        self.message.field.push(FieldDescriptorProto {
+            name: Some(name.to_string()),
+            number: Some(i32::try_from(number).expect("field number out of range")),
+            label: Some(label.into()),
+            r#type: Some(T::field_type().into()),
+            type_name: type_ref.map(|type_ref| format!(".{}", type_ref.name)),
+            ..Default::default()
+        });
+    }
+}
+
+/// A type that is encoded as a protobuf message.
+///
+/// This trait is implemented for the well-known [schema types](crate::schemas), and for
+/// [`Timestamp`] and [`Duration`]. With the `derive` feature, it can be derived for a struct with
+/// `#[derive(foxglove::Encode)]`.
+pub trait ProtobufMessage {
+    /// Adds the file that defines the message, and its dependencies, to the descriptor set.
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef;
+
+    /// Encodes the fields of the message, without a length prefix.
+    fn encode_raw(&self, buf: &mut impl BufMut);
+
+    /// Returns the encoded length of the message, without a length prefix.
+    fn encoded_len(&self) -> usize;
+}
+
+/// A type that is encoded as a protobuf enum.
+///
+/// With the `derive` feature, this trait can be derived for an enum whose variants have no
+/// fields, with `#[derive(foxglove::Encode)]`.
+pub trait ProtobufEnum {
+    /// Adds the file that defines the enum to the descriptor set.
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef;
+
+    /// Returns the numeric value of the enum.
+    fn value(&self) -> i32;
+}
+
+/// A type that can be used as the field of a protobuf message.
+pub trait ProtobufField {
+    /// Returns the protobuf type of the field.
+    fn field_type() -> FieldType;
+
+    /// Returns true if the field is repeated.
+    fn repeated() -> bool {
+        false
+    }
+
+    /// For message and enum fields, adds the file that defines the type to the descriptor set.
+    fn type_ref(_set: &mut DescriptorSet) -> Option<TypeRef> {
+        None
+    }
+
+    /// Encodes the field. Fields with default scalar values may be omitted.
+    fn write_field(&self, number: u32, buf: &mut impl BufMut);
+
+    /// Returns the encoded length of the field.
+    fn field_len(&self, number: u32) -> usize;
+}
+
+/// A type that can be used as an element of a repeated protobuf field.
+pub trait ProtobufRepeated: ProtobufField + Sized {
+    /// Encodes a repeated field.
+    fn write_repeated(values: &[Self], number: u32, buf: &mut impl BufMut);
+
+    /// Returns the encoded length of a repeated field.
+    fn repeated_len(values: &[Self], number: u32) -> usize;
+}
+
+/// Implements [`ProtobufField`] and [`ProtobufRepeated`] for a scalar type.
+///
+/// Default values are omitted, and repeated fields are packed.
+macro_rules! impl_scalar_field {
+    ($ty:ty, $field_type:ident, $encoding:ident) => {
+        impl ProtobufField for $ty {
+            fn field_type() -> FieldType {
+                FieldType::$field_type
+            }
+
+            fn write_field(&self, number: u32, buf: &mut impl BufMut) {
+                if *self != <$ty>::default() {
+                    encoding::$encoding::encode(number, self, buf);
+                }
+            }
+
+            fn field_len(&self, number: u32) -> usize {
+                if *self == <$ty>::default() {
+                    0
+                } else {
+                    encoding::$encoding::encoded_len(number, self)
+                }
+            }
+        }
+
+        impl ProtobufRepeated for $ty {
+            fn write_repeated(values: &[Self], number: u32, buf: &mut impl BufMut) {
+                encoding::$encoding::encode_packed(number, values, buf);
+            }
+
+            fn repeated_len(values: &[Self], number: u32) -> usize {
+                encoding::$encoding::encoded_len_packed(number, values)
+            }
+        }
+    };
+}
+
+impl_scalar_field!(bool, Bool, bool);
+impl_scalar_field!(f32, Float, float);
+impl_scalar_field!(f64, Double, double);
+impl_scalar_field!(i32, Int32, int32);
+impl_scalar_field!(i64, Int64, int64);
+impl_scalar_field!(u32, Uint32, uint32);
+impl_scalar_field!(u64, Uint64, uint64);
+
+/// Implements [`ProtobufField`] and [`ProtobufRepeated`] for a length-delimited type.
+///
+/// Empty values are omitted.
+macro_rules! impl_length_delimited_field {
+    ($ty:ty, $field_type:ident, $encoding:ident) => {
+        impl ProtobufField for $ty {
+            fn field_type() -> FieldType {
+                FieldType::$field_type
+            }
+
+            fn write_field(&self, number: u32, buf: &mut impl BufMut) {
+                if !self.is_empty() {
+                    encoding::$encoding::encode(number, self, buf);
+                }
+            }
+
+            fn field_len(&self, number: u32) -> usize {
+                if self.is_empty() {
+                    0
+                } else {
+                    encoding::$encoding::encoded_len(number, self)
+                }
+            }
+        }
+
+        impl ProtobufRepeated for $ty {
+            fn write_repeated(values: &[Self], number: u32, buf: &mut impl BufMut) {
+                encoding::$encoding::encode_repeated(number, values, buf);
+            }
+
+            fn repeated_len(values: &[Self], number: u32) -> usize {
+                encoding::$encoding::encoded_len_repeated(number, values)
+            }
+        }
+    };
+}
+
+impl_length_delimited_field!(String, String, string);
+impl_length_delimited_field!(Vec<u8>, Bytes, bytes);
+impl_length_delimited_field!(bytes::Bytes, Bytes, bytes);
+
+impl<M: ProtobufMessage> ProtobufField for M {
+    fn field_type() -> FieldType {
+        FieldType::Message
+    }
+
+    fn type_ref(set: &mut DescriptorSet) -> Option<TypeRef> {
+        Some(M::type_ref(set))
+    }
+
+    fn write_field(&self, number: u32, buf: &mut impl BufMut) {
+        encode_key(number, WireType::LengthDelimited, buf);
+        encode_varint(self.encoded_len() as u64, buf);
+        self.encode_raw(buf);
+    }
+
+    fn field_len(&self, number: u32) -> usize {
+        let len = self.encoded_len();
+        key_len(number) + encoded_len_varint(len as u64) + len
+    }
+}
+
+impl<M: ProtobufMessage> ProtobufRepeated for M {
+    fn write_repeated(values: &[Self], number: u32, buf: &mut impl BufMut) {
+        for value in values {
+            value.write_field(number, buf);
+        }
+    }
+
+    fn repeated_len(values: &[Self], number: u32) -> usize {
+        values.iter().map(|value| value.field_len(number)).sum()
+    }
+}
+
+impl<T: ProtobufField> ProtobufField for Option<T> {
+    fn field_type() -> FieldType {
+        T::field_type()
+    }
+
+    fn repeated() -> bool {
+        T::repeated()
+    }
+
+    fn type_ref(set: &mut DescriptorSet) -> Option<TypeRef> {
+        T::type_ref(set)
+    }
+
+    fn write_field(&self, number: u32, buf: &mut impl BufMut) {
+        if let Some(value) = self {
+            value.write_field(number, buf);
+        }
+    }
+
+    fn field_len(&self, number: u32) -> usize {
+        self.as_ref().map_or(0, |value| value.field_len(number))
+    }
+}
+
+impl<T: ProtobufRepeated> ProtobufField for Vec<T> {
+    fn field_type() -> FieldType {
+        T::field_type()
+    }
+
+    fn repeated() -> bool {
+        true
+    }
+
+    fn type_ref(set: &mut DescriptorSet) -> Option<TypeRef> {
+        T::type_ref(set)
+    }
+
+    fn write_field(&self, number: u32, buf: &mut impl BufMut) {
+        T::write_repeated(self, number, buf);
+    }
+
+    fn field_len(&self, number: u32) -> usize {
+        T::repeated_len(self, number)
+    }
+}
+
+/// Returns the definition of `google/protobuf/timestamp.proto` or `duration.proto`.
+///
+/// Both types have the same fields.
+fn well_known_type(name: &str) -> (TypeRef, FileDescriptorProto) {
+    let type_ref = TypeRef::new(
+        format!("google.protobuf.{name}"),
+        format!("google/protobuf/{}.proto", name.to_lowercase()),
+    );
+    let field = |name: &str, number: i32, r#type: FieldType| FieldDescriptorProto {
+        name: Some(name.to_string()),
+        number: Some(number),
+        label: Some(Label::Optional.into()),
+        r#type: Some(r#type.into()),
+        ..Default::default()
+    };
+    let file = FileDescriptorProto {
+        name: Some(type_ref.file.clone()),
+        package: Some("google.protobuf".to_string()),
+        message_type: vec![DescriptorProto {
+            name: Some(name.to_string()),
+            field: vec![
+                field("seconds", 1, FieldType::Int64),
+                field("nanos", 2, FieldType::Int32),
+            ],
+            ..Default::default()
+        }],
+        syntax: Some("proto3".to_string()),
+        ..Default::default()
+    };
+    (type_ref, file)
+}
+
+impl ProtobufMessage for Timestamp {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        let (type_ref, file) = well_known_type("Timestamp");
+        set.add_file(file);
+        type_ref
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        Message::encode_raw(self, buf);
+    }
+
+    fn encoded_len(&self) -> usize {
+        Message::encoded_len(self)
+    }
+}
+
+impl ProtobufMessage for Duration {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        let (type_ref, file) = well_known_type("Duration");
+        set.add_file(file);
+        type_ref
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        Message::encode_raw(self, buf);
+    }
+
+    fn encoded_len(&self) -> usize {
+        Message::encoded_len(self)
+    }
+}
+
+/// A message compiled with prost, whose definition is available as an encoded
+/// `FileDescriptorSet`.
+///
+/// Implementing this trait implements [`ProtobufMessage`] for the type. To also implement
+/// [`Encode`](crate::Encode), use [`impl_prost_encode!`](crate::impl_prost_encode).
+pub trait ProstMessage: Message + prost::Name {
+    /// An encoded `FileDescriptorSet` which contains the file that defines the message, and its
+    /// dependencies. It may contain other files, such as the output of `prost-build`'s
+    /// `file_descriptor_set_path` option.
+    const FILE_DESCRIPTOR_SET: &'static [u8];
+}
+
+impl<T: ProstMessage> ProtobufMessage for T {
+    /// Adds the file that defines the message, and its dependencies, from
+    /// [`FILE_DESCRIPTOR_SET`](ProstMessage::FILE_DESCRIPTOR_SET).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the descriptor set is invalid, or does not define the message.
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        let name = T::full_name();
+        let fds =
+            FileDescriptorSet::decode(T::FILE_DESCRIPTOR_SET).expect("valid file descriptor set");
+        let Some(file) = fds.file.iter().find(|file| defines_message(file, &name)) else {
+            panic!("File descriptor set does not define {name}");
+        };
+        let type_ref = TypeRef::new(name, file.name());
+        let mut files: HashMap<_, _> = fds
+            .file
+            .into_iter()
+            .map(|file| (file.name().to_string(), file))
+            .collect();
+        add_file_with_dependencies(set, &mut files, &type_ref.file);
+        type_ref
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        Message::encode_raw(self, buf);
+    }
+
+    fn encoded_len(&self) -> usize {
+        Message::encoded_len(self)
+    }
+}
+
+/// Returns true if the file defines a message with the fully-qualified name, including nested
+/// messages.
+fn defines_message(file: &FileDescriptorProto, full_name: &str) -> bool {
+    fn find(messages: &[DescriptorProto], prefix: &str, full_name: &str) -> bool {
+        messages.iter().any(|message| {
+            let name = if prefix.is_empty() {
+                message.name().to_string()
+            } else {
+                format!("{prefix}.{}", message.name())
+            };
+            name == full_name || find(&message.nested_type, &name, full_name)
+        })
+    }
+    find(&file.message_type, file.package(), full_name)
+}
+
+/// Adds a file to the descriptor set, after its dependencies.
+///
+/// Files are removed from the map as they are added. Dependencies that are missing from the map
+/// are skipped.
+fn add_file_with_dependencies(
+    set: &mut DescriptorSet,
+    files: &mut HashMap<String, FileDescriptorProto>,
+    name: &str,
+) {
+    let Some(file) = files.remove(name) else {
+        return;
+    };
+    for dependency in &file.dependency {
+        add_file_with_dependencies(set, files, dependency);
+    }
+    set.add_file(file);
+}
+
+/// Implements [`ProstMessage`] and [`Encode`](crate::Encode) for messages compiled with prost.
+///
+/// The first argument is an encoded `FileDescriptorSet` that defines the messages, followed by
+/// `=>` and a list of message types. Each type must implement [`prost::Name`], which `prost-build`
+/// generates with the `enable_type_names` option. See the [module documentation](self) for an
+/// example.
+///
+/// Prefer passing the descriptor set as the name of a constant, rather than an `include_bytes!`
+/// expression, so that it's only embedded in the binary once.
+#[macro_export]
+macro_rules! impl_prost_encode {
+    ($descriptors:expr => $($ty:ty),+ $(,)?) => {
+        $(
+            impl $crate::protobuf::ProstMessage for $ty {
+                const FILE_DESCRIPTOR_SET: &'static [u8] = $descriptors;
+            }
+
+            impl $crate::Encode for $ty {
+                type Error = $crate::protobuf::EncodeError;
+
+                fn get_schema() -> ::core::option::Option<$crate::Schema> {
+                    ::core::option::Option::Some($crate::protobuf::schema::<Self>())
+                }
+
+                fn get_message_encoding() -> ::std::string::String {
+                    ::std::string::String::from("protobuf")
+                }
+
+                fn encode(
+                    &self,
+                    buf: &mut impl $crate::protobuf::BufMut,
+                ) -> ::core::result::Result<(), Self::Error> {
+                    $crate::protobuf::encode(self, buf)
+                }
+
+                fn encoded_len(&self) -> ::core::option::Option<usize> {
+                    ::core::option::Option::Some(
+                        <Self as $crate::protobuf::ProtobufMessage>::encoded_len(self),
+                    )
+                }
+            }
+        )+
+    };
+}
+
+/// Returns the protobuf schema for a message.
+pub fn schema<M: ProtobufMessage>() -> Schema {
+    let mut set = DescriptorSet::new();
+    let type_ref = M::type_ref(&mut set);
+    Schema::new(
+        type_ref.name,
+        "protobuf",
+        set.into_file_descriptor_set().encode_to_vec(),
+    )
+}
+
+/// Encodes a message to the buffer.
+///
+/// Returns an error if the buffer does not have sufficient capacity.
+pub fn encode<M: ProtobufMessage>(msg: &M, buf: &mut impl BufMut) -> Result<(), EncodeError> {
+    let required = msg.encoded_len();
+    let remaining = buf.remaining_mut();
+    if required > remaining {
+        return Err(EncodeError {
+            required,
+            remaining,
+        });
+    }
+    msg.encode_raw(buf);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::Pose;
+    use crate::Encode;
+
+    #[derive(foxglove_derive::Encode)]
+    enum Status {
+        Ok,
+        Degraded = 3,
+    }
+
+    #[derive(foxglove_derive::Encode)]
+    struct Measurement {
+        label: String,
+        values: Vec<f64>,
+    }
+
+    #[derive(foxglove_derive::Encode)]
+    struct Reading {
+        timestamp: Option<Timestamp>,
+        pose: Option<Pose>,
+        status: Status,
+        statuses: Vec<Status>,
+        measurements: Vec<Measurement>,
+        data: Vec<u8>,
+        r#type: u32,
+    }
+
+    #[derive(foxglove_derive::Encode)]
+    struct Tagged {
+        #[foxglove(tag = 4)]
+        label: String,
+        value: f64,
+        #[foxglove(tag = 1)]
+        count: u32,
+    }
+
+    /// The protobuf representation of `Tagged`, for decoding.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct TaggedProto {
+        #[prost(string, tag = "4")]
+        label: String,
+        #[prost(double, tag = "5")]
+        value: f64,
+        #[prost(uint32, tag = "1")]
+        count: u32,
+    }
+
+    #[derive(foxglove_derive::Encode)]
+    struct Node {
+        children: Vec<Node>,
+    }
+
+    /// The protobuf representation of `Reading`, for decoding.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ReadingProto {
+        #[prost(message, optional, tag = "1")]
+        timestamp: Option<prost_types::Timestamp>,
+        #[prost(message, optional, tag = "2")]
+        pose: Option<Pose>,
+        #[prost(int32, tag = "3")]
+        status: i32,
+        #[prost(int32, repeated, tag = "4")]
+        statuses: Vec<i32>,
+        #[prost(message, repeated, tag = "5")]
+        measurements: Vec<MeasurementProto>,
+        #[prost(bytes = "vec", tag = "6")]
+        data: Vec<u8>,
+        #[prost(uint32, tag = "7")]
+        r#type: u32,
+    }
+
+    /// The protobuf representation of `Measurement`, for decoding.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct MeasurementProto {
+        #[prost(string, tag = "1")]
+        label: String,
+        #[prost(double, repeated, tag = "2")]
+        values: Vec<f64>,
+    }
+
+    /// A message compiled with prost, which is defined in a larger descriptor set.
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ProstPose {
+        #[prost(message, optional, tag = "1")]
+        position: Option<crate::schemas::Vector3>,
+    }
+
+    impl prost::Name for ProstPose {
+        const NAME: &'static str = "Pose";
+        const PACKAGE: &'static str = "foxglove";
+    }
+
+    crate::impl_prost_encode!(crate::schemas::descriptors::SCENE_UPDATE => ProstPose);
+
+    #[derive(foxglove_derive::Encode)]
+    struct Waypoint {
+        pose: Option<ProstPose>,
+    }
+
+    fn file_descriptor_set<T: Encode>() -> FileDescriptorSet {
+        let schema = T::get_schema().unwrap();
+        FileDescriptorSet::decode(schema.data.as_ref()).unwrap()
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Reading::get_schema().unwrap();
+        let package = module_path!().replace("::", ".");
+        assert_eq!(schema.name, format!("{package}.Reading"));
+        assert_eq!(schema.encoding, "protobuf");
+        assert_eq!(Reading::get_message_encoding(), "protobuf");
+
+        let fds = file_descriptor_set::<Reading>();
+        let dir = package.replace('.', "/");
+        let files: Vec<_> = fds.file.iter().map(|f| f.name()).collect();
+        // Dependencies precede the files that depend on them.
+        assert_eq!(
+            files,
+            [
+                "google/protobuf/timestamp.proto".to_string(),
+                "foxglove/Quaternion.proto".to_string(),
+                "foxglove/Vector3.proto".to_string(),
+                "foxglove/Pose.proto".to_string(),
+                format!("{dir}/Status.proto"),
+                format!("{dir}/Measurement.proto"),
+                format!("{dir}/Reading.proto"),
+            ]
+        );
+
+        let file = fds.file.last().unwrap();
+        assert_eq!(file.package(), package);
+        assert_eq!(file.syntax(), "proto3");
+        assert_eq!(
+            file.dependency,
+            [
+                "google/protobuf/timestamp.proto".to_string(),
+                "foxglove/Pose.proto".to_string(),
+                format!("{dir}/Status.proto"),
+                format!("{dir}/Measurement.proto"),
+            ]
+        );
+        let fields: Vec<_> = file.message_type[0]
+            .field
+            .iter()
+            .map(|f| (f.name(), f.number(), f.label(), f.r#type(), f.type_name()))
+            .collect();
+        let status = format!(".{package}.Status");
+        let measurement = format!(".{package}.Measurement");
+        assert_eq!(
+            fields,
+            [
+                (
+                    "timestamp",
+                    1,
+                    Label::Optional,
+                    FieldType::Message,
+                    ".google.protobuf.Timestamp"
+                ),
+                (
+                    "pose",
+                    2,
+                    Label::Optional,
+                    FieldType::Message,
+                    ".foxglove.Pose"
+                ),
+                ("status", 3, Label::Optional, FieldType::Enum, &status),
+                ("statuses", 4, Label::Repeated, FieldType::Enum, &status),
+                (
+                    "measurements",
+                    5,
+                    Label::Repeated,
+                    FieldType::Message,
+                    &measurement
+                ),
+                ("data", 6, Label::Optional, FieldType::Bytes, ""),
+                ("type", 7, Label::Optional, FieldType::Uint32, ""),
+            ]
+        );
+
+        let status = &fds.file[4].enum_type[0];
+        let values: Vec<_> = status
+            .value
+            .iter()
+            .map(|v| (v.name(), v.number()))
+            .collect();
+        assert_eq!(values, [("Ok", 0), ("Degraded", 3)]);
+    }
+
+    #[test]
+    fn test_tagged_fields() {
+        let fds = file_descriptor_set::<Tagged>();
+        let fields: Vec<_> = fds.file[0].message_type[0]
+            .field
+            .iter()
+            .map(|f| (f.name(), f.number()))
+            .collect();
+        assert_eq!(fields, [("label", 4), ("value", 5), ("count", 1)]);
+
+        let mut buf = Vec::new();
+        Tagged {
+            label: "x".to_string(),
+            value: 1.5,
+            count: 3,
+        }
+        .encode(&mut buf)
+        .unwrap();
+        assert_eq!(
+            TaggedProto::decode(buf.as_slice()).unwrap(),
+            TaggedProto {
+                label: "x".to_string(),
+                value: 1.5,
+                count: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_recursive_schema() {
+        let fds = file_descriptor_set::<Node>();
+        assert_eq!(fds.file.len(), 1);
+        assert!(fds.file[0].dependency.is_empty());
+        let field = &fds.file[0].message_type[0].field[0];
+        assert_eq!(
+            field.type_name(),
+            format!(".{}.Node", module_path!().replace("::", "."))
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        let reading = Reading {
+            timestamp: Some(Timestamp::new(1, 2)),
+            pose: None,
+            status: Status::Degraded,
+            statuses: vec![Status::Ok, Status::Degraded],
+            measurements: vec![
+                Measurement {
+                    label: "a".to_string(),
+                    values: vec![1.0, 2.0],
+                },
+                Measurement {
+                    label: String::new(),
+                    values: vec![],
+                },
+            ],
+            data: vec![1, 2, 3],
+            r#type: 4,
+        };
+        let mut buf = vec![];
+        reading.encode(&mut buf).unwrap();
+        assert_eq!(Some(buf.len()), Encode::encoded_len(&reading));
+
+        let decoded = ReadingProto::decode(buf.as_slice()).unwrap();
+        assert_eq!(
+            decoded,
+            ReadingProto {
+                timestamp: Some(prost_types::Timestamp {
+                    seconds: 1,
+                    nanos: 2
+                }),
+                pose: None,
+                status: 3,
+                statuses: vec![0, 3],
+                measurements: vec![
+                    MeasurementProto {
+                        label: "a".to_string(),
+                        values: vec![1.0, 2.0],
+                    },
+                    MeasurementProto::default(),
+                ],
+                data: vec![1, 2, 3],
+                r#type: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn test_encode_insufficient_capacity() {
+        let msg = Measurement {
+            label: "label".to_string(),
+            values: vec![],
+        };
+        let mut buf = [0u8; 4];
+        let err = msg.encode(&mut buf.as_mut_slice()).unwrap_err();
+        assert_eq!(err.required, 7);
+        assert_eq!(err.remaining, 4);
+    }
+
+    #[test]
+    fn test_prost_message() {
+        let schema = ProstPose::get_schema().unwrap();
+        assert_eq!(schema.name, "foxglove.Pose");
+        assert_eq!(schema.encoding, "protobuf");
+        assert_eq!(ProstPose::get_message_encoding(), "protobuf");
+
+        // Only the file that defines the message and its dependencies are included.
+        let fds = file_descriptor_set::<ProstPose>();
+        let files: Vec<_> = fds.file.iter().map(|f| f.name()).collect();
+        assert_eq!(
+            files,
+            [
+                "foxglove/Quaternion.proto",
+                "foxglove/Vector3.proto",
+                "foxglove/Pose.proto"
+            ]
+        );
+
+        let msg = ProstPose {
+            position: Some(crate::schemas::Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            }),
+        };
+        let mut buf = vec![];
+        Encode::encode(&msg, &mut buf).unwrap();
+        assert_eq!(Some(buf.len()), Encode::encoded_len(&msg));
+        assert_eq!(ProstPose::decode(buf.as_slice()).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_prost_message_field() {
+        let fds = file_descriptor_set::<Waypoint>();
+        let files: Vec<_> = fds.file.iter().map(|f| f.name()).collect();
+        let dir = module_path!().replace("::", "/");
+        assert_eq!(
+            files,
+            [
+                "foxglove/Quaternion.proto".to_string(),
+                "foxglove/Vector3.proto".to_string(),
+                "foxglove/Pose.proto".to_string(),
+                format!("{dir}/Waypoint.proto"),
+            ]
+        );
+        let field = &fds.file[3].message_type[0].field[0];
+        assert_eq!(field.type_name(), ".foxglove.Pose");
+    }
+
+    #[test]
+    #[should_panic(expected = "File descriptor set does not define foxglove.Pose")]
+    fn test_prost_message_undefined() {
+        #[derive(Clone, PartialEq, prost::Message)]
+        struct Pose {}
+
+        impl prost::Name for Pose {
+            const NAME: &'static str = "Pose";
+            const PACKAGE: &'static str = "foxglove";
+        }
+
+        impl ProstMessage for Pose {
+            const FILE_DESCRIPTOR_SET: &'static [u8] = crate::schemas::descriptors::LOG;
+        }
+
+        schema::<Pose>();
+    }
+}
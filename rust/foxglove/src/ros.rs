@@ -0,0 +1,157 @@
+//! Support shared by the [`ros1`](crate::ros1) and [`ros2`](crate::ros2) encodings.
+//!
+//! The two formats differ only in their byte layout: ROS 1 writes values without padding, while
+//! CDR aligns them to their size. The writers implement the primitive methods for their layout,
+//! and share the methods defined here, which are built on top of them.
+
+/// An error that can occur when encoding a message for ROS 1 or ROS 2.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum EncodeError {
+    /// A fixed-size array field has the wrong number of elements.
+    #[error("Field {field} must have {expected} elements, but has {actual}")]
+    ArrayLength {
+        /// The name of the field.
+        field: &'static str,
+        /// The required number of elements.
+        expected: usize,
+        /// The actual number of elements.
+        actual: usize,
+    },
+    /// A field value cannot be represented by its ROS type.
+    #[error("Field {0} is out of range for its ROS type")]
+    OutOfRange(&'static str),
+    /// A string or sequence has too many elements to be encoded.
+    #[error("Sequence of length {0} is too long to encode")]
+    TooLong(usize),
+}
+
+/// Defines the methods which are shared by the ROS 1 and ROS 2 writers.
+///
+/// The writer must have the primitive methods `write_u8`, `write_i32` and `write_u32`, and a
+/// `write_sec` method which writes the seconds of a timestamp. The nested messages written by
+/// `write_message` are encoded with the given trait method.
+macro_rules! writer_methods {
+    ($encode_trait:ident, $encode_fn:ident) => {
+        /// Writes a `bool`.
+        pub fn write_bool(&mut self, value: bool) {
+            self.write_u8(value.into());
+        }
+
+        /// Writes the length of a string or sequence.
+        pub fn write_len(&mut self, len: usize) -> Result<(), EncodeError> {
+            let len = u32::try_from(len).map_err(|_| EncodeError::TooLong(len))?;
+            self.write_u32(len);
+            Ok(())
+        }
+
+        /// Checks the length of a fixed-size array, which is written without a length prefix.
+        pub fn check_array_len(
+            &self,
+            field: &'static str,
+            len: usize,
+            expected: usize,
+        ) -> Result<(), EncodeError> {
+            if len == expected {
+                Ok(())
+            } else {
+                Err(EncodeError::ArrayLength {
+                    field,
+                    expected,
+                    actual: len,
+                })
+            }
+        }
+
+        /// Writes an enum value as a `uint8`.
+        pub fn write_enum(&mut self, field: &'static str, value: i32) -> Result<(), EncodeError> {
+            let value = u8::try_from(value).map_err(|_| EncodeError::OutOfRange(field))?;
+            self.write_u8(value);
+            Ok(())
+        }
+
+        /// Writes a time. If the timestamp is absent, zero is written.
+        pub fn write_time(
+            &mut self,
+            field: &'static str,
+            value: Option<&$crate::schemas::Timestamp>,
+        ) -> Result<(), EncodeError> {
+            let value = value.copied().unwrap_or_default();
+            self.write_sec(field, value.sec())?;
+            self.write_u32(value.nsec());
+            Ok(())
+        }
+
+        /// Writes a duration. If the duration is absent, zero is written.
+        pub fn write_duration(&mut self, value: Option<&$crate::schemas::Duration>) {
+            let value = value.copied().unwrap_or_default();
+            self.write_i32(value.sec());
+            // The nanoseconds are always less than 1e9, so the `int32` encoding is the same.
+            self.write_u32(value.nsec());
+        }
+
+        /// Writes a nested message. If the message is absent, its default value is written.
+        pub fn write_message<T: $encode_trait + Default>(
+            &mut self,
+            value: Option<&T>,
+        ) -> Result<(), EncodeError> {
+            match value {
+                Some(value) => value.$encode_fn(self),
+                None => T::default().$encode_fn(self),
+            }
+        }
+    };
+}
+
+/// Defines a wrapper for logging a message with a ROS encoding.
+///
+/// The wrapper implements [`Encode`](crate::Encode) for messages which implement the given trait,
+/// by encoding them with the given trait method and writer.
+macro_rules! ros_wrapper {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident;
+        encode = $encode_trait:ident::$encode_fn:ident;
+        writer = $writer:ident;
+        message_encoding = $message_encoding:literal;
+        schema_encoding = $schema_encoding:literal;
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Default, Clone, PartialEq)]
+        $vis struct $name<T>(pub T);
+
+        impl<T> From<T> for $name<T> {
+            fn from(msg: T) -> Self {
+                Self(msg)
+            }
+        }
+
+        impl<T: $encode_trait> $crate::Encode for $name<T> {
+            type Error = EncodeError;
+
+            fn get_schema() -> Option<$crate::Schema> {
+                Some($crate::Schema::new(
+                    T::TYPE_NAME,
+                    $schema_encoding,
+                    T::DEFINITION.as_bytes(),
+                ))
+            }
+
+            fn get_message_encoding() -> String {
+                $message_encoding.to_string()
+            }
+
+            fn encode(&self, buf: &mut impl bytes::BufMut) -> Result<(), Self::Error> {
+                let mut w = $writer::new(buf);
+                self.0.$encode_fn(&mut w)
+            }
+
+            fn validate(&self) -> Result<(), $crate::validate::ValidationError> {
+                self.0.validate()
+            }
+        }
+    };
+}
+
+pub(crate) use ros_wrapper;
+pub(crate) use writer_methods;
@@ -0,0 +1,198 @@
+//! ROS 1 encoding for well-known Foxglove schemas.
+//!
+//! The [schema types](crate::schemas) are encoded as protobuf by default. To log them as
+//! `ros1`-encoded messages with a `ros1msg` schema instead, so that recordings can be consumed by
+//! ROS 1 tools, wrap the message type in [`Ros1`]. The choice is made per channel, so protobuf
+//! and ROS 1 channels can be used side by side.
+//!
+//! The schema data is the concatenated message definition that MCAP expects for `ros1msg`
+//! schemas: the definition of the message itself, followed by the definitions of each of its
+//! dependencies.
+//!
+//! ```no_run
+//! use foxglove::ros1::Ros1;
+//! use foxglove::schemas::{Log, log::Level};
+//! use foxglove::TypedChannel;
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! // Logged with the schema "foxglove_msgs/Log".
+//! let channel = TypedChannel::<Ros1<Log>>::new("/rosout")?;
+//! channel.log(&Ros1(Log {
+//!     level: Level::Info.into(),
+//!     message: "Hello, ROS 1!".to_string(),
+//!     ..Default::default()
+//! }));
+//! # Ok(()) }
+//! ```
+
+use crate::validate::ValidationError;
+use bytes::BufMut;
+
+#[rustfmt::skip]
+mod impls;
+
+pub use crate::ros::EncodeError;
+
+/// A message that can be encoded for ROS 1, with a corresponding ROS 1 message definition.
+///
+/// This trait is implemented for the well-known [schema types](crate::schemas). Wrap a message in
+/// [`Ros1`] to log it.
+pub trait Ros1Encode {
+    /// The ROS 1 type name, such as `foxglove_msgs/Log`.
+    const TYPE_NAME: &'static str;
+
+    /// The `ros1msg` definition, including the definitions of all of its dependencies.
+    const DEFINITION: &'static str;
+
+    /// Serializes the message using the provided writer.
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError>;
+
+    /// Optional. Checks the invariants of the message; see
+    /// [`Encode::validate`](crate::Encode::validate).
+    fn validate(&self) -> Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+crate::ros::ros_wrapper! {
+    /// A wrapper for logging a message with ROS 1 encoding.
+    ///
+    /// Use `TypedChannel<Ros1<T>>` to create a channel with message encoding `ros1`, and schema
+    /// encoding `ros1msg`.
+    pub struct Ros1;
+    encode = Ros1Encode::encode_ros1;
+    writer = Ros1Writer;
+    message_encoding = "ros1";
+    schema_encoding = "ros1msg";
+}
+
+/// A writer for the ROS 1 serialization format.
+///
+/// Values are written in little-endian byte order, without padding. Strings and variable-length
+/// arrays are prefixed with their length as a `uint32`.
+pub struct Ros1Writer<B> {
+    buf: B,
+}
+
+impl<B: BufMut> Ros1Writer<B> {
+    /// Creates a new writer.
+    pub fn new(buf: B) -> Self {
+        Self { buf }
+    }
+
+    crate::ros::writer_methods!(Ros1Encode, encode_ros1);
+
+    /// Writes a `uint8`.
+    pub fn write_u8(&mut self, value: u8) {
+        self.buf.put_u8(value);
+    }
+
+    /// Writes an `int32`.
+    pub fn write_i32(&mut self, value: i32) {
+        self.buf.put_i32_le(value);
+    }
+
+    /// Writes a `uint32`.
+    pub fn write_u32(&mut self, value: u32) {
+        self.buf.put_u32_le(value);
+    }
+
+    /// Writes a `float64`.
+    pub fn write_f64(&mut self, value: f64) {
+        self.buf.put_f64_le(value);
+    }
+
+    /// Writes a `uint8[]`.
+    pub fn write_bytes(&mut self, data: &[u8]) -> Result<(), EncodeError> {
+        self.write_len(data.len())?;
+        self.buf.put_slice(data);
+        Ok(())
+    }
+
+    /// Writes a `string`.
+    pub fn write_string(&mut self, value: &str) -> Result<(), EncodeError> {
+        self.write_bytes(value.as_bytes())
+    }
+
+    /// Writes the seconds of a `time`. They are unsigned, so every
+    /// [`Timestamp`](crate::schemas::Timestamp) is representable.
+    fn write_sec(&mut self, _field: &'static str, sec: u32) -> Result<(), EncodeError> {
+        self.write_u32(sec);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::{CameraCalibration, Log, Point3, SceneUpdate, Timestamp};
+    use crate::Encode;
+
+    fn encode<T: Ros1Encode>(msg: T) -> Vec<u8> {
+        let mut buf = vec![];
+        Ros1(msg).encode(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Ros1::<SceneUpdate>::get_schema().unwrap();
+        assert_eq!(schema.name, "foxglove_msgs/SceneUpdate");
+        assert_eq!(schema.encoding, "ros1msg");
+        let definition = std::str::from_utf8(&schema.data).unwrap();
+        assert!(definition.starts_with("# foxglove_msgs/SceneUpdate\n"));
+        assert!(definition.contains(
+            "\n================================================================================\n\
+             MSG: foxglove_msgs/SceneEntity\n"
+        ));
+        assert_eq!(definition.matches("MSG: geometry_msgs/Pose\n").count(), 1);
+        assert_eq!(Ros1::<SceneUpdate>::get_message_encoding(), "ros1");
+
+        let schema = Ros1::<Point3>::get_schema().unwrap();
+        assert_eq!(schema.name, "geometry_msgs/Point");
+    }
+
+    #[test]
+    fn test_encode() {
+        let buf = encode(Log {
+            timestamp: Some(Timestamp::new(u32::MAX, 2)),
+            level: 2,
+            message: "hi".to_string(),
+            line: 7,
+            ..Default::default()
+        });
+        #[rustfmt::skip]
+        assert_eq!(buf, [
+            255, 255, 255, 255, 2, 0, 0, 0, // timestamp
+            2, // level
+            2, 0, 0, 0, b'h', b'i', // message
+            0, 0, 0, 0, // name
+            0, 0, 0, 0, // file
+            7, 0, 0, 0, // line
+        ]);
+    }
+
+    #[test]
+    fn test_encode_fixed_size_arrays() {
+        let buf = encode(CameraCalibration {
+            k: vec![1.0; 9],
+            r: vec![2.0; 9],
+            p: vec![3.0; 12],
+            ..Default::default()
+        });
+        // timestamp, frame_id, width, height, distortion_model, D, K, R, P
+        assert_eq!(buf.len(), 8 + 4 + 4 + 4 + 4 + 4 + 8 * (9 + 9 + 12));
+
+        let err = Ros1(CameraCalibration::default())
+            .encode(&mut vec![])
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            EncodeError::ArrayLength {
+                field: "K",
+                expected: 9,
+                actual: 0
+            }
+        ));
+    }
+}
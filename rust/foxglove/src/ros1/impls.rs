@@ -0,0 +1,779 @@
+// This file is @generated by foxglove-proto-gen
+use super::{Ros1Encode, Ros1Writer, EncodeError};
+use crate::schemas::*;
+use crate::validate::{validate, ValidationError};
+use bytes::BufMut;
+
+impl Ros1Encode for ArrowPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/ArrowPrimitive";
+    const DEFINITION: &'static str = include_str!("data/ArrowPrimitive.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_f64(self.shaft_length);
+        w.write_f64(self.shaft_diameter);
+        w.write_f64(self.head_length);
+        w.write_f64(self.head_diameter);
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for CameraCalibration {
+    const TYPE_NAME: &'static str = "foxglove_msgs/CameraCalibration";
+    const DEFINITION: &'static str = include_str!("data/CameraCalibration.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_u32(self.width);
+        w.write_u32(self.height);
+        w.write_string(&self.distortion_model)?;
+        w.write_len(self.d.len())?;
+        for v in &self.d {
+            w.write_f64(*v);
+        }
+        w.check_array_len("K", self.k.len(), 9)?;
+        for v in &self.k {
+            w.write_f64(*v);
+        }
+        w.check_array_len("R", self.r.len(), 9)?;
+        for v in &self.r {
+            w.write_f64(*v);
+        }
+        w.check_array_len("P", self.p.len(), 12)?;
+        for v in &self.p {
+            w.write_f64(*v);
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for CircleAnnotation {
+    const TYPE_NAME: &'static str = "foxglove_msgs/CircleAnnotation";
+    const DEFINITION: &'static str = include_str!("data/CircleAnnotation.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_message(self.position.as_ref())?;
+        w.write_f64(self.diameter);
+        w.write_f64(self.thickness);
+        w.write_message(self.fill_color.as_ref())?;
+        w.write_message(self.outline_color.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for Color {
+    const TYPE_NAME: &'static str = "foxglove_msgs/Color";
+    const DEFINITION: &'static str = include_str!("data/Color.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_f64(self.r);
+        w.write_f64(self.g);
+        w.write_f64(self.b);
+        w.write_f64(self.a);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for CompressedImage {
+    const TYPE_NAME: &'static str = "foxglove_msgs/CompressedImage";
+    const DEFINITION: &'static str = include_str!("data/CompressedImage.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_bytes(&self.data)?;
+        w.write_string(&self.format)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for CompressedVideo {
+    const TYPE_NAME: &'static str = "foxglove_msgs/CompressedVideo";
+    const DEFINITION: &'static str = include_str!("data/CompressedVideo.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_bytes(&self.data)?;
+        w.write_string(&self.format)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for CubePrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/CubePrimitive";
+    const DEFINITION: &'static str = include_str!("data/CubePrimitive.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.size.as_ref())?;
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for CylinderPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/CylinderPrimitive";
+    const DEFINITION: &'static str = include_str!("data/CylinderPrimitive.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.size.as_ref())?;
+        w.write_f64(self.bottom_scale);
+        w.write_f64(self.top_scale);
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for FrameTransform {
+    const TYPE_NAME: &'static str = "foxglove_msgs/FrameTransform";
+    const DEFINITION: &'static str = include_str!("data/FrameTransform.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.parent_frame_id)?;
+        w.write_string(&self.child_frame_id)?;
+        w.write_message(self.translation.as_ref())?;
+        w.write_message(self.rotation.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for FrameTransforms {
+    const TYPE_NAME: &'static str = "foxglove_msgs/FrameTransforms";
+    const DEFINITION: &'static str = include_str!("data/FrameTransforms.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_len(self.transforms.len())?;
+        for v in &self.transforms {
+            v.encode_ros1(w)?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for GeoJson {
+    const TYPE_NAME: &'static str = "foxglove_msgs/GeoJSON";
+    const DEFINITION: &'static str = include_str!("data/GeoJson.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_string(&self.geojson)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for Grid {
+    const TYPE_NAME: &'static str = "foxglove_msgs/Grid";
+    const DEFINITION: &'static str = include_str!("data/Grid.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_u32(self.column_count);
+        w.write_message(self.cell_size.as_ref())?;
+        w.write_u32(self.row_stride);
+        w.write_u32(self.cell_stride);
+        w.write_len(self.fields.len())?;
+        for v in &self.fields {
+            v.encode_ros1(w)?;
+        }
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for ImageAnnotations {
+    const TYPE_NAME: &'static str = "foxglove_msgs/ImageAnnotations";
+    const DEFINITION: &'static str = include_str!("data/ImageAnnotations.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_len(self.circles.len())?;
+        for v in &self.circles {
+            v.encode_ros1(w)?;
+        }
+        w.write_len(self.points.len())?;
+        for v in &self.points {
+            v.encode_ros1(w)?;
+        }
+        w.write_len(self.texts.len())?;
+        for v in &self.texts {
+            v.encode_ros1(w)?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for KeyValuePair {
+    const TYPE_NAME: &'static str = "foxglove_msgs/KeyValuePair";
+    const DEFINITION: &'static str = include_str!("data/KeyValuePair.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_string(&self.key)?;
+        w.write_string(&self.value)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for LaserScan {
+    const TYPE_NAME: &'static str = "foxglove_msgs/LaserScan";
+    const DEFINITION: &'static str = include_str!("data/LaserScan.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_f64(self.start_angle);
+        w.write_f64(self.end_angle);
+        w.write_len(self.ranges.len())?;
+        for v in &self.ranges {
+            w.write_f64(*v);
+        }
+        w.write_len(self.intensities.len())?;
+        for v in &self.intensities {
+            w.write_f64(*v);
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for LinePrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/LinePrimitive";
+    const DEFINITION: &'static str = include_str!("data/LinePrimitive.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_enum("type", self.r#type)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_f64(self.thickness);
+        w.write_bool(self.scale_invariant);
+        w.write_len(self.points.len())?;
+        for v in &self.points {
+            v.encode_ros1(w)?;
+        }
+        w.write_message(self.color.as_ref())?;
+        w.write_len(self.colors.len())?;
+        for v in &self.colors {
+            v.encode_ros1(w)?;
+        }
+        w.write_len(self.indices.len())?;
+        for v in &self.indices {
+            w.write_u32(*v);
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for LocationFix {
+    const TYPE_NAME: &'static str = "foxglove_msgs/LocationFix";
+    const DEFINITION: &'static str = include_str!("data/LocationFix.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_f64(self.latitude);
+        w.write_f64(self.longitude);
+        w.write_f64(self.altitude);
+        w.check_array_len("position_covariance", self.position_covariance.len(), 9)?;
+        for v in &self.position_covariance {
+            w.write_f64(*v);
+        }
+        w.write_enum("position_covariance_type", self.position_covariance_type)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for Log {
+    const TYPE_NAME: &'static str = "foxglove_msgs/Log";
+    const DEFINITION: &'static str = include_str!("data/Log.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_enum("level", self.level)?;
+        w.write_string(&self.message)?;
+        w.write_string(&self.name)?;
+        w.write_string(&self.file)?;
+        w.write_u32(self.line);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for ModelPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/ModelPrimitive";
+    const DEFINITION: &'static str = include_str!("data/ModelPrimitive.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.scale.as_ref())?;
+        w.write_message(self.color.as_ref())?;
+        w.write_bool(self.override_color);
+        w.write_string(&self.url)?;
+        w.write_string(&self.media_type)?;
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for PackedElementField {
+    const TYPE_NAME: &'static str = "foxglove_msgs/PackedElementField";
+    const DEFINITION: &'static str = include_str!("data/PackedElementField.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_string(&self.name)?;
+        w.write_u32(self.offset);
+        w.write_enum("type", self.r#type)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for Point2 {
+    const TYPE_NAME: &'static str = "foxglove_msgs/Point2";
+    const DEFINITION: &'static str = include_str!("data/Point2.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for PointCloud {
+    const TYPE_NAME: &'static str = "foxglove_msgs/PointCloud";
+    const DEFINITION: &'static str = include_str!("data/PointCloud.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_u32(self.point_stride);
+        w.write_len(self.fields.len())?;
+        for v in &self.fields {
+            v.encode_ros1(w)?;
+        }
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for PointsAnnotation {
+    const TYPE_NAME: &'static str = "foxglove_msgs/PointsAnnotation";
+    const DEFINITION: &'static str = include_str!("data/PointsAnnotation.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_enum("type", self.r#type)?;
+        w.write_len(self.points.len())?;
+        for v in &self.points {
+            v.encode_ros1(w)?;
+        }
+        w.write_message(self.outline_color.as_ref())?;
+        w.write_len(self.outline_colors.len())?;
+        for v in &self.outline_colors {
+            v.encode_ros1(w)?;
+        }
+        w.write_message(self.fill_color.as_ref())?;
+        w.write_f64(self.thickness);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for PoseInFrame {
+    const TYPE_NAME: &'static str = "foxglove_msgs/PoseInFrame";
+    const DEFINITION: &'static str = include_str!("data/PoseInFrame.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for PosesInFrame {
+    const TYPE_NAME: &'static str = "foxglove_msgs/PosesInFrame";
+    const DEFINITION: &'static str = include_str!("data/PosesInFrame.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_len(self.poses.len())?;
+        for v in &self.poses {
+            v.encode_ros1(w)?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for RawImage {
+    const TYPE_NAME: &'static str = "foxglove_msgs/RawImage";
+    const DEFINITION: &'static str = include_str!("data/RawImage.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_u32(self.width);
+        w.write_u32(self.height);
+        w.write_string(&self.encoding)?;
+        w.write_u32(self.step);
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for SceneEntity {
+    const TYPE_NAME: &'static str = "foxglove_msgs/SceneEntity";
+    const DEFINITION: &'static str = include_str!("data/SceneEntity.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_string(&self.id)?;
+        w.write_duration(self.lifetime.as_ref());
+        w.write_bool(self.frame_locked);
+        w.write_len(self.metadata.len())?;
+        for v in &self.metadata {
+            v.encode_ros1(w)?;
+        }
+        w.write_len(self.arrows.len())?;
+        for v in &self.arrows {
+            v.encode_ros1(w)?;
+        }
+        w.write_len(self.cubes.len())?;
+        for v in &self.cubes {
+            v.encode_ros1(w)?;
+        }
+        w.write_len(self.spheres.len())?;
+        for v in &self.spheres {
+            v.encode_ros1(w)?;
+        }
+        w.write_len(self.cylinders.len())?;
+        for v in &self.cylinders {
+            v.encode_ros1(w)?;
+        }
+        w.write_len(self.lines.len())?;
+        for v in &self.lines {
+            v.encode_ros1(w)?;
+        }
+        w.write_len(self.triangles.len())?;
+        for v in &self.triangles {
+            v.encode_ros1(w)?;
+        }
+        w.write_len(self.texts.len())?;
+        for v in &self.texts {
+            v.encode_ros1(w)?;
+        }
+        w.write_len(self.models.len())?;
+        for v in &self.models {
+            v.encode_ros1(w)?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for SceneEntityDeletion {
+    const TYPE_NAME: &'static str = "foxglove_msgs/SceneEntityDeletion";
+    const DEFINITION: &'static str = include_str!("data/SceneEntityDeletion.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_enum("type", self.r#type)?;
+        w.write_string(&self.id)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for SceneUpdate {
+    const TYPE_NAME: &'static str = "foxglove_msgs/SceneUpdate";
+    const DEFINITION: &'static str = include_str!("data/SceneUpdate.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_len(self.deletions.len())?;
+        for v in &self.deletions {
+            v.encode_ros1(w)?;
+        }
+        w.write_len(self.entities.len())?;
+        for v in &self.entities {
+            v.encode_ros1(w)?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for SpherePrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/SpherePrimitive";
+    const DEFINITION: &'static str = include_str!("data/SpherePrimitive.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.size.as_ref())?;
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for TextAnnotation {
+    const TYPE_NAME: &'static str = "foxglove_msgs/TextAnnotation";
+    const DEFINITION: &'static str = include_str!("data/TextAnnotation.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_message(self.position.as_ref())?;
+        w.write_string(&self.text)?;
+        w.write_f64(self.font_size);
+        w.write_message(self.text_color.as_ref())?;
+        w.write_message(self.background_color.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for TextPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/TextPrimitive";
+    const DEFINITION: &'static str = include_str!("data/TextPrimitive.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_bool(self.billboard);
+        w.write_f64(self.font_size);
+        w.write_bool(self.scale_invariant);
+        w.write_message(self.color.as_ref())?;
+        w.write_string(&self.text)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for TriangleListPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/TriangleListPrimitive";
+    const DEFINITION: &'static str = include_str!("data/TriangleListPrimitive.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_len(self.points.len())?;
+        for v in &self.points {
+            v.encode_ros1(w)?;
+        }
+        w.write_message(self.color.as_ref())?;
+        w.write_len(self.colors.len())?;
+        for v in &self.colors {
+            v.encode_ros1(w)?;
+        }
+        w.write_len(self.indices.len())?;
+        for v in &self.indices {
+            w.write_u32(*v);
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for Vector2 {
+    const TYPE_NAME: &'static str = "foxglove_msgs/Vector2";
+    const DEFINITION: &'static str = include_str!("data/Vector2.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for Point3 {
+    const TYPE_NAME: &'static str = "geometry_msgs/Point";
+    const DEFINITION: &'static str = include_str!("data/Point3.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for Pose {
+    const TYPE_NAME: &'static str = "geometry_msgs/Pose";
+    const DEFINITION: &'static str = include_str!("data/Pose.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.position.as_ref())?;
+        w.write_message(self.orientation.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for Quaternion {
+    const TYPE_NAME: &'static str = "geometry_msgs/Quaternion";
+    const DEFINITION: &'static str = include_str!("data/Quaternion.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        w.write_f64(self.w);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl Ros1Encode for Vector3 {
+    const TYPE_NAME: &'static str = "geometry_msgs/Vector3";
+    const DEFINITION: &'static str = include_str!("data/Vector3.msg");
+
+    fn encode_ros1(&self, w: &mut Ros1Writer<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
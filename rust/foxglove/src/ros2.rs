@@ -0,0 +1,270 @@
+//! ROS 2 (CDR) encoding for well-known Foxglove schemas.
+//!
+//! The [schema types](crate::schemas) are encoded as protobuf by default. To log them as
+//! `cdr`-encoded messages with a `ros2msg` schema instead, for use with ROS 2 tooling, wrap the
+//! message type in [`Cdr`]. The choice is made per channel, so protobuf and CDR channels can be
+//! used side by side.
+//!
+//! ```no_run
+//! use foxglove::ros2::Cdr;
+//! use foxglove::schemas::{Log, log::Level};
+//! use foxglove::TypedChannel;
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! // Logged with the schema "foxglove_msgs/msg/Log".
+//! let channel = TypedChannel::<Cdr<Log>>::new("/rosout")?;
+//! channel.log(&Cdr(Log {
+//!     level: Level::Info.into(),
+//!     message: "Hello, ROS 2!".to_string(),
+//!     ..Default::default()
+//! }));
+//! # Ok(()) }
+//! ```
+
+use crate::validate::ValidationError;
+use bytes::BufMut;
+
+#[rustfmt::skip]
+mod impls;
+
+/// The CDR encapsulation header for little-endian, plain CDR data.
+const CDR_LE_HEADER: [u8; 4] = [0x00, 0x01, 0x00, 0x00];
+
+pub use crate::ros::EncodeError;
+
+/// A message that can be encoded as CDR, with a corresponding ROS 2 message definition.
+///
+/// This trait is implemented for the well-known [schema types](crate::schemas). Wrap a message in
+/// [`Cdr`] to log it.
+pub trait CdrEncode {
+    /// The fully-qualified ROS 2 type name, such as `foxglove_msgs/msg/Log`.
+    const TYPE_NAME: &'static str;
+
+    /// The `ros2msg` definition, including the definitions of all of its dependencies.
+    const DEFINITION: &'static str;
+
+    /// Serializes the message using the provided writer.
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError>;
+
+    /// Optional. Checks the invariants of the message; see
+    /// [`Encode::validate`](crate::Encode::validate).
+    fn validate(&self) -> Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+crate::ros::ros_wrapper! {
+    /// A wrapper for logging a message with ROS 2 (CDR) encoding.
+    ///
+    /// Use `TypedChannel<Cdr<T>>` to create a channel with message encoding `cdr`, and schema
+    /// encoding `ros2msg`.
+    pub struct Cdr;
+    encode = CdrEncode::encode_cdr;
+    writer = CdrWriter;
+    message_encoding = "cdr";
+    schema_encoding = "ros2msg";
+}
+
+/// A writer for little-endian, plain CDR data, as used by ROS 2.
+///
+/// The writer emits the encapsulation header when it is created, and aligns primitive values to
+/// their size relative to the end of the header.
+pub struct CdrWriter<B> {
+    buf: B,
+    offset: usize,
+}
+
+impl<B: BufMut> CdrWriter<B> {
+    /// Creates a new writer, and writes the encapsulation header to the buffer.
+    pub fn new(mut buf: B) -> Self {
+        buf.put_slice(&CDR_LE_HEADER);
+        Self { buf, offset: 0 }
+    }
+
+    crate::ros::writer_methods!(CdrEncode, encode_cdr);
+
+    /// Pads the buffer with zeros to the specified alignment.
+    fn align(&mut self, alignment: usize) {
+        let padding = (alignment - self.offset % alignment) % alignment;
+        self.buf.put_bytes(0, padding);
+        self.offset += padding;
+    }
+
+    /// Writes a `uint8`.
+    pub fn write_u8(&mut self, value: u8) {
+        self.buf.put_u8(value);
+        self.offset += 1;
+    }
+
+    /// Writes an `int32`.
+    pub fn write_i32(&mut self, value: i32) {
+        self.align(4);
+        self.buf.put_i32_le(value);
+        self.offset += 4;
+    }
+
+    /// Writes a `uint32`.
+    pub fn write_u32(&mut self, value: u32) {
+        self.align(4);
+        self.buf.put_u32_le(value);
+        self.offset += 4;
+    }
+
+    /// Writes a `float64`.
+    pub fn write_f64(&mut self, value: f64) {
+        self.align(8);
+        self.buf.put_f64_le(value);
+        self.offset += 8;
+    }
+
+    /// Writes a `uint8[]`.
+    pub fn write_bytes(&mut self, data: &[u8]) -> Result<(), EncodeError> {
+        self.write_len(data.len())?;
+        self.buf.put_slice(data);
+        self.offset += data.len();
+        Ok(())
+    }
+
+    /// Writes a `string`, which is null-terminated.
+    pub fn write_string(&mut self, value: &str) -> Result<(), EncodeError> {
+        self.write_len(value.len() + 1)?;
+        self.buf.put_slice(value.as_bytes());
+        self.buf.put_u8(0);
+        self.offset += value.len() + 1;
+        Ok(())
+    }
+
+    /// Writes the seconds of a `builtin_interfaces/Time`, which are signed.
+    fn write_sec(&mut self, field: &'static str, sec: u32) -> Result<(), EncodeError> {
+        let sec = i32::try_from(sec).map_err(|_| EncodeError::OutOfRange(field))?;
+        self.write_i32(sec);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::{
+        packed_element_field::NumericType, CameraCalibration, Color, Log, PackedElementField, Pose,
+        SceneUpdate, Timestamp,
+    };
+    use crate::Encode;
+
+    fn encode<T: CdrEncode>(msg: T) -> Vec<u8> {
+        let mut buf = vec![];
+        Cdr(msg).encode(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = Cdr::<SceneUpdate>::get_schema().unwrap();
+        assert_eq!(schema.name, "foxglove_msgs/msg/SceneUpdate");
+        assert_eq!(schema.encoding, "ros2msg");
+        let definition = std::str::from_utf8(&schema.data).unwrap();
+        assert!(definition.starts_with("# foxglove_msgs/msg/SceneUpdate\n"));
+        for dep in [
+            "MSG: foxglove_msgs/SceneEntity\n",
+            "MSG: foxglove_msgs/Color\n",
+            "MSG: geometry_msgs/Pose\n",
+            "MSG: geometry_msgs/Point\n",
+        ] {
+            assert_eq!(definition.matches(dep).count(), 1, "{dep}");
+        }
+        assert_eq!(Cdr::<SceneUpdate>::get_message_encoding(), "cdr");
+
+        let schema = Cdr::<Pose>::get_schema().unwrap();
+        assert_eq!(schema.name, "geometry_msgs/msg/Pose");
+        assert!(std::str::from_utf8(&schema.data)
+            .unwrap()
+            .starts_with("# geometry_msgs/msg/Pose\n"));
+    }
+
+    #[test]
+    fn test_encode_alignment() {
+        let buf = encode(PackedElementField {
+            name: "x".to_string(),
+            offset: 4,
+            r#type: NumericType::Float32.into(),
+        });
+        #[rustfmt::skip]
+        assert_eq!(buf, [
+            0, 1, 0, 0, // header
+            2, 0, 0, 0, b'x', 0, // name
+            0, 0, // padding
+            4, 0, 0, 0, // offset
+            7, // type
+        ]);
+
+        let buf = encode(Color {
+            r: 1.0,
+            g: 0.0,
+            b: 0.0,
+            a: 1.0,
+        });
+        assert_eq!(buf.len(), 4 + 4 * 8);
+        assert_eq!(&buf[4..12], 1.0f64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_encode_time_and_absent_fields() {
+        let buf = encode(Log {
+            timestamp: Some(Timestamp::new(1, 2)),
+            level: 2,
+            ..Default::default()
+        });
+        #[rustfmt::skip]
+        assert_eq!(buf, [
+            0, 1, 0, 0, // header
+            1, 0, 0, 0, 2, 0, 0, 0, // timestamp
+            2, // level
+            0, 0, 0, // padding
+            1, 0, 0, 0, 0, // message
+            0, 0, 0, // padding
+            1, 0, 0, 0, 0, // name
+            0, 0, 0, // padding
+            1, 0, 0, 0, 0, // file
+            0, 0, 0, // padding
+            0, 0, 0, 0, // line
+        ]);
+
+        // An absent timestamp is encoded as zero.
+        let buf = encode(Log::default());
+        assert_eq!(&buf[4..12], [0; 8]);
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        let err = Cdr(CameraCalibration {
+            k: vec![0.0; 3],
+            ..Default::default()
+        })
+        .encode(&mut vec![])
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            EncodeError::ArrayLength {
+                field: "k",
+                expected: 9,
+                actual: 3
+            }
+        ));
+
+        let err = Cdr(Log {
+            level: 256,
+            ..Default::default()
+        })
+        .encode(&mut vec![])
+        .unwrap_err();
+        assert!(matches!(err, EncodeError::OutOfRange("level")));
+
+        let err = Cdr(Log {
+            timestamp: Some(Timestamp::new(u32::MAX, 0)),
+            ..Default::default()
+        })
+        .encode(&mut vec![])
+        .unwrap_err();
+        assert!(matches!(err, EncodeError::OutOfRange("timestamp")));
+    }
+}
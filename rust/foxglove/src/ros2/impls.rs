@@ -0,0 +1,779 @@
+// This file is @generated by foxglove-proto-gen
+use super::{CdrEncode, CdrWriter, EncodeError};
+use crate::schemas::*;
+use crate::validate::{validate, ValidationError};
+use bytes::BufMut;
+
+impl CdrEncode for ArrowPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/ArrowPrimitive";
+    const DEFINITION: &'static str = include_str!("data/ArrowPrimitive.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_f64(self.shaft_length);
+        w.write_f64(self.shaft_diameter);
+        w.write_f64(self.head_length);
+        w.write_f64(self.head_diameter);
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for CameraCalibration {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/CameraCalibration";
+    const DEFINITION: &'static str = include_str!("data/CameraCalibration.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_u32(self.width);
+        w.write_u32(self.height);
+        w.write_string(&self.distortion_model)?;
+        w.write_len(self.d.len())?;
+        for v in &self.d {
+            w.write_f64(*v);
+        }
+        w.check_array_len("k", self.k.len(), 9)?;
+        for v in &self.k {
+            w.write_f64(*v);
+        }
+        w.check_array_len("r", self.r.len(), 9)?;
+        for v in &self.r {
+            w.write_f64(*v);
+        }
+        w.check_array_len("p", self.p.len(), 12)?;
+        for v in &self.p {
+            w.write_f64(*v);
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for CircleAnnotation {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/CircleAnnotation";
+    const DEFINITION: &'static str = include_str!("data/CircleAnnotation.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_message(self.position.as_ref())?;
+        w.write_f64(self.diameter);
+        w.write_f64(self.thickness);
+        w.write_message(self.fill_color.as_ref())?;
+        w.write_message(self.outline_color.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for Color {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/Color";
+    const DEFINITION: &'static str = include_str!("data/Color.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_f64(self.r);
+        w.write_f64(self.g);
+        w.write_f64(self.b);
+        w.write_f64(self.a);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for CompressedImage {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/CompressedImage";
+    const DEFINITION: &'static str = include_str!("data/CompressedImage.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_bytes(&self.data)?;
+        w.write_string(&self.format)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for CompressedVideo {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/CompressedVideo";
+    const DEFINITION: &'static str = include_str!("data/CompressedVideo.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_bytes(&self.data)?;
+        w.write_string(&self.format)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for CubePrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/CubePrimitive";
+    const DEFINITION: &'static str = include_str!("data/CubePrimitive.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.size.as_ref())?;
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for CylinderPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/CylinderPrimitive";
+    const DEFINITION: &'static str = include_str!("data/CylinderPrimitive.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.size.as_ref())?;
+        w.write_f64(self.bottom_scale);
+        w.write_f64(self.top_scale);
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for FrameTransform {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/FrameTransform";
+    const DEFINITION: &'static str = include_str!("data/FrameTransform.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.parent_frame_id)?;
+        w.write_string(&self.child_frame_id)?;
+        w.write_message(self.translation.as_ref())?;
+        w.write_message(self.rotation.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for FrameTransforms {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/FrameTransforms";
+    const DEFINITION: &'static str = include_str!("data/FrameTransforms.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_len(self.transforms.len())?;
+        for v in &self.transforms {
+            v.encode_cdr(w)?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for GeoJson {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/GeoJSON";
+    const DEFINITION: &'static str = include_str!("data/GeoJson.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_string(&self.geojson)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for Grid {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/Grid";
+    const DEFINITION: &'static str = include_str!("data/Grid.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_u32(self.column_count);
+        w.write_message(self.cell_size.as_ref())?;
+        w.write_u32(self.row_stride);
+        w.write_u32(self.cell_stride);
+        w.write_len(self.fields.len())?;
+        for v in &self.fields {
+            v.encode_cdr(w)?;
+        }
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for ImageAnnotations {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/ImageAnnotations";
+    const DEFINITION: &'static str = include_str!("data/ImageAnnotations.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_len(self.circles.len())?;
+        for v in &self.circles {
+            v.encode_cdr(w)?;
+        }
+        w.write_len(self.points.len())?;
+        for v in &self.points {
+            v.encode_cdr(w)?;
+        }
+        w.write_len(self.texts.len())?;
+        for v in &self.texts {
+            v.encode_cdr(w)?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for KeyValuePair {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/KeyValuePair";
+    const DEFINITION: &'static str = include_str!("data/KeyValuePair.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_string(&self.key)?;
+        w.write_string(&self.value)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for LaserScan {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/LaserScan";
+    const DEFINITION: &'static str = include_str!("data/LaserScan.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_f64(self.start_angle);
+        w.write_f64(self.end_angle);
+        w.write_len(self.ranges.len())?;
+        for v in &self.ranges {
+            w.write_f64(*v);
+        }
+        w.write_len(self.intensities.len())?;
+        for v in &self.intensities {
+            w.write_f64(*v);
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for LinePrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/LinePrimitive";
+    const DEFINITION: &'static str = include_str!("data/LinePrimitive.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_enum("type", self.r#type)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_f64(self.thickness);
+        w.write_bool(self.scale_invariant);
+        w.write_len(self.points.len())?;
+        for v in &self.points {
+            v.encode_cdr(w)?;
+        }
+        w.write_message(self.color.as_ref())?;
+        w.write_len(self.colors.len())?;
+        for v in &self.colors {
+            v.encode_cdr(w)?;
+        }
+        w.write_len(self.indices.len())?;
+        for v in &self.indices {
+            w.write_u32(*v);
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for LocationFix {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/LocationFix";
+    const DEFINITION: &'static str = include_str!("data/LocationFix.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_f64(self.latitude);
+        w.write_f64(self.longitude);
+        w.write_f64(self.altitude);
+        w.check_array_len("position_covariance", self.position_covariance.len(), 9)?;
+        for v in &self.position_covariance {
+            w.write_f64(*v);
+        }
+        w.write_enum("position_covariance_type", self.position_covariance_type)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for Log {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/Log";
+    const DEFINITION: &'static str = include_str!("data/Log.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_enum("level", self.level)?;
+        w.write_string(&self.message)?;
+        w.write_string(&self.name)?;
+        w.write_string(&self.file)?;
+        w.write_u32(self.line);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for ModelPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/ModelPrimitive";
+    const DEFINITION: &'static str = include_str!("data/ModelPrimitive.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.scale.as_ref())?;
+        w.write_message(self.color.as_ref())?;
+        w.write_bool(self.override_color);
+        w.write_string(&self.url)?;
+        w.write_string(&self.media_type)?;
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for PackedElementField {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/PackedElementField";
+    const DEFINITION: &'static str = include_str!("data/PackedElementField.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_string(&self.name)?;
+        w.write_u32(self.offset);
+        w.write_enum("type", self.r#type)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for Point2 {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/Point2";
+    const DEFINITION: &'static str = include_str!("data/Point2.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for PointCloud {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/PointCloud";
+    const DEFINITION: &'static str = include_str!("data/PointCloud.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        w.write_u32(self.point_stride);
+        w.write_len(self.fields.len())?;
+        for v in &self.fields {
+            v.encode_cdr(w)?;
+        }
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for PointsAnnotation {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/PointsAnnotation";
+    const DEFINITION: &'static str = include_str!("data/PointsAnnotation.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_enum("type", self.r#type)?;
+        w.write_len(self.points.len())?;
+        for v in &self.points {
+            v.encode_cdr(w)?;
+        }
+        w.write_message(self.outline_color.as_ref())?;
+        w.write_len(self.outline_colors.len())?;
+        for v in &self.outline_colors {
+            v.encode_cdr(w)?;
+        }
+        w.write_message(self.fill_color.as_ref())?;
+        w.write_f64(self.thickness);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for PoseInFrame {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/PoseInFrame";
+    const DEFINITION: &'static str = include_str!("data/PoseInFrame.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_message(self.pose.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for PosesInFrame {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/PosesInFrame";
+    const DEFINITION: &'static str = include_str!("data/PosesInFrame.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_len(self.poses.len())?;
+        for v in &self.poses {
+            v.encode_cdr(w)?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for RawImage {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/RawImage";
+    const DEFINITION: &'static str = include_str!("data/RawImage.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_u32(self.width);
+        w.write_u32(self.height);
+        w.write_string(&self.encoding)?;
+        w.write_u32(self.step);
+        w.write_bytes(&self.data)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for SceneEntity {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/SceneEntity";
+    const DEFINITION: &'static str = include_str!("data/SceneEntity.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_string(&self.frame_id)?;
+        w.write_string(&self.id)?;
+        w.write_duration(self.lifetime.as_ref());
+        w.write_bool(self.frame_locked);
+        w.write_len(self.metadata.len())?;
+        for v in &self.metadata {
+            v.encode_cdr(w)?;
+        }
+        w.write_len(self.arrows.len())?;
+        for v in &self.arrows {
+            v.encode_cdr(w)?;
+        }
+        w.write_len(self.cubes.len())?;
+        for v in &self.cubes {
+            v.encode_cdr(w)?;
+        }
+        w.write_len(self.spheres.len())?;
+        for v in &self.spheres {
+            v.encode_cdr(w)?;
+        }
+        w.write_len(self.cylinders.len())?;
+        for v in &self.cylinders {
+            v.encode_cdr(w)?;
+        }
+        w.write_len(self.lines.len())?;
+        for v in &self.lines {
+            v.encode_cdr(w)?;
+        }
+        w.write_len(self.triangles.len())?;
+        for v in &self.triangles {
+            v.encode_cdr(w)?;
+        }
+        w.write_len(self.texts.len())?;
+        for v in &self.texts {
+            v.encode_cdr(w)?;
+        }
+        w.write_len(self.models.len())?;
+        for v in &self.models {
+            v.encode_cdr(w)?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for SceneEntityDeletion {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/SceneEntityDeletion";
+    const DEFINITION: &'static str = include_str!("data/SceneEntityDeletion.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_enum("type", self.r#type)?;
+        w.write_string(&self.id)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for SceneUpdate {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/SceneUpdate";
+    const DEFINITION: &'static str = include_str!("data/SceneUpdate.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_len(self.deletions.len())?;
+        for v in &self.deletions {
+            v.encode_cdr(w)?;
+        }
+        w.write_len(self.entities.len())?;
+        for v in &self.entities {
+            v.encode_cdr(w)?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for SpherePrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/SpherePrimitive";
+    const DEFINITION: &'static str = include_str!("data/SpherePrimitive.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_message(self.size.as_ref())?;
+        w.write_message(self.color.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for TextAnnotation {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/TextAnnotation";
+    const DEFINITION: &'static str = include_str!("data/TextAnnotation.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_time("timestamp", self.timestamp.as_ref())?;
+        w.write_message(self.position.as_ref())?;
+        w.write_string(&self.text)?;
+        w.write_f64(self.font_size);
+        w.write_message(self.text_color.as_ref())?;
+        w.write_message(self.background_color.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for TextPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/TextPrimitive";
+    const DEFINITION: &'static str = include_str!("data/TextPrimitive.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_bool(self.billboard);
+        w.write_f64(self.font_size);
+        w.write_bool(self.scale_invariant);
+        w.write_message(self.color.as_ref())?;
+        w.write_string(&self.text)?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for TriangleListPrimitive {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/TriangleListPrimitive";
+    const DEFINITION: &'static str = include_str!("data/TriangleListPrimitive.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.pose.as_ref())?;
+        w.write_len(self.points.len())?;
+        for v in &self.points {
+            v.encode_cdr(w)?;
+        }
+        w.write_message(self.color.as_ref())?;
+        w.write_len(self.colors.len())?;
+        for v in &self.colors {
+            v.encode_cdr(w)?;
+        }
+        w.write_len(self.indices.len())?;
+        for v in &self.indices {
+            w.write_u32(*v);
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for Vector2 {
+    const TYPE_NAME: &'static str = "foxglove_msgs/msg/Vector2";
+    const DEFINITION: &'static str = include_str!("data/Vector2.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for Point3 {
+    const TYPE_NAME: &'static str = "geometry_msgs/msg/Point";
+    const DEFINITION: &'static str = include_str!("data/Point3.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for Pose {
+    const TYPE_NAME: &'static str = "geometry_msgs/msg/Pose";
+    const DEFINITION: &'static str = include_str!("data/Pose.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_message(self.position.as_ref())?;
+        w.write_message(self.orientation.as_ref())?;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for Quaternion {
+    const TYPE_NAME: &'static str = "geometry_msgs/msg/Quaternion";
+    const DEFINITION: &'static str = include_str!("data/Quaternion.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        w.write_f64(self.w);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
+
+impl CdrEncode for Vector3 {
+    const TYPE_NAME: &'static str = "geometry_msgs/msg/Vector3";
+    const DEFINITION: &'static str = include_str!("data/Vector3.msg");
+
+    fn encode_cdr(&self, w: &mut CdrWriter<impl BufMut>) -> Result<(), EncodeError> {
+        w.write_f64(self.x);
+        w.write_f64(self.y);
+        w.write_f64(self.z);
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate(self)
+    }
+}
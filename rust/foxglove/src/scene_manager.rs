@@ -0,0 +1,426 @@
+//! Managing the entities of a scene across [`SceneUpdate`] messages.
+//!
+//! The 3D panel keeps each [`SceneEntity`] until it is replaced, deleted, or its lifetime expires.
+//! Publishing a scene by hand means tracking which entities were published before, so that the
+//! ones which disappear can be deleted. A [`SceneManager`] does this tracking.
+//!
+//! Entities are grouped in namespaces. Each namespace can be replaced as a whole with
+//! [`SceneManager::set_entities`], which deletes the entities that are no longer present, or
+//! updated one entity at a time. Changes are batched, and published as a single [`SceneUpdate`]
+//! by [`SceneManager::flush`], typically once per tick of the application's main loop.
+//!
+//! ```no_run
+//! use foxglove::scene_manager::SceneManager;
+//! use foxglove::schemas::{SceneEntity, SceneUpdate};
+//! use foxglove::TypedChannel;
+//!
+//! # fn func(detections: &[(String, SceneEntity)]) -> Result<(), foxglove::FoxgloveError> {
+//! let mut scene = SceneManager::new(TypedChannel::<SceneUpdate>::new("/scene")?);
+//! loop {
+//!     // Entities from the previous tick which are not detected in this tick are deleted.
+//!     scene.set_entities("detections", detections.iter().cloned().map(|(_, e)| e));
+//!     scene.flush();
+//!     # break;
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! The ID of an entity is prefixed with its namespace, as `namespace/id`, unless the namespace is
+//! empty.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::time::Duration;
+
+use crate::convert::SaturatingInto;
+use crate::schemas::scene_entity_deletion::Type;
+use crate::schemas::{self, SceneEntity, SceneEntityDeletion, SceneUpdate, Timestamp};
+use crate::{nanoseconds_since_epoch, TypedChannel};
+
+/// An entity which has been added to the scene, and not yet deleted or expired.
+#[derive(Debug)]
+struct LiveEntity {
+    namespace: String,
+    /// The time at which the entity expires, in nanoseconds since epoch.
+    expires: Option<u64>,
+}
+
+/// Tracks the entities of a scene, and publishes changes to it. See the
+/// [module-level documentation](self) for details.
+pub struct SceneManager {
+    channel: TypedChannel<SceneUpdate>,
+    default_lifetime: Option<schemas::Duration>,
+    /// Entities by ID, including entities which have not yet been published.
+    live: BTreeMap<String, LiveEntity>,
+    /// Entities to publish in the next update, by ID.
+    pending: BTreeMap<String, SceneEntity>,
+    /// IDs of entities to delete in the next update.
+    deletions: BTreeSet<String>,
+    /// Whether the next update deletes all entities.
+    delete_all: bool,
+}
+
+impl SceneManager {
+    /// Creates a manager which publishes on the given channel.
+    ///
+    /// The manager assumes that it is the only publisher on the channel.
+    pub fn new(channel: TypedChannel<SceneUpdate>) -> Self {
+        Self {
+            channel,
+            default_lifetime: None,
+            live: BTreeMap::new(),
+            pending: BTreeMap::new(),
+            deletions: BTreeSet::new(),
+            delete_all: false,
+        }
+    }
+
+    /// Sets the lifetime of entities which do not specify one.
+    ///
+    /// By default, entities without a lifetime remain until they are replaced or deleted.
+    #[must_use]
+    pub fn default_lifetime(mut self, lifetime: Duration) -> Self {
+        self.default_lifetime = Some(lifetime.saturating_into());
+        self
+    }
+
+    /// Returns the IDs of the entities in a namespace, without the namespace prefix.
+    ///
+    /// Entities whose lifetime has expired are removed when the scene is
+    /// [flushed](SceneManager::flush).
+    pub fn entity_ids(&self, namespace: &str) -> Vec<&str> {
+        self.live
+            .iter()
+            .filter(|(_, entity)| entity.namespace == namespace)
+            .map(|(id, _)| &id[prefix_len(namespace)..])
+            .collect()
+    }
+
+    /// Adds an entity to a namespace, replacing any entity with the same ID.
+    pub fn add_entity(&mut self, namespace: &str, mut entity: SceneEntity) {
+        let id = scoped_id(namespace, &entity.id);
+        if entity
+            .lifetime
+            .is_none_or(|l| l.sec() == 0 && l.nsec() == 0)
+        {
+            entity.lifetime = self.default_lifetime;
+        }
+        let expires = entity.lifetime.and_then(|lifetime| {
+            let nanos = i64::from(lifetime.sec()) * 1_000_000_000 + i64::from(lifetime.nsec());
+            u64::try_from(nanos)
+                .ok()
+                .filter(|nanos| *nanos > 0)
+                .map(|nanos| nanoseconds_since_epoch().saturating_add(nanos))
+        });
+        entity.id.clone_from(&id);
+        self.deletions.remove(&id);
+        self.live.insert(
+            id.clone(),
+            LiveEntity {
+                namespace: namespace.to_string(),
+                expires,
+            },
+        );
+        self.pending.insert(id, entity);
+    }
+
+    /// Deletes an entity from a namespace, if it exists.
+    pub fn remove_entity(&mut self, namespace: &str, id: &str) {
+        self.remove(scoped_id(namespace, id));
+    }
+
+    /// Replaces the entities in a namespace.
+    ///
+    /// Entities in the namespace which are not in `entities` are deleted.
+    pub fn set_entities(
+        &mut self,
+        namespace: &str,
+        entities: impl IntoIterator<Item = SceneEntity>,
+    ) {
+        let mut ids = HashSet::new();
+        for entity in entities {
+            ids.insert(scoped_id(namespace, &entity.id));
+            self.add_entity(namespace, entity);
+        }
+        let stale: Vec<String> = self
+            .live
+            .iter()
+            .filter(|(id, entity)| entity.namespace == namespace && !ids.contains(*id))
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in stale {
+            self.remove(id);
+        }
+    }
+
+    /// Deletes all entities in a namespace.
+    pub fn clear_namespace(&mut self, namespace: &str) {
+        self.set_entities(namespace, []);
+    }
+
+    /// Deletes all entities in the scene, including any published on the channel by other means.
+    pub fn clear(&mut self) {
+        self.live.clear();
+        self.pending.clear();
+        self.deletions.clear();
+        self.delete_all = true;
+    }
+
+    /// Publishes the changes since the previous flush as a single [`SceneUpdate`].
+    ///
+    /// Nothing is published if there are no changes. Deletions apply to entities with timestamps
+    /// earlier than the current time.
+    pub fn flush(&mut self) {
+        let now = nanoseconds_since_epoch();
+        self.live
+            .retain(|_, entity| entity.expires.is_none_or(|expires| expires > now));
+
+        let timestamp = Some(Timestamp::new(
+            u32::try_from(now / 1_000_000_000).unwrap_or(u32::MAX),
+            (now % 1_000_000_000) as u32,
+        ));
+        let deletion = |r#type: Type, id: String| SceneEntityDeletion {
+            timestamp,
+            r#type: r#type.into(),
+            id,
+        };
+        let mut deletions = Vec::new();
+        if std::mem::take(&mut self.delete_all) {
+            deletions.push(deletion(Type::All, String::new()));
+        }
+        deletions.extend(
+            std::mem::take(&mut self.deletions)
+                .into_iter()
+                .map(|id| deletion(Type::MatchingId, id)),
+        );
+        let entities: Vec<SceneEntity> = std::mem::take(&mut self.pending).into_values().collect();
+        if deletions.is_empty() && entities.is_empty() {
+            return;
+        }
+        self.channel.log(&SceneUpdate {
+            deletions,
+            entities,
+        });
+    }
+
+    fn remove(&mut self, id: String) {
+        self.pending.remove(&id);
+        if self.live.remove(&id).is_some() {
+            self.deletions.insert(id);
+        }
+    }
+}
+
+/// Returns the length of the prefix of IDs in a namespace.
+fn prefix_len(namespace: &str) -> usize {
+    if namespace.is_empty() {
+        0
+    } else {
+        namespace.len() + 1
+    }
+}
+
+/// Returns the ID of an entity in a namespace.
+fn scoped_id(namespace: &str, id: &str) -> String {
+    if namespace.is_empty() {
+        id.to_string()
+    } else {
+        format!("{namespace}/{id}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::*;
+    use crate::testutil::RecordingSink;
+    use crate::{ChannelBuilder, LogContext};
+    use std::sync::Arc;
+
+    struct Fixture {
+        /// Keeps the sink attached to the channel.
+        ctx: LogContext,
+        sink: Arc<RecordingSink>,
+        scene: SceneManager,
+    }
+
+    fn fixture() -> Fixture {
+        let ctx = LogContext::new();
+        let sink = Arc::new(RecordingSink::new());
+        ctx.add_sink(sink.clone());
+        let channel = ChannelBuilder::new("/scene")
+            .with_context(&ctx)
+            .build_typed::<SceneUpdate>()
+            .unwrap();
+        Fixture {
+            ctx,
+            sink,
+            scene: SceneManager::new(channel),
+        }
+    }
+
+    fn entity(id: &str) -> SceneEntity {
+        SceneEntity {
+            id: id.to_string(),
+            frame_id: "world".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the logged updates, with only the IDs of the entities, and the types and IDs of
+    /// the deletions.
+    ///
+    /// Schema types with timestamps cannot be decoded, so the logged messages are decoded into a
+    /// subset of their fields.
+    fn take_updates(sink: &RecordingSink) -> Vec<SceneUpdate> {
+        std::mem::take(&mut *sink.recorded.lock())
+            .into_iter()
+            .map(|call| {
+                let update = SceneUpdateIds::decode(call.msg.as_slice()).unwrap();
+                SceneUpdate {
+                    deletions: update
+                        .deletions
+                        .into_iter()
+                        .map(|d| SceneEntityDeletion {
+                            timestamp: None,
+                            r#type: d.r#type,
+                            id: d.id,
+                        })
+                        .collect(),
+                    entities: update.entities.into_iter().map(|e| entity(&e.id)).collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// The subset of fields of a `SceneUpdate` that tests inspect.
+    #[derive(Clone, PartialEq, Message)]
+    struct SceneUpdateIds {
+        #[prost(message, repeated, tag = "1")]
+        deletions: Vec<DeletionIds>,
+        #[prost(message, repeated, tag = "2")]
+        entities: Vec<EntityIds>,
+    }
+
+    #[derive(Clone, PartialEq, Message)]
+    struct DeletionIds {
+        #[prost(int32, tag = "2")]
+        r#type: i32,
+        #[prost(string, tag = "3")]
+        id: String,
+    }
+
+    #[derive(Clone, PartialEq, Message)]
+    struct EntityIds {
+        #[prost(string, tag = "3")]
+        id: String,
+    }
+
+    fn deletion(r#type: Type, id: &str) -> SceneEntityDeletion {
+        SceneEntityDeletion {
+            timestamp: None,
+            r#type: r#type.into(),
+            id: id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_set_entities_deletes_stale() {
+        let Fixture {
+            ctx: _ctx,
+            sink,
+            mut scene,
+        } = fixture();
+        scene.set_entities("boxes", [entity("a"), entity("b")]);
+        scene.add_entity("", entity("ground"));
+        scene.flush();
+        scene.set_entities("boxes", [entity("b"), entity("c")]);
+        scene.flush();
+        // Nothing changed, so nothing is published.
+        scene.flush();
+
+        assert_eq!(
+            take_updates(&sink),
+            vec![
+                SceneUpdate {
+                    deletions: vec![],
+                    entities: vec![entity("boxes/a"), entity("boxes/b"), entity("ground")],
+                },
+                SceneUpdate {
+                    deletions: vec![deletion(Type::MatchingId, "boxes/a")],
+                    entities: vec![entity("boxes/b"), entity("boxes/c")],
+                },
+            ]
+        );
+        assert_eq!(scene.entity_ids("boxes"), ["b", "c"]);
+        assert_eq!(scene.entity_ids(""), ["ground"]);
+    }
+
+    #[test]
+    fn test_batching_and_clearing() {
+        let Fixture {
+            ctx: _ctx,
+            sink,
+            mut scene,
+        } = fixture();
+        scene.set_entities("boxes", [entity("a"), entity("b")]);
+        scene.set_entities("arrows", [entity("a")]);
+        scene.flush();
+
+        // Changes between flushes are merged.
+        scene.remove_entity("boxes", "a");
+        scene.add_entity("boxes", entity("a"));
+        scene.remove_entity("boxes", "b");
+        scene.clear_namespace("arrows");
+        scene.flush();
+
+        scene.add_entity("boxes", entity("c"));
+        scene.clear();
+        scene.add_entity("boxes", entity("d"));
+        scene.flush();
+
+        let updates = take_updates(&sink);
+        assert_eq!(updates.len(), 3);
+        assert_eq!(
+            updates[1],
+            SceneUpdate {
+                deletions: vec![
+                    deletion(Type::MatchingId, "arrows/a"),
+                    deletion(Type::MatchingId, "boxes/b"),
+                ],
+                entities: vec![entity("boxes/a")],
+            }
+        );
+        assert_eq!(
+            updates[2],
+            SceneUpdate {
+                deletions: vec![deletion(Type::All, "")],
+                entities: vec![entity("boxes/d")],
+            }
+        );
+    }
+
+    #[test]
+    fn test_lifetimes() {
+        let mut scene = fixture().scene.default_lifetime(Duration::from_millis(1));
+        scene.add_entity("boxes", entity("a"));
+        scene.add_entity(
+            "boxes",
+            SceneEntity {
+                lifetime: Some(schemas::Duration::new(60, 0)),
+                ..entity("b")
+            },
+        );
+        assert_eq!(
+            scene.pending["boxes/a"].lifetime,
+            Some(schemas::Duration::new(0, 1_000_000))
+        );
+        std::thread::sleep(Duration::from_millis(5));
+        scene.flush();
+
+        // The expired entity is forgotten, so replacing the namespace does not delete it.
+        assert_eq!(scene.entity_ids("boxes"), ["b"]);
+        scene.clear_namespace("boxes");
+        assert_eq!(scene.deletions, BTreeSet::from(["boxes/b".to_string()]));
+    }
+}
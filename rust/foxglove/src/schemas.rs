@@ -5,7 +5,40 @@
 //!
 //! They're encoded as compact, binary protobuf messages,
 //! and can be conveniently used with the [`TypedChannel`](crate::TypedChannel) API.
+//!
+//! To log them with ROS 1 or ROS 2 (CDR) encoding instead, see [`ros1::Ros1`](crate::ros1::Ros1)
+//! and [`ros2::Cdr`](crate::ros2::Cdr). With the `flatbuffers` feature, they can be logged with
+//! FlatBuffers encoding with `foxglove::flatbuffer::FlatBuffer`.
+//!
+//! With the `serde` feature, these types implement `Serialize` and `Deserialize`, using a
+//! representation that matches the Foxglove JSON schemas. They can be logged as JSON with
+//! `foxglove::json::Json`.
+//!
+//! Scene primitives and image annotations have `with_*` setters for each field, so that they can
+//! be built from their defaults without nested struct literals. Setters of message fields accept
+//! anything that converts into the field's type, such as arrays for vectors and points, and
+//! [`Color`] has constructors for hex strings and named colors.
+//!
+//! ```
+//! use foxglove::schemas::{Color, CubePrimitive, Pose, SceneEntity, TextPrimitive};
+//!
+//! let entity = SceneEntity::default()
+//!     .with_frame_id("base_link")
+//!     .with_id("obstacle")
+//!     .with_metadata([("source", "lidar")])
+//!     .with_cubes([CubePrimitive::default()
+//!         .with_pose(Pose::identity())
+//!         .with_size([1.0, 1.0, 2.0])
+//!         .with_color(Color::RED.with_alpha(0.5))])
+//!     .with_texts([TextPrimitive::default()
+//!         .with_text("obstacle")
+//!         .with_font_size(12.0)
+//!         .with_color("#ffffff".parse::<Color>().unwrap())]);
+//! assert_eq!(entity.cubes[0].size.unwrap().z, 2.0);
+//! ```
 
+#[rustfmt::skip]
+mod builders;
 pub(crate) mod descriptors;
 #[allow(missing_docs)]
 #[rustfmt::skip]
@@ -14,4 +47,7 @@ mod foxglove;
 mod impls;
 
 pub use self::foxglove::*;
+pub use crate::schemas_ext::ParseColorError;
+#[cfg(feature = "geo")]
+pub use crate::schemas_ext::ParseGeoJsonError;
 pub use crate::schemas_wkt::{Duration, Timestamp};
@@ -0,0 +1,701 @@
+// This file is @generated by foxglove-proto-gen
+use crate::schemas::*;
+use bytes::Bytes;
+
+impl ArrowPrimitive {
+    /// Sets [`pose`](Self::pose).
+    #[must_use]
+    pub fn with_pose(mut self, pose: impl Into<Pose>) -> Self {
+        self.pose = Some(pose.into());
+        self
+    }
+
+    /// Sets [`shaft_length`](Self::shaft_length).
+    #[must_use]
+    pub fn with_shaft_length(mut self, shaft_length: f64) -> Self {
+        self.shaft_length = shaft_length;
+        self
+    }
+
+    /// Sets [`shaft_diameter`](Self::shaft_diameter).
+    #[must_use]
+    pub fn with_shaft_diameter(mut self, shaft_diameter: f64) -> Self {
+        self.shaft_diameter = shaft_diameter;
+        self
+    }
+
+    /// Sets [`head_length`](Self::head_length).
+    #[must_use]
+    pub fn with_head_length(mut self, head_length: f64) -> Self {
+        self.head_length = head_length;
+        self
+    }
+
+    /// Sets [`head_diameter`](Self::head_diameter).
+    #[must_use]
+    pub fn with_head_diameter(mut self, head_diameter: f64) -> Self {
+        self.head_diameter = head_diameter;
+        self
+    }
+
+    /// Sets [`color`](Self::color).
+    #[must_use]
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl CircleAnnotation {
+    /// Sets [`timestamp`](Self::timestamp).
+    #[must_use]
+    pub fn with_timestamp(mut self, timestamp: impl Into<Timestamp>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Sets [`position`](Self::position).
+    #[must_use]
+    pub fn with_position(mut self, position: impl Into<Point2>) -> Self {
+        self.position = Some(position.into());
+        self
+    }
+
+    /// Sets [`diameter`](Self::diameter).
+    #[must_use]
+    pub fn with_diameter(mut self, diameter: f64) -> Self {
+        self.diameter = diameter;
+        self
+    }
+
+    /// Sets [`thickness`](Self::thickness).
+    #[must_use]
+    pub fn with_thickness(mut self, thickness: f64) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets [`fill_color`](Self::fill_color).
+    #[must_use]
+    pub fn with_fill_color(mut self, fill_color: impl Into<Color>) -> Self {
+        self.fill_color = Some(fill_color.into());
+        self
+    }
+
+    /// Sets [`outline_color`](Self::outline_color).
+    #[must_use]
+    pub fn with_outline_color(mut self, outline_color: impl Into<Color>) -> Self {
+        self.outline_color = Some(outline_color.into());
+        self
+    }
+}
+
+impl CubePrimitive {
+    /// Sets [`pose`](Self::pose).
+    #[must_use]
+    pub fn with_pose(mut self, pose: impl Into<Pose>) -> Self {
+        self.pose = Some(pose.into());
+        self
+    }
+
+    /// Sets [`size`](Self::size).
+    #[must_use]
+    pub fn with_size(mut self, size: impl Into<Vector3>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets [`color`](Self::color).
+    #[must_use]
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl CylinderPrimitive {
+    /// Sets [`pose`](Self::pose).
+    #[must_use]
+    pub fn with_pose(mut self, pose: impl Into<Pose>) -> Self {
+        self.pose = Some(pose.into());
+        self
+    }
+
+    /// Sets [`size`](Self::size).
+    #[must_use]
+    pub fn with_size(mut self, size: impl Into<Vector3>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets [`bottom_scale`](Self::bottom_scale).
+    #[must_use]
+    pub fn with_bottom_scale(mut self, bottom_scale: f64) -> Self {
+        self.bottom_scale = bottom_scale;
+        self
+    }
+
+    /// Sets [`top_scale`](Self::top_scale).
+    #[must_use]
+    pub fn with_top_scale(mut self, top_scale: f64) -> Self {
+        self.top_scale = top_scale;
+        self
+    }
+
+    /// Sets [`color`](Self::color).
+    #[must_use]
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl ImageAnnotations {
+    /// Sets [`circles`](Self::circles), replacing any existing values.
+    #[must_use]
+    pub fn with_circles<I>(mut self, circles: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<CircleAnnotation>,
+    {
+        self.circles = circles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`points`](Self::points), replacing any existing values.
+    #[must_use]
+    pub fn with_points<I>(mut self, points: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<PointsAnnotation>,
+    {
+        self.points = points.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`texts`](Self::texts), replacing any existing values.
+    #[must_use]
+    pub fn with_texts<I>(mut self, texts: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<TextAnnotation>,
+    {
+        self.texts = texts.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl LinePrimitive {
+    /// Sets `type`.
+    #[must_use]
+    pub fn with_type(mut self, value: line_primitive::Type) -> Self {
+        self.set_type(value);
+        self
+    }
+
+    /// Sets [`pose`](Self::pose).
+    #[must_use]
+    pub fn with_pose(mut self, pose: impl Into<Pose>) -> Self {
+        self.pose = Some(pose.into());
+        self
+    }
+
+    /// Sets [`thickness`](Self::thickness).
+    #[must_use]
+    pub fn with_thickness(mut self, thickness: f64) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets [`scale_invariant`](Self::scale_invariant).
+    #[must_use]
+    pub fn with_scale_invariant(mut self, scale_invariant: bool) -> Self {
+        self.scale_invariant = scale_invariant;
+        self
+    }
+
+    /// Sets [`points`](Self::points), replacing any existing values.
+    #[must_use]
+    pub fn with_points<I>(mut self, points: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Point3>,
+    {
+        self.points = points.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`color`](Self::color).
+    #[must_use]
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets [`colors`](Self::colors), replacing any existing values.
+    #[must_use]
+    pub fn with_colors<I>(mut self, colors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Color>,
+    {
+        self.colors = colors.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`indices`](Self::indices), replacing any existing values.
+    #[must_use]
+    pub fn with_indices(mut self, indices: impl IntoIterator<Item = u32>) -> Self {
+        self.indices = indices.into_iter().collect();
+        self
+    }
+}
+
+impl ModelPrimitive {
+    /// Sets [`pose`](Self::pose).
+    #[must_use]
+    pub fn with_pose(mut self, pose: impl Into<Pose>) -> Self {
+        self.pose = Some(pose.into());
+        self
+    }
+
+    /// Sets [`scale`](Self::scale).
+    #[must_use]
+    pub fn with_scale(mut self, scale: impl Into<Vector3>) -> Self {
+        self.scale = Some(scale.into());
+        self
+    }
+
+    /// Sets [`color`](Self::color).
+    #[must_use]
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets [`override_color`](Self::override_color).
+    #[must_use]
+    pub fn with_override_color(mut self, override_color: bool) -> Self {
+        self.override_color = override_color;
+        self
+    }
+
+    /// Sets [`url`](Self::url).
+    #[must_use]
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    /// Sets [`media_type`](Self::media_type).
+    #[must_use]
+    pub fn with_media_type(mut self, media_type: impl Into<String>) -> Self {
+        self.media_type = media_type.into();
+        self
+    }
+
+    /// Sets [`data`](Self::data).
+    #[must_use]
+    pub fn with_data(mut self, data: impl Into<Bytes>) -> Self {
+        self.data = data.into();
+        self
+    }
+}
+
+impl PointsAnnotation {
+    /// Sets [`timestamp`](Self::timestamp).
+    #[must_use]
+    pub fn with_timestamp(mut self, timestamp: impl Into<Timestamp>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Sets `type`.
+    #[must_use]
+    pub fn with_type(mut self, value: points_annotation::Type) -> Self {
+        self.set_type(value);
+        self
+    }
+
+    /// Sets [`points`](Self::points), replacing any existing values.
+    #[must_use]
+    pub fn with_points<I>(mut self, points: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Point2>,
+    {
+        self.points = points.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`outline_color`](Self::outline_color).
+    #[must_use]
+    pub fn with_outline_color(mut self, outline_color: impl Into<Color>) -> Self {
+        self.outline_color = Some(outline_color.into());
+        self
+    }
+
+    /// Sets [`outline_colors`](Self::outline_colors), replacing any existing values.
+    #[must_use]
+    pub fn with_outline_colors<I>(mut self, outline_colors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Color>,
+    {
+        self.outline_colors = outline_colors.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`fill_color`](Self::fill_color).
+    #[must_use]
+    pub fn with_fill_color(mut self, fill_color: impl Into<Color>) -> Self {
+        self.fill_color = Some(fill_color.into());
+        self
+    }
+
+    /// Sets [`thickness`](Self::thickness).
+    #[must_use]
+    pub fn with_thickness(mut self, thickness: f64) -> Self {
+        self.thickness = thickness;
+        self
+    }
+}
+
+impl SceneEntity {
+    /// Sets [`timestamp`](Self::timestamp).
+    #[must_use]
+    pub fn with_timestamp(mut self, timestamp: impl Into<Timestamp>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Sets [`frame_id`](Self::frame_id).
+    #[must_use]
+    pub fn with_frame_id(mut self, frame_id: impl Into<String>) -> Self {
+        self.frame_id = frame_id.into();
+        self
+    }
+
+    /// Sets [`id`](Self::id).
+    #[must_use]
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Sets [`lifetime`](Self::lifetime).
+    #[must_use]
+    pub fn with_lifetime(mut self, lifetime: impl Into<Duration>) -> Self {
+        self.lifetime = Some(lifetime.into());
+        self
+    }
+
+    /// Sets [`frame_locked`](Self::frame_locked).
+    #[must_use]
+    pub fn with_frame_locked(mut self, frame_locked: bool) -> Self {
+        self.frame_locked = frame_locked;
+        self
+    }
+
+    /// Sets [`metadata`](Self::metadata), replacing any existing values.
+    #[must_use]
+    pub fn with_metadata<I>(mut self, metadata: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<KeyValuePair>,
+    {
+        self.metadata = metadata.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`arrows`](Self::arrows), replacing any existing values.
+    #[must_use]
+    pub fn with_arrows<I>(mut self, arrows: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<ArrowPrimitive>,
+    {
+        self.arrows = arrows.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`cubes`](Self::cubes), replacing any existing values.
+    #[must_use]
+    pub fn with_cubes<I>(mut self, cubes: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<CubePrimitive>,
+    {
+        self.cubes = cubes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`spheres`](Self::spheres), replacing any existing values.
+    #[must_use]
+    pub fn with_spheres<I>(mut self, spheres: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<SpherePrimitive>,
+    {
+        self.spheres = spheres.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`cylinders`](Self::cylinders), replacing any existing values.
+    #[must_use]
+    pub fn with_cylinders<I>(mut self, cylinders: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<CylinderPrimitive>,
+    {
+        self.cylinders = cylinders.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`lines`](Self::lines), replacing any existing values.
+    #[must_use]
+    pub fn with_lines<I>(mut self, lines: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<LinePrimitive>,
+    {
+        self.lines = lines.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`triangles`](Self::triangles), replacing any existing values.
+    #[must_use]
+    pub fn with_triangles<I>(mut self, triangles: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<TriangleListPrimitive>,
+    {
+        self.triangles = triangles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`texts`](Self::texts), replacing any existing values.
+    #[must_use]
+    pub fn with_texts<I>(mut self, texts: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<TextPrimitive>,
+    {
+        self.texts = texts.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`models`](Self::models), replacing any existing values.
+    #[must_use]
+    pub fn with_models<I>(mut self, models: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<ModelPrimitive>,
+    {
+        self.models = models.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl SceneEntityDeletion {
+    /// Sets [`timestamp`](Self::timestamp).
+    #[must_use]
+    pub fn with_timestamp(mut self, timestamp: impl Into<Timestamp>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Sets `type`.
+    #[must_use]
+    pub fn with_type(mut self, value: scene_entity_deletion::Type) -> Self {
+        self.set_type(value);
+        self
+    }
+
+    /// Sets [`id`](Self::id).
+    #[must_use]
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+impl SceneUpdate {
+    /// Sets [`deletions`](Self::deletions), replacing any existing values.
+    #[must_use]
+    pub fn with_deletions<I>(mut self, deletions: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<SceneEntityDeletion>,
+    {
+        self.deletions = deletions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`entities`](Self::entities), replacing any existing values.
+    #[must_use]
+    pub fn with_entities<I>(mut self, entities: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<SceneEntity>,
+    {
+        self.entities = entities.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl SpherePrimitive {
+    /// Sets [`pose`](Self::pose).
+    #[must_use]
+    pub fn with_pose(mut self, pose: impl Into<Pose>) -> Self {
+        self.pose = Some(pose.into());
+        self
+    }
+
+    /// Sets [`size`](Self::size).
+    #[must_use]
+    pub fn with_size(mut self, size: impl Into<Vector3>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets [`color`](Self::color).
+    #[must_use]
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl TextAnnotation {
+    /// Sets [`timestamp`](Self::timestamp).
+    #[must_use]
+    pub fn with_timestamp(mut self, timestamp: impl Into<Timestamp>) -> Self {
+        self.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Sets [`position`](Self::position).
+    #[must_use]
+    pub fn with_position(mut self, position: impl Into<Point2>) -> Self {
+        self.position = Some(position.into());
+        self
+    }
+
+    /// Sets [`text`](Self::text).
+    #[must_use]
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Sets [`font_size`](Self::font_size).
+    #[must_use]
+    pub fn with_font_size(mut self, font_size: f64) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Sets [`text_color`](Self::text_color).
+    #[must_use]
+    pub fn with_text_color(mut self, text_color: impl Into<Color>) -> Self {
+        self.text_color = Some(text_color.into());
+        self
+    }
+
+    /// Sets [`background_color`](Self::background_color).
+    #[must_use]
+    pub fn with_background_color(mut self, background_color: impl Into<Color>) -> Self {
+        self.background_color = Some(background_color.into());
+        self
+    }
+}
+
+impl TextPrimitive {
+    /// Sets [`pose`](Self::pose).
+    #[must_use]
+    pub fn with_pose(mut self, pose: impl Into<Pose>) -> Self {
+        self.pose = Some(pose.into());
+        self
+    }
+
+    /// Sets [`billboard`](Self::billboard).
+    #[must_use]
+    pub fn with_billboard(mut self, billboard: bool) -> Self {
+        self.billboard = billboard;
+        self
+    }
+
+    /// Sets [`font_size`](Self::font_size).
+    #[must_use]
+    pub fn with_font_size(mut self, font_size: f64) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Sets [`scale_invariant`](Self::scale_invariant).
+    #[must_use]
+    pub fn with_scale_invariant(mut self, scale_invariant: bool) -> Self {
+        self.scale_invariant = scale_invariant;
+        self
+    }
+
+    /// Sets [`color`](Self::color).
+    #[must_use]
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets [`text`](Self::text).
+    #[must_use]
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+}
+
+impl TriangleListPrimitive {
+    /// Sets [`pose`](Self::pose).
+    #[must_use]
+    pub fn with_pose(mut self, pose: impl Into<Pose>) -> Self {
+        self.pose = Some(pose.into());
+        self
+    }
+
+    /// Sets [`points`](Self::points), replacing any existing values.
+    #[must_use]
+    pub fn with_points<I>(mut self, points: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Point3>,
+    {
+        self.points = points.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`color`](Self::color).
+    #[must_use]
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets [`colors`](Self::colors), replacing any existing values.
+    #[must_use]
+    pub fn with_colors<I>(mut self, colors: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Color>,
+    {
+        self.colors = colors.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`indices`](Self::indices), replacing any existing values.
+    #[must_use]
+    pub fn with_indices(mut self, indices: impl IntoIterator<Item = u32>) -> Self {
+        self.indices = indices.into_iter().collect();
+        self
+    }
+}
@@ -1,9 +1,12 @@
 // This file is @generated by prost-build.
 /// A primitive representing an arrow
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ArrowPrimitive {
     /// Position of the arrow's tail and orientation of the arrow. Identity orientation means the arrow points in the +x direction.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pose: ::core::option::Option<Pose>,
     /// Length of the arrow shaft
     #[prost(double, tag = "2")]
@@ -19,13 +22,17 @@ pub struct ArrowPrimitive {
     pub head_diameter: f64,
     /// Color of the arrow
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub color: ::core::option::Option<Color>,
 }
 /// Camera calibration parameters
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CameraCalibration {
     /// Timestamp of calibration data
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Frame of reference for the camera. The origin of the frame is the optical center of the camera. +x points to the right in the image, +y points down, and +z points into the plane of the image.
     #[prost(string, tag = "9")]
@@ -43,6 +50,7 @@ pub struct CameraCalibration {
     pub distortion_model: ::prost::alloc::string::String,
     /// Distortion parameters
     #[prost(double, repeated, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(rename = "D"))]
     pub d: ::prost::alloc::vec::Vec<f64>,
     /// Intrinsic camera matrix (3x3 row-major matrix)
     ///
@@ -58,6 +66,7 @@ pub struct CameraCalibration {
     ///
     /// length 9
     #[prost(double, repeated, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(rename = "K"))]
     pub k: ::prost::alloc::vec::Vec<f64>,
     /// Rectification matrix (stereo cameras only, 3x3 row-major matrix)
     ///
@@ -65,6 +74,7 @@ pub struct CameraCalibration {
     ///
     /// length 9
     #[prost(double, repeated, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(rename = "R"))]
     pub r: ::prost::alloc::vec::Vec<f64>,
     /// Projection/camera matrix (3x4 row-major matrix)
     ///
@@ -94,17 +104,22 @@ pub struct CameraCalibration {
     ///
     /// length 12
     #[prost(double, repeated, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(rename = "P"))]
     pub p: ::prost::alloc::vec::Vec<f64>,
 }
 /// A circle annotation on a 2D image
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CircleAnnotation {
     /// Timestamp of circle
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Center of the circle in 2D image coordinates (pixels).
     /// The coordinate uses the top-left corner of the top-left pixel of the image as the origin.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: ::core::option::Option<Point2>,
     /// Circle diameter in pixels
     #[prost(double, tag = "3")]
@@ -114,12 +129,16 @@ pub struct CircleAnnotation {
     pub thickness: f64,
     /// Fill color
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub fill_color: ::core::option::Option<Color>,
     /// Outline color
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub outline_color: ::core::option::Option<Color>,
 }
 /// A color in RGBA format
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Color {
     /// Red value between 0 and 1
@@ -136,16 +155,20 @@ pub struct Color {
     pub a: f64,
 }
 /// A compressed image
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CompressedImage {
     /// Timestamp of image
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Frame of reference for the image. The origin of the frame is the optical center of the camera. +x points to the right in the image, +y points down, and +z points into the plane of the image.
     #[prost(string, tag = "4")]
     pub frame_id: ::prost::alloc::string::String,
     /// Compressed image data
     #[prost(bytes = "bytes", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::schemas_wkt::serde::bytes"))]
     pub data: ::prost::bytes::Bytes,
     /// Image format
     ///
@@ -154,10 +177,13 @@ pub struct CompressedImage {
     pub format: ::prost::alloc::string::String,
 }
 /// A single frame of a compressed video bitstream
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CompressedVideo {
     /// Timestamp of video frame
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Frame of reference for the video.
     ///
@@ -188,6 +214,7 @@ pub struct CompressedVideo {
     ///    - Each CompressedVideo message should contain enough OBUs to decode exactly one video frame
     ///    - Each message containing a key frame must also include a Sequence Header OBU
     #[prost(bytes = "bytes", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::schemas_wkt::serde::bytes"))]
     pub data: ::prost::bytes::Bytes,
     /// Video format.
     ///
@@ -198,26 +225,35 @@ pub struct CompressedVideo {
     pub format: ::prost::alloc::string::String,
 }
 /// A primitive representing a cube or rectangular prism
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CubePrimitive {
     /// Position of the center of the cube and orientation of the cube
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pose: ::core::option::Option<Pose>,
     /// Size of the cube along each axis
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub size: ::core::option::Option<Vector3>,
     /// Color of the cube
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub color: ::core::option::Option<Color>,
 }
 /// A primitive representing a cylinder, elliptic cylinder, or truncated cone
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CylinderPrimitive {
     /// Position of the center of the cylinder and orientation of the cylinder. The flat face(s) are perpendicular to the z-axis.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pose: ::core::option::Option<Pose>,
     /// Size of the cylinder's bounding box
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub size: ::core::option::Option<Vector3>,
     /// 0-1, ratio of the diameter of the cylinder's bottom face (min z) to the bottom of the bounding box
     #[prost(double, tag = "3")]
@@ -227,13 +263,17 @@ pub struct CylinderPrimitive {
     pub top_scale: f64,
     /// Color of the cylinder
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub color: ::core::option::Option<Color>,
 }
 /// A transform between two reference frames in 3D space
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FrameTransform {
     /// Timestamp of transform
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Name of the parent frame
     #[prost(string, tag = "2")]
@@ -243,12 +283,16 @@ pub struct FrameTransform {
     pub child_frame_id: ::prost::alloc::string::String,
     /// Translation component of the transform
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub translation: ::core::option::Option<Vector3>,
     /// Rotation component of the transform
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub rotation: ::core::option::Option<Quaternion>,
 }
 /// An array of FrameTransform messages
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FrameTransforms {
     /// Array of transforms
@@ -256,6 +300,8 @@ pub struct FrameTransforms {
     pub transforms: ::prost::alloc::vec::Vec<FrameTransform>,
 }
 /// GeoJSON data for annotating maps
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GeoJson {
     /// GeoJSON data encoded as a UTF-8 string
@@ -263,22 +309,27 @@ pub struct GeoJson {
     pub geojson: ::prost::alloc::string::String,
 }
 /// A 2D grid of data
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Grid {
     /// Timestamp of grid
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Frame of reference
     #[prost(string, tag = "2")]
     pub frame_id: ::prost::alloc::string::String,
     /// Origin of grid's corner relative to frame of reference; grid is positioned in the x-y plane relative to this origin
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pose: ::core::option::Option<Pose>,
     /// Number of grid columns
     #[prost(fixed32, tag = "4")]
     pub column_count: u32,
     /// Size of single grid cell along x and y axes, relative to `pose`
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub cell_size: ::core::option::Option<Vector2>,
     /// Number of bytes between rows in `data`
     #[prost(fixed32, tag = "6")]
@@ -291,9 +342,12 @@ pub struct Grid {
     pub fields: ::prost::alloc::vec::Vec<PackedElementField>,
     /// Grid cell data, interpreted using `fields`, in row-major (y-major) order
     #[prost(bytes = "bytes", tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::schemas_wkt::serde::bytes"))]
     pub data: ::prost::bytes::Bytes,
 }
 /// Array of annotations for a 2D image
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ImageAnnotations {
     /// Circle annotations
@@ -307,6 +361,8 @@ pub struct ImageAnnotations {
     pub texts: ::prost::alloc::vec::Vec<TextAnnotation>,
 }
 /// A key with its associated value
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct KeyValuePair {
     /// Key
@@ -317,16 +373,20 @@ pub struct KeyValuePair {
     pub value: ::prost::alloc::string::String,
 }
 /// A single scan from a planar laser range-finder
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LaserScan {
     /// Timestamp of scan
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Frame of reference
     #[prost(string, tag = "2")]
     pub frame_id: ::prost::alloc::string::String,
     /// Origin of scan relative to frame of reference; points are positioned in the x-y plane relative to this origin; angles are interpreted as counterclockwise rotations around the z axis with 0 rad being in the +x direction
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pose: ::core::option::Option<Pose>,
     /// Bearing of first point, in radians
     #[prost(double, tag = "4")]
@@ -342,6 +402,8 @@ pub struct LaserScan {
     pub intensities: ::prost::alloc::vec::Vec<f64>,
 }
 /// A primitive representing a series of points connected by lines
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LinePrimitive {
     /// Drawing primitive to use for lines
@@ -349,6 +411,7 @@ pub struct LinePrimitive {
     pub r#type: i32,
     /// Origin of lines relative to reference frame
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pose: ::core::option::Option<Pose>,
     /// Line thickness
     #[prost(double, tag = "3")]
@@ -361,6 +424,7 @@ pub struct LinePrimitive {
     pub points: ::prost::alloc::vec::Vec<Point3>,
     /// Solid color to use for the whole line. One of `color` or `colors` must be provided.
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub color: ::core::option::Option<Color>,
     /// Per-point colors (if specified, must have the same length as `points`). One of `color` or `colors` must be provided.
     #[prost(message, repeated, tag = "7")]
@@ -374,6 +438,7 @@ pub struct LinePrimitive {
 /// Nested message and enum types in `LinePrimitive`.
 pub mod line_primitive {
     /// An enumeration indicating how input points should be interpreted to create lines
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[derive(
         Clone,
         Copy,
@@ -418,10 +483,13 @@ pub mod line_primitive {
     }
 }
 /// A navigation satellite fix for any Global Navigation Satellite System
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LocationFix {
     /// Timestamp of the message
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Frame for the sensor. Latitude and longitude readings are at the origin of the frame.
     #[prost(string, tag = "7")]
@@ -447,6 +515,7 @@ pub struct LocationFix {
 /// Nested message and enum types in `LocationFix`.
 pub mod location_fix {
     /// Type of position covariance
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[derive(
         Clone,
         Copy,
@@ -491,10 +560,13 @@ pub mod location_fix {
     }
 }
 /// A log message
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Log {
     /// Timestamp of log message
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Log level
     #[prost(enumeration = "log::Level", tag = "2")]
@@ -515,6 +587,7 @@ pub struct Log {
 /// Nested message and enum types in `Log`.
 pub mod log {
     /// Log level
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[derive(
         Clone,
         Copy,
@@ -565,16 +638,21 @@ pub mod log {
     }
 }
 /// A primitive representing a 3D model file loaded from an external URL or embedded data
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelPrimitive {
     /// Origin of model relative to reference frame
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pose: ::core::option::Option<Pose>,
     /// Scale factor to apply to the model along each axis
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub scale: ::core::option::Option<Vector3>,
     /// Solid color to use for the whole model if `override_color` is true.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub color: ::core::option::Option<Color>,
     /// Whether to use the color specified in `color` instead of any materials embedded in the original model.
     #[prost(bool, tag = "4")]
@@ -587,9 +665,12 @@ pub struct ModelPrimitive {
     pub media_type: ::prost::alloc::string::String,
     /// Embedded model. One of `url` or `data` should be provided. If `data` is provided, `media_type` must be set to indicate the type of the data.
     #[prost(bytes = "bytes", tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::schemas_wkt::serde::bytes"))]
     pub data: ::prost::bytes::Bytes,
 }
 /// A field present within each element in a byte array of packed elements.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PackedElementField {
     /// Name of the field
@@ -605,6 +686,7 @@ pub struct PackedElementField {
 /// Nested message and enum types in `PackedElementField`.
 pub mod packed_element_field {
     /// Numeric type
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[derive(
         Clone,
         Copy,
@@ -664,6 +746,8 @@ pub mod packed_element_field {
     }
 }
 /// A point representing a position in 2D space
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Point2 {
     /// x coordinate position
@@ -674,6 +758,8 @@ pub struct Point2 {
     pub y: f64,
 }
 /// A point representing a position in 3D space
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Point3 {
     /// x coordinate position
@@ -687,16 +773,20 @@ pub struct Point3 {
     pub z: f64,
 }
 /// A collection of N-dimensional points, which may contain additional fields with information like normals, intensity, etc.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PointCloud {
     /// Timestamp of point cloud
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Frame of reference
     #[prost(string, tag = "2")]
     pub frame_id: ::prost::alloc::string::String,
     /// The origin of the point cloud relative to the frame of reference
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pose: ::core::option::Option<Pose>,
     /// Number of bytes between points in the `data`
     #[prost(fixed32, tag = "4")]
@@ -706,13 +796,17 @@ pub struct PointCloud {
     pub fields: ::prost::alloc::vec::Vec<PackedElementField>,
     /// Point data, interpreted using `fields`
     #[prost(bytes = "bytes", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::schemas_wkt::serde::bytes"))]
     pub data: ::prost::bytes::Bytes,
 }
 /// An array of points on a 2D image
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PointsAnnotation {
     /// Timestamp of annotation
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Type of points annotation to draw
     #[prost(enumeration = "points_annotation::Type", tag = "2")]
@@ -723,12 +817,14 @@ pub struct PointsAnnotation {
     pub points: ::prost::alloc::vec::Vec<Point2>,
     /// Outline color
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub outline_color: ::core::option::Option<Color>,
     /// Per-point colors, if `type` is `POINTS`, or per-segment stroke colors, if `type` is `LINE_LIST`, `LINE_STRIP` or `LINE_LOOP`.
     #[prost(message, repeated, tag = "5")]
     pub outline_colors: ::prost::alloc::vec::Vec<Color>,
     /// Fill color
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub fill_color: ::core::option::Option<Color>,
     /// Stroke thickness in pixels
     #[prost(double, tag = "7")]
@@ -737,6 +833,7 @@ pub struct PointsAnnotation {
 /// Nested message and enum types in `PointsAnnotation`.
 pub mod points_annotation {
     /// Type of points annotation
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[derive(
         Clone,
         Copy,
@@ -788,33 +885,44 @@ pub mod points_annotation {
     }
 }
 /// A position and orientation for an object or reference frame in 3D space
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Pose {
     /// Point denoting position in 3D space
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: ::core::option::Option<Vector3>,
     /// Quaternion denoting orientation in 3D space
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub orientation: ::core::option::Option<Quaternion>,
 }
 /// A timestamped pose for an object or reference frame in 3D space
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PoseInFrame {
     /// Timestamp of pose
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Frame of reference for pose position and orientation
     #[prost(string, tag = "2")]
     pub frame_id: ::prost::alloc::string::String,
     /// Pose in 3D space
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pose: ::core::option::Option<Pose>,
 }
 /// An array of timestamped poses for an object or reference frame in 3D space
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PosesInFrame {
     /// Timestamp of pose
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Frame of reference for pose position and orientation
     #[prost(string, tag = "2")]
@@ -824,6 +932,8 @@ pub struct PosesInFrame {
     pub poses: ::prost::alloc::vec::Vec<Pose>,
 }
 /// A [quaternion](<https://eater.net/quaternions>) representing a rotation in 3D space
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Quaternion {
     /// x value
@@ -840,10 +950,13 @@ pub struct Quaternion {
     pub w: f64,
 }
 /// A raw image
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RawImage {
     /// Timestamp of image
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Frame of reference for the image. The origin of the frame is the optical center of the camera. +x points to the right in the image, +y points down, and +z points into the plane of the image.
     #[prost(string, tag = "7")]
@@ -864,13 +977,17 @@ pub struct RawImage {
     pub step: u32,
     /// Raw image data
     #[prost(bytes = "bytes", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::schemas_wkt::serde::bytes"))]
     pub data: ::prost::bytes::Bytes,
 }
 /// A visual element in a 3D scene. An entity may be composed of multiple primitives which all share the same frame of reference.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SceneEntity {
     /// Timestamp of the entity
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Frame of reference
     #[prost(string, tag = "2")]
@@ -880,6 +997,7 @@ pub struct SceneEntity {
     pub id: ::prost::alloc::string::String,
     /// Length of time (relative to `timestamp`) after which the entity should be automatically removed. Zero value indicates the entity should remain visible until it is replaced or deleted.
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub lifetime: ::core::option::Option<crate::schemas::Duration>,
     /// Whether the entity should keep its location in the fixed frame (false) or follow the frame specified in `frame_id` as it moves relative to the fixed frame (true)
     #[prost(bool, tag = "5")]
@@ -913,10 +1031,13 @@ pub struct SceneEntity {
     pub models: ::prost::alloc::vec::Vec<ModelPrimitive>,
 }
 /// Command to remove previously published entities
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SceneEntityDeletion {
     /// Timestamp of the deletion. Only matching entities earlier than this timestamp will be deleted.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Type of deletion action to perform
     #[prost(enumeration = "scene_entity_deletion::Type", tag = "2")]
@@ -928,6 +1049,7 @@ pub struct SceneEntityDeletion {
 /// Nested message and enum types in `SceneEntityDeletion`.
 pub mod scene_entity_deletion {
     /// An enumeration indicating which entities should match a SceneEntityDeletion command
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[derive(
         Clone,
         Copy,
@@ -968,6 +1090,8 @@ pub mod scene_entity_deletion {
     }
 }
 /// An update to the entities displayed in a 3D scene
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SceneUpdate {
     /// Scene entities to delete
@@ -978,27 +1102,36 @@ pub struct SceneUpdate {
     pub entities: ::prost::alloc::vec::Vec<SceneEntity>,
 }
 /// A primitive representing a sphere or ellipsoid
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct SpherePrimitive {
     /// Position of the center of the sphere and orientation of the sphere
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pose: ::core::option::Option<Pose>,
     /// Size (diameter) of the sphere along each axis
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub size: ::core::option::Option<Vector3>,
     /// Color of the sphere
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub color: ::core::option::Option<Color>,
 }
 /// A text label on a 2D image
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TextAnnotation {
     /// Timestamp of annotation
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub timestamp: ::core::option::Option<crate::schemas::Timestamp>,
     /// Bottom-left origin of the text label in 2D image coordinates (pixels).
     /// The coordinate uses the top-left corner of the top-left pixel of the image as the origin.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: ::core::option::Option<Point2>,
     /// Text to display
     #[prost(string, tag = "3")]
@@ -1008,16 +1141,21 @@ pub struct TextAnnotation {
     pub font_size: f64,
     /// Text color
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub text_color: ::core::option::Option<Color>,
     /// Background fill color
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub background_color: ::core::option::Option<Color>,
 }
 /// A primitive representing a text label
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TextPrimitive {
     /// Position of the center of the text box and orientation of the text. Identity orientation means the text is oriented in the xy-plane and flows from -x to +x.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pose: ::core::option::Option<Pose>,
     /// Whether the text should respect `pose.orientation` (false) or always face the camera (true)
     #[prost(bool, tag = "2")]
@@ -1030,22 +1168,27 @@ pub struct TextPrimitive {
     pub scale_invariant: bool,
     /// Color of the text
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub color: ::core::option::Option<Color>,
     /// Text
     #[prost(string, tag = "6")]
     pub text: ::prost::alloc::string::String,
 }
 /// A primitive representing a set of triangles or a surface tiled by triangles
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TriangleListPrimitive {
     /// Origin of triangles relative to reference frame
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pose: ::core::option::Option<Pose>,
     /// Vertices to use for triangles, interpreted as a list of triples (0-1-2, 3-4-5, ...)
     #[prost(message, repeated, tag = "2")]
     pub points: ::prost::alloc::vec::Vec<Point3>,
     /// Solid color to use for the whole shape. One of `color` or `colors` must be provided.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub color: ::core::option::Option<Color>,
     /// Per-vertex colors (if specified, must have the same length as `points`). One of `color` or `colors` must be provided.
     #[prost(message, repeated, tag = "4")]
@@ -1057,6 +1200,8 @@ pub struct TriangleListPrimitive {
     pub indices: ::prost::alloc::vec::Vec<u32>,
 }
 /// A vector in 2D space that represents a direction only
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Vector2 {
     /// x coordinate length
@@ -1067,6 +1212,8 @@ pub struct Vector2 {
     pub y: f64,
 }
 /// A vector in 3D space that represents a direction only
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Vector3 {
     /// x coordinate length
@@ -1,8 +1,242 @@
 // This file is @generated by foxglove-proto-gen
 use crate::schemas::{descriptors, foxglove::*};
+use crate::protobuf::{DescriptorSet, ProtobufMessage, TypeRef};
+use crate::validate::{Validate, ValidationError, Validator};
 use crate::{Schema, Encode};
 use bytes::BufMut;
 
+impl Validate for ArrowPrimitive {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.message("pose", self.pose.as_ref());
+        validator.message("color", self.color.as_ref());
+    }
+}
+
+impl Validate for CameraCalibration {
+    fn validate_fields(&self, validator: &mut Validator) {
+        self.check_invariants(validator);
+    }
+}
+
+impl Validate for CircleAnnotation {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.message("position", self.position.as_ref());
+        validator.message("fill_color", self.fill_color.as_ref());
+        validator.message("outline_color", self.outline_color.as_ref());
+    }
+}
+
+impl Validate for Color {}
+
+impl Validate for CompressedImage {
+    fn validate_fields(&self, validator: &mut Validator) {
+        self.check_invariants(validator);
+    }
+}
+
+impl Validate for CompressedVideo {}
+
+impl Validate for CubePrimitive {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.message("pose", self.pose.as_ref());
+        validator.message("size", self.size.as_ref());
+        validator.message("color", self.color.as_ref());
+    }
+}
+
+impl Validate for CylinderPrimitive {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.message("pose", self.pose.as_ref());
+        validator.message("size", self.size.as_ref());
+        validator.message("color", self.color.as_ref());
+    }
+}
+
+impl Validate for FrameTransform {
+    fn validate_fields(&self, validator: &mut Validator) {
+        self.check_invariants(validator);
+        validator.message("translation", self.translation.as_ref());
+        validator.message("rotation", self.rotation.as_ref());
+    }
+}
+
+impl Validate for FrameTransforms {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.messages("transforms", &self.transforms);
+    }
+}
+
+impl Validate for GeoJson {
+    fn validate_fields(&self, validator: &mut Validator) {
+        self.check_invariants(validator);
+    }
+}
+
+impl Validate for Grid {
+    fn validate_fields(&self, validator: &mut Validator) {
+        self.check_invariants(validator);
+        validator.message("pose", self.pose.as_ref());
+        validator.message("cell_size", self.cell_size.as_ref());
+        validator.messages("fields", &self.fields);
+    }
+}
+
+impl Validate for ImageAnnotations {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.messages("circles", &self.circles);
+        validator.messages("points", &self.points);
+        validator.messages("texts", &self.texts);
+    }
+}
+
+impl Validate for KeyValuePair {}
+
+impl Validate for LaserScan {
+    fn validate_fields(&self, validator: &mut Validator) {
+        self.check_invariants(validator);
+        validator.message("pose", self.pose.as_ref());
+    }
+}
+
+impl Validate for LinePrimitive {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.message("pose", self.pose.as_ref());
+        validator.messages("points", &self.points);
+        validator.message("color", self.color.as_ref());
+        validator.messages("colors", &self.colors);
+    }
+}
+
+impl Validate for LocationFix {
+    fn validate_fields(&self, validator: &mut Validator) {
+        self.check_invariants(validator);
+    }
+}
+
+impl Validate for Log {}
+
+impl Validate for ModelPrimitive {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.message("pose", self.pose.as_ref());
+        validator.message("scale", self.scale.as_ref());
+        validator.message("color", self.color.as_ref());
+    }
+}
+
+impl Validate for PackedElementField {}
+
+impl Validate for Point2 {}
+
+impl Validate for Point3 {}
+
+impl Validate for PointCloud {
+    fn validate_fields(&self, validator: &mut Validator) {
+        self.check_invariants(validator);
+        validator.message("pose", self.pose.as_ref());
+        validator.messages("fields", &self.fields);
+    }
+}
+
+impl Validate for PointsAnnotation {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.messages("points", &self.points);
+        validator.message("outline_color", self.outline_color.as_ref());
+        validator.messages("outline_colors", &self.outline_colors);
+        validator.message("fill_color", self.fill_color.as_ref());
+    }
+}
+
+impl Validate for Pose {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.message("position", self.position.as_ref());
+        validator.message("orientation", self.orientation.as_ref());
+    }
+}
+
+impl Validate for PoseInFrame {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.message("pose", self.pose.as_ref());
+    }
+}
+
+impl Validate for PosesInFrame {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.messages("poses", &self.poses);
+    }
+}
+
+impl Validate for Quaternion {
+    fn validate_fields(&self, validator: &mut Validator) {
+        self.check_invariants(validator);
+    }
+}
+
+impl Validate for RawImage {
+    fn validate_fields(&self, validator: &mut Validator) {
+        self.check_invariants(validator);
+    }
+}
+
+impl Validate for SceneEntity {
+    fn validate_fields(&self, validator: &mut Validator) {
+        self.check_invariants(validator);
+        validator.messages("metadata", &self.metadata);
+        validator.messages("arrows", &self.arrows);
+        validator.messages("cubes", &self.cubes);
+        validator.messages("spheres", &self.spheres);
+        validator.messages("cylinders", &self.cylinders);
+        validator.messages("lines", &self.lines);
+        validator.messages("triangles", &self.triangles);
+        validator.messages("texts", &self.texts);
+        validator.messages("models", &self.models);
+    }
+}
+
+impl Validate for SceneEntityDeletion {}
+
+impl Validate for SceneUpdate {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.messages("deletions", &self.deletions);
+        validator.messages("entities", &self.entities);
+    }
+}
+
+impl Validate for SpherePrimitive {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.message("pose", self.pose.as_ref());
+        validator.message("size", self.size.as_ref());
+        validator.message("color", self.color.as_ref());
+    }
+}
+
+impl Validate for TextAnnotation {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.message("position", self.position.as_ref());
+        validator.message("text_color", self.text_color.as_ref());
+        validator.message("background_color", self.background_color.as_ref());
+    }
+}
+
+impl Validate for TextPrimitive {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.message("pose", self.pose.as_ref());
+        validator.message("color", self.color.as_ref());
+    }
+}
+
+impl Validate for TriangleListPrimitive {
+    fn validate_fields(&self, validator: &mut Validator) {
+        validator.message("pose", self.pose.as_ref());
+        validator.messages("points", &self.points);
+        validator.message("color", self.color.as_ref());
+        validator.messages("colors", &self.colors);
+    }
+}
+
+impl Validate for Vector2 {}
+
+impl Validate for Vector3 {}
+
 impl Encode for CameraCalibration {
     type Error = ::prost::EncodeError;
 
@@ -23,6 +257,25 @@ impl Encode for CameraCalibration {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for CameraCalibration {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::CAMERA_CALIBRATION);
+        TypeRef::new("foxglove.CameraCalibration", "foxglove/CameraCalibration.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for CircleAnnotation {
@@ -45,6 +298,25 @@ impl Encode for CircleAnnotation {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for CircleAnnotation {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::CIRCLE_ANNOTATION);
+        TypeRef::new("foxglove.CircleAnnotation", "foxglove/CircleAnnotation.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for Color {
@@ -67,6 +339,25 @@ impl Encode for Color {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for Color {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::COLOR);
+        TypeRef::new("foxglove.Color", "foxglove/Color.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for CompressedImage {
@@ -89,6 +380,25 @@ impl Encode for CompressedImage {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for CompressedImage {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::COMPRESSED_IMAGE);
+        TypeRef::new("foxglove.CompressedImage", "foxglove/CompressedImage.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for CompressedVideo {
@@ -111,6 +421,25 @@ impl Encode for CompressedVideo {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for CompressedVideo {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::COMPRESSED_VIDEO);
+        TypeRef::new("foxglove.CompressedVideo", "foxglove/CompressedVideo.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for FrameTransform {
@@ -133,6 +462,25 @@ impl Encode for FrameTransform {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for FrameTransform {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::FRAME_TRANSFORM);
+        TypeRef::new("foxglove.FrameTransform", "foxglove/FrameTransform.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for FrameTransforms {
@@ -155,6 +503,25 @@ impl Encode for FrameTransforms {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for FrameTransforms {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::FRAME_TRANSFORMS);
+        TypeRef::new("foxglove.FrameTransforms", "foxglove/FrameTransforms.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for GeoJson {
@@ -177,6 +544,25 @@ impl Encode for GeoJson {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for GeoJson {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::GEO_JSON);
+        TypeRef::new("foxglove.GeoJSON", "foxglove/GeoJSON.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for Grid {
@@ -199,6 +585,25 @@ impl Encode for Grid {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for Grid {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::GRID);
+        TypeRef::new("foxglove.Grid", "foxglove/Grid.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for ImageAnnotations {
@@ -221,6 +626,25 @@ impl Encode for ImageAnnotations {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for ImageAnnotations {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::IMAGE_ANNOTATIONS);
+        TypeRef::new("foxglove.ImageAnnotations", "foxglove/ImageAnnotations.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for KeyValuePair {
@@ -243,6 +667,25 @@ impl Encode for KeyValuePair {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for KeyValuePair {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::KEY_VALUE_PAIR);
+        TypeRef::new("foxglove.KeyValuePair", "foxglove/KeyValuePair.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for LaserScan {
@@ -265,6 +708,25 @@ impl Encode for LaserScan {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for LaserScan {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::LASER_SCAN);
+        TypeRef::new("foxglove.LaserScan", "foxglove/LaserScan.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for LocationFix {
@@ -287,6 +749,25 @@ impl Encode for LocationFix {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for LocationFix {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::LOCATION_FIX);
+        TypeRef::new("foxglove.LocationFix", "foxglove/LocationFix.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for Log {
@@ -309,6 +790,25 @@ impl Encode for Log {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for Log {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::LOG);
+        TypeRef::new("foxglove.Log", "foxglove/Log.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for PackedElementField {
@@ -331,6 +831,25 @@ impl Encode for PackedElementField {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for PackedElementField {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::PACKED_ELEMENT_FIELD);
+        TypeRef::new("foxglove.PackedElementField", "foxglove/PackedElementField.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for Point2 {
@@ -353,6 +872,25 @@ impl Encode for Point2 {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for Point2 {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::POINT2);
+        TypeRef::new("foxglove.Point2", "foxglove/Point2.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for Point3 {
@@ -375,6 +913,25 @@ impl Encode for Point3 {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for Point3 {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::POINT3);
+        TypeRef::new("foxglove.Point3", "foxglove/Point3.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for PointCloud {
@@ -397,6 +954,25 @@ impl Encode for PointCloud {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for PointCloud {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::POINT_CLOUD);
+        TypeRef::new("foxglove.PointCloud", "foxglove/PointCloud.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for PointsAnnotation {
@@ -419,6 +995,25 @@ impl Encode for PointsAnnotation {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for PointsAnnotation {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::POINTS_ANNOTATION);
+        TypeRef::new("foxglove.PointsAnnotation", "foxglove/PointsAnnotation.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for Pose {
@@ -441,6 +1036,25 @@ impl Encode for Pose {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for Pose {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::POSE);
+        TypeRef::new("foxglove.Pose", "foxglove/Pose.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for PoseInFrame {
@@ -463,6 +1077,25 @@ impl Encode for PoseInFrame {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for PoseInFrame {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::POSE_IN_FRAME);
+        TypeRef::new("foxglove.PoseInFrame", "foxglove/PoseInFrame.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for PosesInFrame {
@@ -485,6 +1118,25 @@ impl Encode for PosesInFrame {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for PosesInFrame {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::POSES_IN_FRAME);
+        TypeRef::new("foxglove.PosesInFrame", "foxglove/PosesInFrame.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for Quaternion {
@@ -507,6 +1159,25 @@ impl Encode for Quaternion {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for Quaternion {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::QUATERNION);
+        TypeRef::new("foxglove.Quaternion", "foxglove/Quaternion.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for RawImage {
@@ -529,6 +1200,25 @@ impl Encode for RawImage {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for RawImage {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::RAW_IMAGE);
+        TypeRef::new("foxglove.RawImage", "foxglove/RawImage.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for SceneEntity {
@@ -551,6 +1241,25 @@ impl Encode for SceneEntity {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for SceneEntity {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::SCENE_ENTITY);
+        TypeRef::new("foxglove.SceneEntity", "foxglove/SceneEntity.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for SceneEntityDeletion {
@@ -573,6 +1282,25 @@ impl Encode for SceneEntityDeletion {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for SceneEntityDeletion {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::SCENE_ENTITY_DELETION);
+        TypeRef::new("foxglove.SceneEntityDeletion", "foxglove/SceneEntityDeletion.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for SceneUpdate {
@@ -595,6 +1323,25 @@ impl Encode for SceneUpdate {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for SceneUpdate {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::SCENE_UPDATE);
+        TypeRef::new("foxglove.SceneUpdate", "foxglove/SceneUpdate.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for TextAnnotation {
@@ -617,6 +1364,25 @@ impl Encode for TextAnnotation {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for TextAnnotation {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::TEXT_ANNOTATION);
+        TypeRef::new("foxglove.TextAnnotation", "foxglove/TextAnnotation.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for Vector2 {
@@ -639,6 +1405,25 @@ impl Encode for Vector2 {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for Vector2 {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::VECTOR2);
+        TypeRef::new("foxglove.Vector2", "foxglove/Vector2.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
 
 impl Encode for Vector3 {
@@ -661,4 +1446,23 @@ impl Encode for Vector3 {
     }
 
     fn encoded_len(&self) -> Option<usize> { Some(::prost::Message::encoded_len(self)) }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        crate::validate::validate(self)
+    }
+}
+
+impl ProtobufMessage for Vector3 {
+    fn type_ref(set: &mut DescriptorSet) -> TypeRef {
+        set.add_encoded_files(descriptors::VECTOR3);
+        TypeRef::new("foxglove.Vector3", "foxglove/Vector3.proto")
+    }
+
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        ::prost::Message::encode_raw(self, buf)
+    }
+
+    fn encoded_len(&self) -> usize {
+        ::prost::Message::encoded_len(self)
+    }
 }
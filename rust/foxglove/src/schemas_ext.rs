@@ -0,0 +1,64 @@
+//! Helpers and conversions for the well-known schema types.
+//!
+//! This module lives outside `crate::schemas`, because everything under the schemas/ directory is
+//! generated.
+
+use crate::schemas::{FrameTransform, KeyValuePair, Pose};
+
+mod color;
+#[cfg(feature = "geo")]
+mod geo;
+mod geometry;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "image")]
+mod image;
+mod location;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+pub use color::ParseColorError;
+#[cfg(feature = "geo")]
+pub use geo::ParseGeoJsonError;
+
+impl FrameTransform {
+    /// Creates a transform from the parent frame to the child frame, where the pose is the
+    /// position and orientation of the child frame in the parent frame.
+    ///
+    /// With the `nalgebra` or `glam` features, the pose may be an `Isometry3` or `DAffine3`:
+    ///
+    /// ```
+    /// # #[cfg(feature = "nalgebra")]
+    /// # {
+    /// use foxglove::schemas::FrameTransform;
+    /// use nalgebra::Isometry3;
+    ///
+    /// let base = Isometry3::translation(1.0, 2.0, 0.0);
+    /// let tf = FrameTransform::from_pose("world", "base_link", base);
+    /// assert_eq!(tf.translation.unwrap().x, 1.0);
+    /// # }
+    /// ```
+    pub fn from_pose(
+        parent_frame_id: impl Into<String>,
+        child_frame_id: impl Into<String>,
+        pose: impl Into<Pose>,
+    ) -> Self {
+        let pose = pose.into();
+        Self {
+            timestamp: None,
+            parent_frame_id: parent_frame_id.into(),
+            child_frame_id: child_frame_id.into(),
+            translation: pose.position,
+            rotation: pose.orientation,
+        }
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> From<(K, V)> for KeyValuePair {
+    fn from((key, value): (K, V)) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
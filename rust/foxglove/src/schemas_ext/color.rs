@@ -0,0 +1,150 @@
+//! Constructors for [`Color`].
+
+use std::str::FromStr;
+
+use crate::schemas::Color;
+
+#[cfg(test)]
+mod tests;
+
+/// An error parsing a [`Color`] from a string.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid color {0:?}: expected a hex color such as #ff8800, or a color name")]
+pub struct ParseColorError(String);
+
+/// Named colors, matching the CSS basic color keywords, plus orange.
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("black", 0x000000),
+    ("silver", 0xc0c0c0),
+    ("gray", 0x808080),
+    ("grey", 0x808080),
+    ("white", 0xffffff),
+    ("maroon", 0x800000),
+    ("red", 0xff0000),
+    ("purple", 0x800080),
+    ("fuchsia", 0xff00ff),
+    ("magenta", 0xff00ff),
+    ("green", 0x008000),
+    ("lime", 0x00ff00),
+    ("olive", 0x808000),
+    ("yellow", 0xffff00),
+    ("navy", 0x000080),
+    ("blue", 0x0000ff),
+    ("teal", 0x008080),
+    ("aqua", 0x00ffff),
+    ("cyan", 0x00ffff),
+    ("orange", 0xffa500),
+];
+
+impl Color {
+    /// Opaque black.
+    pub const BLACK: Self = Self::rgb(0.0, 0.0, 0.0);
+    /// Opaque white.
+    pub const WHITE: Self = Self::rgb(1.0, 1.0, 1.0);
+    /// Opaque red.
+    pub const RED: Self = Self::rgb(1.0, 0.0, 0.0);
+    /// Opaque green, `#00ff00`. This is the color named `lime` by [`Color::from_name`].
+    pub const GREEN: Self = Self::rgb(0.0, 1.0, 0.0);
+    /// Opaque blue.
+    pub const BLUE: Self = Self::rgb(0.0, 0.0, 1.0);
+    /// Opaque yellow.
+    pub const YELLOW: Self = Self::rgb(1.0, 1.0, 0.0);
+    /// Opaque cyan.
+    pub const CYAN: Self = Self::rgb(0.0, 1.0, 1.0);
+    /// Opaque magenta.
+    pub const MAGENTA: Self = Self::rgb(1.0, 0.0, 1.0);
+    /// Fully transparent black.
+    pub const TRANSPARENT: Self = Self::rgba(0.0, 0.0, 0.0, 0.0);
+
+    /// Creates a color from components in the range 0 to 1.
+    pub const fn rgba(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Creates an opaque color from components in the range 0 to 1.
+    pub const fn rgb(r: f64, g: f64, b: f64) -> Self {
+        Self::rgba(r, g, b, 1.0)
+    }
+
+    /// Creates a color from 8-bit components.
+    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        let c = |v: u8| f64::from(v) / 255.0;
+        Self::rgba(c(r), c(g), c(b), c(a))
+    }
+
+    /// Creates an opaque color from 8-bit components.
+    pub fn from_rgb8(r: u8, g: u8, b: u8) -> Self {
+        Self::from_rgba8(r, g, b, 255)
+    }
+
+    /// Parses a hex color, in the form `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`. The leading
+    /// `#` is optional.
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let err = || ParseColorError(hex.to_string());
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        // `from_str_radix` accepts a sign, so check the digits first. This also ensures that the
+        // digits are ASCII, so they can be sliced by byte.
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(err());
+        }
+        let (width, scale) = match digits.len() {
+            3 | 4 => (1, 17),
+            6 | 8 => (2, 1),
+            _ => return Err(err()),
+        };
+        let mut components = [255; 4];
+        for (i, component) in components.iter_mut().enumerate().take(digits.len() / width) {
+            let value =
+                u8::from_str_radix(&digits[i * width..(i + 1) * width], 16).map_err(|_| err())?;
+            *component = value * scale;
+        }
+        let [r, g, b, a] = components;
+        Ok(Self::from_rgba8(r, g, b, a))
+    }
+
+    /// Returns the named color, or `None` if the name is unknown.
+    ///
+    /// The names are the CSS basic color keywords, such as `red`, `navy` and `teal`, plus `orange`,
+    /// and are case-insensitive.
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMED_COLORS
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|&(_, rgb)| {
+                let [_, r, g, b] = rgb.to_be_bytes();
+                Self::from_rgb8(r, g, b)
+            })
+    }
+
+    /// Returns the color with the given alpha.
+    #[must_use]
+    pub fn with_alpha(&self, a: f64) -> Self {
+        Self { a, ..*self }
+    }
+}
+
+/// Parses a hex color with [`Color::from_hex`], or a color name with [`Color::from_name`].
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(color) = Self::from_name(s) {
+            return Ok(color);
+        }
+        Self::from_hex(s)
+    }
+}
+
+/// Components are in the order red, green, blue, alpha, in the range 0 to 1.
+impl From<[f64; 4]> for Color {
+    fn from([r, g, b, a]: [f64; 4]) -> Self {
+        Self::rgba(r, g, b, a)
+    }
+}
+
+/// Components are in the order red, green, blue, in the range 0 to 1. The color is opaque.
+impl From<[f64; 3]> for Color {
+    fn from([r, g, b]: [f64; 3]) -> Self {
+        Self::rgb(r, g, b)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::schemas::{Color, ImageAnnotations, Point2, PointsAnnotation};
+
+#[test]
+fn test_from_hex() {
+    assert_eq!(Color::from_hex("#ff0000"), Ok(Color::RED));
+    assert_eq!(Color::from_hex("00f"), Ok(Color::BLUE));
+    assert_eq!(
+        Color::from_hex("#ffff0000"),
+        Ok(Color::YELLOW.with_alpha(0.0))
+    );
+    assert_eq!(
+        Color::from_hex("#0f08"),
+        Ok(Color::from_rgba8(0, 255, 0, 136))
+    );
+    assert_eq!(Color::from_hex("#336699"), Ok(Color::rgb(0.2, 0.4, 0.6)));
+    for invalid in [
+        "", "#", "#12345", "#ggg", "#ff00zz", "#éé", "#+f+f+f", "+fff", "#-1-1-1",
+    ] {
+        assert!(Color::from_hex(invalid).is_err(), "{invalid}");
+    }
+}
+
+#[test]
+fn test_from_name() {
+    assert_eq!(Color::from_name("Red"), Some(Color::RED));
+    assert_eq!(Color::from_name("navy"), Some(Color::from_rgb8(0, 0, 128)));
+    assert_eq!(Color::from_name("chartreuse"), None);
+
+    assert_eq!("cyan".parse(), Ok(Color::CYAN));
+    assert_eq!("#000".parse(), Ok(Color::BLACK));
+    let err = "bogus".parse::<Color>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid color \"bogus\": expected a hex color such as #ff8800, or a color name"
+    );
+}
+
+#[test]
+fn test_annotation_setters() {
+    let annotations = ImageAnnotations::default().with_points([PointsAnnotation::default()
+        .with_points([[0.0, 0.0], [10.0, 5.0]])
+        .with_outline_color([0.0, 1.0, 0.0])
+        .with_thickness(2.0)]);
+    let points = &annotations.points[0];
+    assert_eq!(
+        points.points,
+        [Point2 { x: 0.0, y: 0.0 }, Point2 { x: 10.0, y: 5.0 }]
+    );
+    assert_eq!(points.outline_color, Some(Color::GREEN));
+    assert_eq!(points.thickness, 2.0);
+}
@@ -0,0 +1,116 @@
+//! Conversions from geo-types geometries and geojson values.
+//!
+//! Geometries and GeoJSON values convert to [`GeoJson`] with [`From`]. A string can be parsed
+//! into a [`GeoJson`] with [`FromStr`], which checks that it is valid GeoJSON.
+
+use std::str::FromStr;
+
+use geo_types::CoordFloat;
+
+use crate::schemas::{GeoJson, LocationFix};
+
+#[cfg(test)]
+mod tests;
+
+/// An error parsing GeoJSON data.
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid GeoJSON: {0}")]
+pub struct ParseGeoJsonError(#[source] Box<geojson::Error>);
+
+impl From<geojson::Error> for ParseGeoJsonError {
+    fn from(err: geojson::Error) -> Self {
+        Self(Box::new(err))
+    }
+}
+
+impl GeoJson {
+    /// Parses the GeoJSON data.
+    pub fn parse(&self) -> Result<geojson::GeoJson, ParseGeoJsonError> {
+        Ok(self.geojson.parse()?)
+    }
+}
+
+/// Parses a GeoJSON string, and returns an error if it is not valid GeoJSON.
+impl FromStr for GeoJson {
+    type Err = ParseGeoJsonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        geojson::GeoJson::from_str(s)?;
+        Ok(Self {
+            geojson: s.to_string(),
+        })
+    }
+}
+
+impl From<geojson::GeoJson> for GeoJson {
+    fn from(value: geojson::GeoJson) -> Self {
+        Self {
+            geojson: value.to_string(),
+        }
+    }
+}
+
+impl From<geojson::Geometry> for GeoJson {
+    fn from(value: geojson::Geometry) -> Self {
+        geojson::GeoJson::from(value).into()
+    }
+}
+
+impl From<geojson::Value> for GeoJson {
+    fn from(value: geojson::Value) -> Self {
+        geojson::Geometry::new(value).into()
+    }
+}
+
+impl From<geojson::Feature> for GeoJson {
+    fn from(value: geojson::Feature) -> Self {
+        geojson::GeoJson::from(value).into()
+    }
+}
+
+impl From<geojson::FeatureCollection> for GeoJson {
+    fn from(value: geojson::FeatureCollection) -> Self {
+        geojson::GeoJson::from(value).into()
+    }
+}
+
+/// Implements `From` for `GeoJson` for a geo-types geometry, by reference and by value.
+macro_rules! impl_from_geometry {
+    ($($ty:ident),*) => {
+        $(
+            impl<T: CoordFloat> From<&geo_types::$ty<T>> for GeoJson {
+                fn from(geometry: &geo_types::$ty<T>) -> Self {
+                    geojson::Value::from(geometry).into()
+                }
+            }
+
+            impl<T: CoordFloat> From<geo_types::$ty<T>> for GeoJson {
+                fn from(geometry: geo_types::$ty<T>) -> Self {
+                    Self::from(&geometry)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_geometry!(
+    Geometry,
+    GeometryCollection,
+    Line,
+    LineString,
+    MultiLineString,
+    MultiPoint,
+    MultiPolygon,
+    Point,
+    Polygon,
+    Rect,
+    Triangle
+);
+
+/// The point's x and y coordinates are the longitude and latitude in degrees. The altitude is zero.
+impl<T: CoordFloat> From<geo_types::Point<T>> for LocationFix {
+    fn from(point: geo_types::Point<T>) -> Self {
+        let coord = |v: T| v.to_f64().unwrap_or(f64::NAN);
+        Self::new(coord(point.y()), coord(point.x()), 0.0)
+    }
+}
@@ -0,0 +1,50 @@
+use geo_types::{line_string, point, polygon, Geometry};
+
+use crate::schemas::{GeoJson, LocationFix};
+
+#[test]
+fn test_geojson_from_geometry() {
+    let msg = GeoJson::from(point! { x: -122.3, y: 47.6 });
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&msg.geojson).unwrap(),
+        serde_json::json!({"type": "Point", "coordinates": [-122.3, 47.6]})
+    );
+
+    let line = line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)];
+    let msg = GeoJson::from(&line);
+    assert_eq!(
+        msg.parse().unwrap(),
+        geojson::GeoJson::from(geojson::Geometry::new(geojson::Value::from(&line)))
+    );
+
+    let polygon: Geometry = polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 0.0, y: 1.0)].into();
+    let msg = GeoJson::from(&polygon);
+    match msg.parse().unwrap() {
+        geojson::GeoJson::Geometry(geometry) => {
+            assert_eq!(Geometry::<f64>::try_from(geometry).unwrap(), polygon);
+        }
+        other => panic!("unexpected GeoJSON {other:?}"),
+    }
+}
+
+#[test]
+fn test_geojson_from_str() {
+    let s = r#"{"type": "FeatureCollection", "features": []}"#;
+    let msg: GeoJson = s.parse().unwrap();
+    assert_eq!(msg.geojson, s);
+    assert!(matches!(
+        msg.parse().unwrap(),
+        geojson::GeoJson::FeatureCollection(_)
+    ));
+
+    assert!("{}".parse::<GeoJson>().is_err());
+    assert!(r#"{"type": "Point"}"#.parse::<GeoJson>().is_err());
+}
+
+#[test]
+fn test_location_fix_from_point() {
+    let fix = LocationFix::from(point! { x: -122.3, y: 47.6 });
+    assert_eq!(fix.latitude, 47.6);
+    assert_eq!(fix.longitude, -122.3);
+    assert_eq!(fix.altitude, 0.0);
+}
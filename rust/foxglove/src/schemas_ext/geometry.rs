@@ -0,0 +1,294 @@
+//! Quaternion and pose math.
+//!
+//! Absent fields of a [`Pose`] are treated as the identity: the origin for the position, and no
+//! rotation for the orientation.
+
+use std::ops::Mul;
+
+use crate::schemas::{Point2, Point3, Pose, Quaternion, Vector2, Vector3};
+
+#[cfg(test)]
+mod tests;
+
+impl Vector3 {
+    /// Creates a new vector.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the Euclidean length of the vector.
+    pub fn norm(&self) -> f64 {
+        self.x.hypot(self.y).hypot(self.z)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+
+    fn scale(&self, s: f64) -> Self {
+        Self::new(self.x * s, self.y * s, self.z * s)
+    }
+
+    fn cross(&self, other: &Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+impl Point3 {
+    /// Creates a new point.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl From<[f64; 2]> for Vector2 {
+    fn from([x, y]: [f64; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<[f64; 3]> for Vector3 {
+    fn from([x, y, z]: [f64; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<[f64; 2]> for Point2 {
+    fn from([x, y]: [f64; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<[f64; 3]> for Point3 {
+    fn from([x, y, z]: [f64; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl Quaternion {
+    /// Creates a new quaternion.
+    pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Returns the identity rotation.
+    ///
+    /// Note that this differs from [`Quaternion::default`], which is all zeros.
+    pub fn identity() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Creates a rotation from Euler angles in radians.
+    ///
+    /// The rotation is about the fixed X axis by `roll`, then the fixed Y axis by `pitch`, then
+    /// the fixed Z axis by `yaw`. This is the same convention as ROS.
+    pub fn from_euler(roll: f64, pitch: f64, yaw: f64) -> Self {
+        let (sr, cr) = (roll / 2.0).sin_cos();
+        let (sp, cp) = (pitch / 2.0).sin_cos();
+        let (sy, cy) = (yaw / 2.0).sin_cos();
+        Self::new(
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+            cr * cp * cy + sr * sp * sy,
+        )
+    }
+
+    /// Returns the Euler angles `(roll, pitch, yaw)` in radians, using the convention of
+    /// [`Quaternion::from_euler`].
+    pub fn to_euler(&self) -> (f64, f64, f64) {
+        let q = self.normalize();
+        let roll = (2.0 * (q.w * q.x + q.y * q.z)).atan2(1.0 - 2.0 * (q.x * q.x + q.y * q.y));
+        let pitch = (2.0 * (q.w * q.y - q.z * q.x)).clamp(-1.0, 1.0).asin();
+        let yaw = (2.0 * (q.w * q.z + q.x * q.y)).atan2(1.0 - 2.0 * (q.y * q.y + q.z * q.z));
+        (roll, pitch, yaw)
+    }
+
+    /// Creates a rotation by `angle` radians about `axis`.
+    ///
+    /// The axis does not need to be normalized. If it is zero, the identity is returned.
+    pub fn from_axis_angle(axis: &Vector3, angle: f64) -> Self {
+        let norm = axis.norm();
+        if norm == 0.0 {
+            return Self::identity();
+        }
+        let (s, c) = (angle / 2.0).sin_cos();
+        let v = axis.scale(s / norm);
+        Self::new(v.x, v.y, v.z, c)
+    }
+
+    /// Returns the norm of the quaternion.
+    pub fn norm(&self) -> f64 {
+        self.x.hypot(self.y).hypot(self.z).hypot(self.w)
+    }
+
+    /// Returns the quaternion scaled to unit length.
+    ///
+    /// If the quaternion is zero, the identity is returned.
+    #[must_use]
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        if norm == 0.0 {
+            return Self::identity();
+        }
+        Self::new(self.x / norm, self.y / norm, self.z / norm, self.w / norm)
+    }
+
+    /// Returns the conjugate of the quaternion, which is its inverse if it has unit length.
+    #[must_use]
+    pub fn conjugate(&self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the inverse of the quaternion.
+    ///
+    /// If the quaternion is zero, the identity is returned.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let norm_sq = self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w;
+        if norm_sq == 0.0 {
+            return Self::identity();
+        }
+        let c = self.conjugate();
+        Self::new(c.x / norm_sq, c.y / norm_sq, c.z / norm_sq, c.w / norm_sq)
+    }
+
+    /// Returns the spherical linear interpolation between two rotations, where `t` is 0 for
+    /// `self` and 1 for `other`.
+    ///
+    /// Both quaternions are normalized, and the interpolation follows the shortest path.
+    #[must_use]
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Self {
+        let a = self.normalize();
+        let mut b = other.normalize();
+        let mut dot = a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+        if dot < 0.0 {
+            b = Self::new(-b.x, -b.y, -b.z, -b.w);
+            dot = -dot;
+        }
+        // Fall back to linear interpolation when the rotations are nearly identical, where the
+        // spherical weights are numerically unstable.
+        let (wa, wb) = if dot > 0.9995 {
+            (1.0 - t, t)
+        } else {
+            let theta = dot.acos();
+            let sin = theta.sin();
+            (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+        Self::new(
+            wa * a.x + wb * b.x,
+            wa * a.y + wb * b.y,
+            wa * a.z + wb * b.z,
+            wa * a.w + wb * b.w,
+        )
+        .normalize()
+    }
+
+    /// Rotates a vector by the quaternion.
+    ///
+    /// The quaternion is normalized, so that a quaternion which is not of unit length rotates the
+    /// vector without scaling it.
+    pub fn rotate(&self, v: &Vector3) -> Vector3 {
+        let q = self.normalize();
+        // v' = v + 2w(u × v) + 2u × (u × v), where u is the vector part.
+        let u = Vector3::new(q.x, q.y, q.z);
+        let t = u.cross(v).scale(2.0);
+        v.add(&t.scale(q.w)).add(&u.cross(&t))
+    }
+}
+
+/// The Hamilton product. The result applies the right-hand rotation first, then the left.
+impl Mul for Quaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+impl Pose {
+    /// Creates a new pose.
+    pub fn new(position: Vector3, orientation: Quaternion) -> Self {
+        Self {
+            position: Some(position),
+            orientation: Some(orientation),
+        }
+    }
+
+    /// Returns the identity pose.
+    pub fn identity() -> Self {
+        Self::new(Vector3::default(), Quaternion::identity())
+    }
+
+    /// Returns the position, or the origin if absent.
+    fn position_or_origin(&self) -> Vector3 {
+        self.position.unwrap_or_default()
+    }
+
+    /// Returns the normalized orientation, or the identity if absent.
+    fn orientation_or_identity(&self) -> Quaternion {
+        self.orientation
+            .map_or_else(Quaternion::identity, |q| q.normalize())
+    }
+
+    /// Returns the composition of this pose with another.
+    ///
+    /// If this pose is the pose of frame B in frame A, and `other` is the pose of frame C in frame
+    /// B, the result is the pose of frame C in frame A.
+    ///
+    /// The orientations are normalized, so the orientation of the result has unit length.
+    #[must_use]
+    pub fn compose(&self, other: &Pose) -> Self {
+        let q = self.orientation_or_identity();
+        let position = self
+            .position_or_origin()
+            .add(&q.rotate(&other.position_or_origin()));
+        Self::new(position, q * other.orientation_or_identity())
+    }
+
+    /// Returns the inverse of the pose, such that composing a pose with its inverse yields the
+    /// identity.
+    ///
+    /// The orientation is normalized, so the orientation of the result has unit length.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let q = self.orientation_or_identity().inverse();
+        let position = q.rotate(&self.position_or_origin()).scale(-1.0);
+        Self::new(position, q)
+    }
+
+    /// Returns the interpolation between two poses, where `t` is 0 for `self` and 1 for `other`.
+    ///
+    /// The position is interpolated linearly, and the orientation with [`Quaternion::slerp`].
+    #[must_use]
+    pub fn interpolate(&self, other: &Pose, t: f64) -> Self {
+        let a = self.position_or_origin();
+        let b = other.position_or_origin();
+        let position = a.scale(1.0 - t).add(&b.scale(t));
+        let orientation = self
+            .orientation_or_identity()
+            .slerp(&other.orientation_or_identity(), t);
+        Self::new(position, orientation)
+    }
+
+    /// Transforms a point from the frame of the pose to the frame in which the pose is defined.
+    ///
+    /// The orientation is normalized before it is applied.
+    pub fn transform_point(&self, point: &Point3) -> Point3 {
+        let v = Vector3::new(point.x, point.y, point.z);
+        let v = self
+            .orientation_or_identity()
+            .rotate(&v)
+            .add(&self.position_or_origin());
+        Point3::new(v.x, v.y, v.z)
+    }
+}
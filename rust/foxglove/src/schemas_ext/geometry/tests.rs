@@ -0,0 +1,201 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+use crate::schemas::{Point3, Pose, Quaternion, Vector3};
+
+const EPSILON: f64 = 1e-12;
+
+fn assert_vector_eq(actual: Vector3, expected: Vector3) {
+    let diff = Vector3::new(
+        actual.x - expected.x,
+        actual.y - expected.y,
+        actual.z - expected.z,
+    );
+    assert!(diff.norm() < EPSILON, "{actual:?} != {expected:?}");
+}
+
+fn assert_point_eq(actual: Point3, expected: Point3) {
+    assert_vector_eq(
+        Vector3::new(actual.x, actual.y, actual.z),
+        Vector3::new(expected.x, expected.y, expected.z),
+    );
+}
+
+/// Asserts that two quaternions represent the same rotation.
+fn assert_rotation_eq(actual: Quaternion, expected: Quaternion) {
+    let dot = actual.x * expected.x
+        + actual.y * expected.y
+        + actual.z * expected.z
+        + actual.w * expected.w;
+    assert!(
+        (dot.abs() - 1.0).abs() < EPSILON,
+        "{actual:?} != {expected:?}"
+    );
+}
+
+#[test]
+fn test_from_euler() {
+    let q = Quaternion::from_euler(0.0, 0.0, FRAC_PI_2);
+    assert_rotation_eq(
+        q,
+        Quaternion::new(0.0, 0.0, FRAC_PI_4.sin(), FRAC_PI_4.cos()),
+    );
+    assert_vector_eq(
+        q.rotate(&Vector3::new(1.0, 0.0, 0.0)),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+
+    // Roll is applied first, then pitch, then yaw, about the fixed axes.
+    let (roll, pitch, yaw) = (0.1, -0.2, 0.3);
+    let q = Quaternion::from_euler(roll, pitch, yaw);
+    let x = Vector3::new(1.0, 0.0, 0.0);
+    let y = Vector3::new(0.0, 1.0, 0.0);
+    let z = Vector3::new(0.0, 0.0, 1.0);
+    let composed = Quaternion::from_axis_angle(&z, yaw)
+        * Quaternion::from_axis_angle(&y, pitch)
+        * Quaternion::from_axis_angle(&x, roll);
+    assert_rotation_eq(q, composed);
+
+    let (r, p, y) = q.to_euler();
+    assert!((r - roll).abs() < EPSILON);
+    assert!((p - pitch).abs() < EPSILON);
+    assert!((y - yaw).abs() < EPSILON);
+}
+
+#[test]
+fn test_from_axis_angle() {
+    // The axis is normalized.
+    let q = Quaternion::from_axis_angle(&Vector3::new(0.0, 2.0, 0.0), PI);
+    assert_rotation_eq(q, Quaternion::new(0.0, 1.0, 0.0, 0.0));
+    assert!((q.norm() - 1.0).abs() < EPSILON);
+    assert_vector_eq(
+        q.rotate(&Vector3::new(1.0, 0.0, 0.0)),
+        Vector3::new(-1.0, 0.0, 0.0),
+    );
+
+    assert_eq!(
+        Quaternion::from_axis_angle(&Vector3::default(), 1.0),
+        Quaternion::identity()
+    );
+}
+
+#[test]
+fn test_normalize_and_inverse() {
+    let q = Quaternion::new(0.0, 0.0, 3.0, 4.0);
+    assert_eq!(q.norm(), 5.0);
+    assert_eq!(q.normalize(), Quaternion::new(0.0, 0.0, 0.6, 0.8));
+    assert_eq!(Quaternion::default().normalize(), Quaternion::identity());
+
+    let product = q * q.inverse();
+    assert_rotation_eq(product, Quaternion::identity());
+    assert!((product.w - 1.0).abs() < EPSILON);
+
+    let unit = q.normalize();
+    assert_rotation_eq(unit.conjugate(), unit.inverse());
+}
+
+#[test]
+fn test_compose_and_inverse() {
+    let a = Pose::new(
+        Vector3::new(1.0, 0.0, 0.0),
+        Quaternion::from_euler(0.0, 0.0, FRAC_PI_2),
+    );
+    let b = Pose::new(
+        Vector3::new(0.0, 2.0, 0.0),
+        Quaternion::from_euler(0.0, FRAC_PI_2, 0.0),
+    );
+    let c = a.compose(&b);
+    // b's position is rotated by a's orientation, then translated.
+    assert_vector_eq(c.position.unwrap(), Vector3::new(-1.0, 0.0, 0.0));
+    assert_rotation_eq(
+        c.orientation.unwrap(),
+        a.orientation.unwrap() * b.orientation.unwrap(),
+    );
+
+    // Transforming by the composition is the same as transforming by each pose in turn.
+    let p = Point3::new(1.0, 2.0, 3.0);
+    assert_point_eq(
+        c.transform_point(&p),
+        a.transform_point(&b.transform_point(&p)),
+    );
+
+    for pose in [a, b, c] {
+        let identity = pose.compose(&pose.inverse());
+        assert_vector_eq(identity.position.unwrap(), Vector3::default());
+        assert_rotation_eq(identity.orientation.unwrap(), Quaternion::identity());
+        let identity = pose.inverse().compose(&pose);
+        assert_vector_eq(identity.position.unwrap(), Vector3::default());
+    }
+}
+
+#[test]
+fn test_non_unit_orientation() {
+    // A rotation of 90 degrees about z, scaled by 2.
+    let scaled = Quaternion::new(0.0, 0.0, 2.0 * FRAC_PI_4.sin(), 2.0 * FRAC_PI_4.cos());
+    let unit = scaled.normalize();
+    assert_vector_eq(
+        scaled.rotate(&Vector3::new(1.0, 0.0, 0.0)),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+
+    let pose = Pose::new(Vector3::new(1.0, 0.0, 0.0), scaled);
+    let unit_pose = Pose::new(Vector3::new(1.0, 0.0, 0.0), unit);
+    let p = Point3::new(1.0, 2.0, 3.0);
+    assert_point_eq(pose.transform_point(&p), unit_pose.transform_point(&p));
+    assert_point_eq(pose.transform_point(&p), Point3::new(-1.0, 1.0, 3.0));
+
+    let composed = pose.compose(&pose);
+    assert!((composed.orientation.unwrap().norm() - 1.0).abs() < EPSILON);
+    assert_vector_eq(
+        composed.position.unwrap(),
+        unit_pose.compose(&unit_pose).position.unwrap(),
+    );
+
+    let inverse = pose.inverse();
+    assert!((inverse.orientation.unwrap().norm() - 1.0).abs() < EPSILON);
+    assert_point_eq(inverse.transform_point(&pose.transform_point(&p)), p);
+    let identity = pose.compose(&inverse);
+    assert_vector_eq(identity.position.unwrap(), Vector3::default());
+    assert_rotation_eq(identity.orientation.unwrap(), Quaternion::identity());
+}
+
+#[test]
+fn test_absent_fields_are_identity() {
+    let pose = Pose::default();
+    let p = Point3::new(1.0, 2.0, 3.0);
+    assert_eq!(pose.transform_point(&p), p);
+    assert_eq!(pose.inverse(), Pose::identity());
+
+    let translated = Pose {
+        position: Some(Vector3::new(1.0, 0.0, 0.0)),
+        orientation: None,
+    };
+    assert_eq!(translated.transform_point(&p), Point3::new(2.0, 2.0, 3.0));
+}
+
+#[test]
+fn test_interpolate() {
+    let z = Vector3::new(0.0, 0.0, 1.0);
+    let a = Pose::new(Vector3::new(0.0, 0.0, 0.0), Quaternion::identity());
+    let b = Pose::new(
+        Vector3::new(2.0, 4.0, 0.0),
+        Quaternion::from_axis_angle(&z, FRAC_PI_2),
+    );
+    let mid = a.interpolate(&b, 0.5);
+    assert_vector_eq(mid.position.unwrap(), Vector3::new(1.0, 2.0, 0.0));
+    assert_rotation_eq(
+        mid.orientation.unwrap(),
+        Quaternion::from_axis_angle(&z, FRAC_PI_4),
+    );
+    assert_rotation_eq(
+        a.interpolate(&b, 1.0).orientation.unwrap(),
+        b.orientation.unwrap(),
+    );
+
+    // The shortest path is taken, even if the quaternions are in opposite hemispheres.
+    let q = Quaternion::from_axis_angle(&z, 0.2);
+    let negated = Quaternion::new(-q.x, -q.y, -q.z, -q.w);
+    assert_rotation_eq(
+        Quaternion::identity().slerp(&negated, 0.5),
+        Quaternion::from_axis_angle(&z, 0.1),
+    );
+}
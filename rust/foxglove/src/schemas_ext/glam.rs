@@ -0,0 +1,147 @@
+//! Conversions to and from glam types.
+//!
+//! The double-precision types convert in both directions. The single-precision types convert to
+//! the schema types only, since converting back would lose precision.
+//!
+//! Absent fields of a [`Pose`] are treated as the identity: the origin for the position, and no
+//! rotation for the orientation.
+
+use crate::schemas::{Point2, Point3, Pose, Quaternion, Vector2, Vector3};
+
+#[cfg(test)]
+mod tests;
+
+impl From<glam::DVec2> for Vector2 {
+    fn from(v: glam::DVec2) -> Self {
+        Self { x: v.x, y: v.y }
+    }
+}
+
+impl From<Vector2> for glam::DVec2 {
+    fn from(v: Vector2) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl From<glam::Vec2> for Vector2 {
+    fn from(v: glam::Vec2) -> Self {
+        v.as_dvec2().into()
+    }
+}
+
+impl From<glam::DVec2> for Point2 {
+    fn from(v: glam::DVec2) -> Self {
+        Self { x: v.x, y: v.y }
+    }
+}
+
+impl From<Point2> for glam::DVec2 {
+    fn from(p: Point2) -> Self {
+        Self::new(p.x, p.y)
+    }
+}
+
+impl From<glam::Vec2> for Point2 {
+    fn from(v: glam::Vec2) -> Self {
+        v.as_dvec2().into()
+    }
+}
+
+impl From<glam::DVec3> for Vector3 {
+    fn from(v: glam::DVec3) -> Self {
+        Self {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<Vector3> for glam::DVec3 {
+    fn from(v: Vector3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<glam::Vec3> for Vector3 {
+    fn from(v: glam::Vec3) -> Self {
+        v.as_dvec3().into()
+    }
+}
+
+impl From<glam::Vec3A> for Vector3 {
+    fn from(v: glam::Vec3A) -> Self {
+        v.as_dvec3().into()
+    }
+}
+
+impl From<glam::DVec3> for Point3 {
+    fn from(v: glam::DVec3) -> Self {
+        Self {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<Point3> for glam::DVec3 {
+    fn from(p: Point3) -> Self {
+        Self::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<glam::Vec3> for Point3 {
+    fn from(v: glam::Vec3) -> Self {
+        v.as_dvec3().into()
+    }
+}
+
+impl From<glam::Vec3A> for Point3 {
+    fn from(v: glam::Vec3A) -> Self {
+        v.as_dvec3().into()
+    }
+}
+
+impl From<glam::DQuat> for Quaternion {
+    fn from(q: glam::DQuat) -> Self {
+        Self {
+            x: q.x,
+            y: q.y,
+            z: q.z,
+            w: q.w,
+        }
+    }
+}
+
+/// The quaternion is not normalized.
+impl From<Quaternion> for glam::DQuat {
+    fn from(q: Quaternion) -> Self {
+        Self::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<glam::Quat> for Quaternion {
+    fn from(q: glam::Quat) -> Self {
+        q.as_dquat().into()
+    }
+}
+
+/// Any scale or shear in the transform is discarded.
+impl From<glam::DAffine3> for Pose {
+    fn from(affine: glam::DAffine3) -> Self {
+        let (_, rotation, translation) = affine.to_scale_rotation_translation();
+        Self {
+            position: Some(translation.into()),
+            orientation: Some(rotation.into()),
+        }
+    }
+}
+
+impl From<Pose> for glam::DAffine3 {
+    fn from(pose: Pose) -> Self {
+        let translation = pose.position.map_or(glam::DVec3::ZERO, Into::into);
+        let rotation = pose.orientation.map_or(glam::DQuat::IDENTITY, Into::into);
+        Self::from_rotation_translation(rotation, translation)
+    }
+}
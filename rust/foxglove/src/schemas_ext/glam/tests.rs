@@ -0,0 +1,60 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::schemas::{FrameTransform, Point2, Pose, Quaternion, Vector3};
+
+#[test]
+fn test_vectors_and_points() {
+    let v = Vector3::from(glam::DVec3::new(1.0, 2.0, 3.0));
+    assert_eq!(
+        v,
+        Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0
+        }
+    );
+    assert_eq!(glam::DVec3::from(v), glam::DVec3::new(1.0, 2.0, 3.0));
+
+    // Single-precision types are widened.
+    assert_eq!(Vector3::from(glam::Vec3::new(1.0, 2.0, 3.0)), v);
+    assert_eq!(Vector3::from(glam::Vec3A::new(1.0, 2.0, 3.0)), v);
+
+    let p = Point2::from(glam::Vec2::new(0.5, 1.5));
+    assert_eq!(p, Point2 { x: 0.5, y: 1.5 });
+    assert_eq!(glam::DVec2::from(p), glam::DVec2::new(0.5, 1.5));
+}
+
+#[test]
+fn test_quaternion() {
+    let rotation = glam::DQuat::from_rotation_z(FRAC_PI_2);
+    let q = Quaternion::from(rotation);
+    assert_eq!(q.z, rotation.z);
+    assert_eq!(q.w, rotation.w);
+    assert_eq!(glam::DQuat::from(q), rotation);
+}
+
+#[test]
+fn test_pose() {
+    let rotation = glam::DQuat::from_rotation_z(FRAC_PI_2);
+    let translation = glam::DVec3::new(1.0, 2.0, 3.0);
+    let affine = glam::DAffine3::from_rotation_translation(rotation, translation);
+    let pose = Pose::from(affine);
+    assert_eq!(pose.position, Some(translation.into()));
+    let orientation = glam::DQuat::from(pose.orientation.unwrap());
+    assert!(orientation.abs_diff_eq(rotation, 1e-12));
+    assert!(glam::DAffine3::from(pose).abs_diff_eq(affine, 1e-12));
+
+    // Scale is discarded.
+    let scaled = affine * glam::DAffine3::from_scale(glam::DVec3::splat(2.0));
+    assert_eq!(Pose::from(scaled).position, Some(translation.into()));
+
+    // Absent fields are the identity.
+    assert_eq!(
+        glam::DAffine3::from(Pose::default()),
+        glam::DAffine3::IDENTITY
+    );
+
+    let tf = FrameTransform::from_pose("world", "base_link", affine);
+    assert_eq!(tf.parent_frame_id, "world");
+    assert_eq!(tf.translation, Some(translation.into()));
+}
@@ -0,0 +1,130 @@
+//! Conversions from image crate types.
+//!
+//! Images convert to [`RawImage`] with [`From`], using the matching encoding where Foxglove
+//! supports one. [`CompressedImage::from_image`] encodes an image in a compressed format.
+
+use std::borrow::Cow;
+use std::io::Cursor;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::{
+    ColorType, DynamicImage, ImageBuffer, ImageError, ImageFormat, Luma, Pixel, Rgb, Rgba,
+};
+
+use crate::schemas::{CompressedImage, RawImage};
+
+#[cfg(test)]
+mod tests;
+
+/// Returns a raw image with the given dimensions, encoding and little-endian data.
+fn raw_image<P: Pixel>(width: u32, height: u32, encoding: &str, data: Vec<u8>) -> RawImage {
+    let bytes_per_pixel = u32::from(P::CHANNEL_COUNT) * size_of::<P::Subpixel>() as u32;
+    RawImage {
+        timestamp: None,
+        frame_id: String::new(),
+        width,
+        height,
+        encoding: encoding.to_string(),
+        step: width * bytes_per_pixel,
+        data: data.into(),
+    }
+}
+
+/// Implements `From<ImageBuffer>` for `RawImage` for a pixel type with 8-bit channels.
+macro_rules! impl_from_image_buffer_u8 {
+    ($pixel:ty, $encoding:literal) => {
+        impl From<ImageBuffer<$pixel, Vec<u8>>> for RawImage {
+            fn from(image: ImageBuffer<$pixel, Vec<u8>>) -> Self {
+                let (width, height) = image.dimensions();
+                raw_image::<$pixel>(width, height, $encoding, image.into_raw())
+            }
+        }
+    };
+}
+
+/// Implements `From<ImageBuffer>` for `RawImage` for a pixel type with wider channels, which are
+/// stored in little-endian byte order.
+macro_rules! impl_from_image_buffer_le {
+    ($pixel:ty, $subpixel:ty, $encoding:literal) => {
+        impl From<ImageBuffer<$pixel, Vec<$subpixel>>> for RawImage {
+            fn from(image: ImageBuffer<$pixel, Vec<$subpixel>>) -> Self {
+                let (width, height) = image.dimensions();
+                let data = image.iter().flat_map(|v| v.to_le_bytes()).collect();
+                raw_image::<$pixel>(width, height, $encoding, data)
+            }
+        }
+    };
+}
+
+impl_from_image_buffer_u8!(Luma<u8>, "mono8");
+impl_from_image_buffer_u8!(Rgb<u8>, "rgb8");
+impl_from_image_buffer_u8!(Rgba<u8>, "rgba8");
+impl_from_image_buffer_le!(Luma<u16>, u16, "mono16");
+impl_from_image_buffer_le!(Luma<f32>, f32, "32FC1");
+
+/// Images with an encoding that Foxglove supports are converted without loss. Other images are
+/// converted to `rgba8` if they have an alpha channel, and `rgb8` otherwise.
+impl From<DynamicImage> for RawImage {
+    fn from(image: DynamicImage) -> Self {
+        match image {
+            DynamicImage::ImageLuma8(image) => image.into(),
+            DynamicImage::ImageRgb8(image) => image.into(),
+            DynamicImage::ImageRgba8(image) => image.into(),
+            DynamicImage::ImageLuma16(image) => image.into(),
+            image if image.color().has_alpha() => image.into_rgba8().into(),
+            image => image.into_rgb8().into(),
+        }
+    }
+}
+
+impl CompressedImage {
+    /// Encodes an image in a compressed format, such as [`ImageFormat::Png`] or
+    /// [`ImageFormat::Jpeg`].
+    ///
+    /// The PNG and JPEG encoders are always available. Other formats can be used by enabling the
+    /// corresponding features of the `image` crate. Images that can't be represented in JPEG,
+    /// such as those with an alpha channel, are converted to 8-bit RGB or grayscale.
+    pub fn from_image(image: &DynamicImage, format: ImageFormat) -> Result<Self, ImageError> {
+        let mut data = Vec::new();
+        if format == ImageFormat::Jpeg {
+            jpeg_compatible(image).write_to(Cursor::new(&mut data), format)?;
+        } else {
+            image.write_to(Cursor::new(&mut data), format)?;
+        }
+        Ok(Self::from_encoded(data, format))
+    }
+
+    /// Encodes an image as JPEG, with a quality between 1 and 100.
+    ///
+    /// See [`CompressedImage::from_image`].
+    pub fn from_image_jpeg(image: &DynamicImage, quality: u8) -> Result<Self, ImageError> {
+        let mut data = Vec::new();
+        let encoder = JpegEncoder::new_with_quality(&mut data, quality);
+        jpeg_compatible(image).write_with_encoder(encoder)?;
+        Ok(Self::from_encoded(data, ImageFormat::Jpeg))
+    }
+
+    fn from_encoded(data: Vec<u8>, format: ImageFormat) -> Self {
+        let mime_type = format.to_mime_type();
+        Self {
+            timestamp: None,
+            frame_id: String::new(),
+            data: data.into(),
+            format: mime_type
+                .strip_prefix("image/")
+                .unwrap_or(mime_type)
+                .to_string(),
+        }
+    }
+}
+
+/// Converts an image to 8-bit grayscale or RGB, unless it already is.
+fn jpeg_compatible(image: &DynamicImage) -> Cow<'_, DynamicImage> {
+    match image.color() {
+        ColorType::L8 | ColorType::Rgb8 => Cow::Borrowed(image),
+        ColorType::La8 | ColorType::L16 | ColorType::La16 => {
+            Cow::Owned(DynamicImage::ImageLuma8(image.to_luma8()))
+        }
+        _ => Cow::Owned(DynamicImage::ImageRgb8(image.to_rgb8())),
+    }
+}
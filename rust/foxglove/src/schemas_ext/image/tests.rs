@@ -0,0 +1,64 @@
+use image::{DynamicImage, GrayImage, ImageBuffer, ImageFormat, Luma, Rgb, RgbaImage};
+
+use crate::schemas::{CompressedImage, RawImage};
+
+#[test]
+fn test_raw_image_from_image_buffer() {
+    let image = RgbaImage::from_fn(3, 2, |x, y| image::Rgba([x as u8, y as u8, 0, 255]));
+    let raw = RawImage::from(image.clone());
+    assert_eq!(raw.width, 3);
+    assert_eq!(raw.height, 2);
+    assert_eq!(raw.encoding, "rgba8");
+    assert_eq!(raw.step, 12);
+    assert_eq!(raw.data, image.into_raw());
+
+    let image: ImageBuffer<Luma<u16>, _> =
+        ImageBuffer::from_raw(2, 1, vec![0x0102, 0x0304]).unwrap();
+    let raw = RawImage::from(image);
+    assert_eq!(raw.encoding, "mono16");
+    assert_eq!(raw.step, 4);
+    assert_eq!(raw.data.as_ref(), [0x02, 0x01, 0x04, 0x03]);
+
+    let image: ImageBuffer<Luma<f32>, _> = ImageBuffer::from_raw(1, 1, vec![1.5]).unwrap();
+    let raw = RawImage::from(image);
+    assert_eq!(raw.encoding, "32FC1");
+    assert_eq!(raw.data.as_ref(), 1.5f32.to_le_bytes());
+}
+
+#[test]
+fn test_raw_image_from_dynamic_image() {
+    let raw = RawImage::from(DynamicImage::new_luma8(4, 3));
+    assert_eq!(raw.encoding, "mono8");
+    assert_eq!(raw.step, 4);
+    assert_eq!(raw.data.len(), 12);
+
+    // Encodings that Foxglove doesn't support are converted.
+    let raw = RawImage::from(DynamicImage::new_rgb16(4, 3));
+    assert_eq!(raw.encoding, "rgb8");
+    assert_eq!(raw.step, 12);
+    let raw = RawImage::from(DynamicImage::new_luma_a8(4, 3));
+    assert_eq!(raw.encoding, "rgba8");
+    assert_eq!(raw.step, 16);
+}
+
+#[test]
+fn test_compressed_image() {
+    let image = DynamicImage::ImageRgb8(ImageBuffer::from_pixel(8, 8, Rgb([200, 100, 50])));
+
+    let png = CompressedImage::from_image(&image, ImageFormat::Png).unwrap();
+    assert_eq!(png.format, "png");
+    let decoded = image::load_from_memory_with_format(&png.data, ImageFormat::Png).unwrap();
+    assert_eq!(decoded, image);
+
+    let jpeg = CompressedImage::from_image_jpeg(&image, 90).unwrap();
+    assert_eq!(jpeg.format, "jpeg");
+    let decoded = image::load_from_memory_with_format(&jpeg.data, ImageFormat::Jpeg).unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (8, 8));
+
+    // Images with an alpha channel can be encoded as JPEG.
+    let rgba = DynamicImage::new_rgba8(8, 8);
+    let jpeg = CompressedImage::from_image(&rgba, ImageFormat::Jpeg).unwrap();
+    assert_eq!(jpeg.format, "jpeg");
+    let gray = DynamicImage::ImageLuma8(GrayImage::new(8, 8));
+    CompressedImage::from_image(&gray, ImageFormat::Jpeg).unwrap();
+}
@@ -0,0 +1,58 @@
+//! Constructors for [`LocationFix`].
+
+use crate::schemas::location_fix::PositionCovarianceType;
+use crate::schemas::LocationFix;
+
+#[cfg(test)]
+mod tests;
+
+impl LocationFix {
+    /// Creates a location fix at the given latitude and longitude in degrees, and altitude in
+    /// meters. The covariance is unknown.
+    pub fn new(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            altitude,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the location fix with the given position covariance, in m², and covariance type.
+    ///
+    /// The covariance is relative to a tangential plane through the reported position, with rows
+    /// and columns in the order east, north, up.
+    #[must_use]
+    pub fn with_position_covariance(
+        mut self,
+        covariance: [[f64; 3]; 3],
+        covariance_type: PositionCovarianceType,
+    ) -> Self {
+        self.position_covariance = covariance.into_iter().flatten().collect();
+        self.set_position_covariance_type(covariance_type);
+        self
+    }
+
+    /// Returns the location fix with a diagonal position covariance, from the variances in m² of
+    /// the east, north and up components.
+    #[must_use]
+    pub fn with_diagonal_covariance(self, east: f64, north: f64, up: f64) -> Self {
+        self.with_position_covariance(
+            [[east, 0.0, 0.0], [0.0, north, 0.0], [0.0, 0.0, up]],
+            PositionCovarianceType::DiagonalKnown,
+        )
+    }
+
+    /// Returns the position covariance as a matrix, with rows and columns in the order east,
+    /// north, up.
+    ///
+    /// Returns `None` if the covariance type is unknown, or the covariance does not have nine
+    /// elements.
+    pub fn position_covariance_matrix(&self) -> Option<[[f64; 3]; 3]> {
+        if self.position_covariance_type() == PositionCovarianceType::Unknown {
+            return None;
+        }
+        let c: &[f64; 9] = self.position_covariance.as_slice().try_into().ok()?;
+        Some([[c[0], c[1], c[2]], [c[3], c[4], c[5]], [c[6], c[7], c[8]]])
+    }
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn test_covariance() {
+    let fix = LocationFix::new(47.6, -122.3, 10.0);
+    assert_eq!(
+        fix.position_covariance_type(),
+        PositionCovarianceType::Unknown
+    );
+    assert_eq!(fix.position_covariance_matrix(), None);
+
+    let fix = fix.with_diagonal_covariance(1.0, 2.0, 3.0);
+    assert_eq!(
+        fix.position_covariance_type(),
+        PositionCovarianceType::DiagonalKnown
+    );
+    assert_eq!(
+        fix.position_covariance,
+        [1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0]
+    );
+    assert_eq!(
+        fix.position_covariance_matrix(),
+        Some([[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]])
+    );
+    assert_eq!(
+        (fix.latitude, fix.longitude, fix.altitude),
+        (47.6, -122.3, 10.0)
+    );
+}
@@ -0,0 +1,124 @@
+//! Conversions to and from nalgebra types.
+//!
+//! Absent fields of a [`Pose`] are treated as the identity: the origin for the position, and no
+//! rotation for the orientation.
+
+use crate::schemas::{Point2, Point3, Pose, Quaternion, Vector2, Vector3};
+
+#[cfg(test)]
+mod tests;
+
+impl From<nalgebra::Vector2<f64>> for Vector2 {
+    fn from(v: nalgebra::Vector2<f64>) -> Self {
+        Self { x: v.x, y: v.y }
+    }
+}
+
+impl From<Vector2> for nalgebra::Vector2<f64> {
+    fn from(v: Vector2) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl From<nalgebra::Point2<f64>> for Point2 {
+    fn from(p: nalgebra::Point2<f64>) -> Self {
+        Self { x: p.x, y: p.y }
+    }
+}
+
+impl From<Point2> for nalgebra::Point2<f64> {
+    fn from(p: Point2) -> Self {
+        Self::new(p.x, p.y)
+    }
+}
+
+impl From<nalgebra::Vector3<f64>> for Vector3 {
+    fn from(v: nalgebra::Vector3<f64>) -> Self {
+        Self {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<Vector3> for nalgebra::Vector3<f64> {
+    fn from(v: Vector3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<nalgebra::Translation3<f64>> for Vector3 {
+    fn from(t: nalgebra::Translation3<f64>) -> Self {
+        t.vector.into()
+    }
+}
+
+impl From<nalgebra::Point3<f64>> for Point3 {
+    fn from(p: nalgebra::Point3<f64>) -> Self {
+        Self {
+            x: p.x,
+            y: p.y,
+            z: p.z,
+        }
+    }
+}
+
+impl From<Point3> for nalgebra::Point3<f64> {
+    fn from(p: Point3) -> Self {
+        Self::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<nalgebra::Quaternion<f64>> for Quaternion {
+    fn from(q: nalgebra::Quaternion<f64>) -> Self {
+        Self {
+            x: q.i,
+            y: q.j,
+            z: q.k,
+            w: q.w,
+        }
+    }
+}
+
+impl From<Quaternion> for nalgebra::Quaternion<f64> {
+    fn from(q: Quaternion) -> Self {
+        Self::new(q.w, q.x, q.y, q.z)
+    }
+}
+
+impl From<nalgebra::UnitQuaternion<f64>> for Quaternion {
+    fn from(q: nalgebra::UnitQuaternion<f64>) -> Self {
+        q.into_inner().into()
+    }
+}
+
+/// Normalizes the quaternion.
+///
+/// The result is not a valid rotation if the quaternion is zero.
+impl From<Quaternion> for nalgebra::UnitQuaternion<f64> {
+    fn from(q: Quaternion) -> Self {
+        Self::from_quaternion(q.into())
+    }
+}
+
+impl From<nalgebra::Isometry3<f64>> for Pose {
+    fn from(iso: nalgebra::Isometry3<f64>) -> Self {
+        Self {
+            position: Some(iso.translation.into()),
+            orientation: Some(iso.rotation.into()),
+        }
+    }
+}
+
+impl From<Pose> for nalgebra::Isometry3<f64> {
+    fn from(pose: Pose) -> Self {
+        let translation = pose
+            .position
+            .map_or_else(nalgebra::Vector3::zeros, Into::into);
+        let rotation = pose
+            .orientation
+            .map_or_else(nalgebra::UnitQuaternion::identity, Into::into);
+        Self::from_parts(translation.into(), rotation)
+    }
+}
@@ -0,0 +1,80 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use crate::schemas::{FrameTransform, Point3, Pose, Quaternion, Vector2, Vector3};
+
+#[test]
+fn test_vectors_and_points() {
+    let v = Vector3::from(nalgebra::Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(
+        v,
+        Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0
+        }
+    );
+    assert_eq!(
+        nalgebra::Vector3::from(v),
+        nalgebra::Vector3::new(1.0, 2.0, 3.0)
+    );
+
+    let p = Point3::from(nalgebra::Point3::new(4.0, 5.0, 6.0));
+    assert_eq!(
+        nalgebra::Point3::from(p),
+        nalgebra::Point3::new(4.0, 5.0, 6.0)
+    );
+
+    let v = Vector2::from(nalgebra::Vector2::new(1.0, 2.0));
+    assert_eq!(v, Vector2 { x: 1.0, y: 2.0 });
+}
+
+#[test]
+fn test_quaternion() {
+    let rotation = nalgebra::UnitQuaternion::from_euler_angles(0.0, 0.0, FRAC_PI_2);
+    let q = Quaternion::from(rotation);
+    assert!((q.z - FRAC_PI_4.sin()).abs() < 1e-12);
+    assert!((q.w - FRAC_PI_4.cos()).abs() < 1e-12);
+    assert!(nalgebra::UnitQuaternion::from(q).angle_to(&rotation) < 1e-12);
+
+    // Quaternions are normalized when converted to unit quaternions.
+    let q = Quaternion {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 2.0,
+    };
+    assert_eq!(
+        nalgebra::UnitQuaternion::from(q),
+        nalgebra::UnitQuaternion::identity()
+    );
+}
+
+#[test]
+fn test_pose() {
+    let iso = nalgebra::Isometry3::new(
+        nalgebra::Vector3::new(1.0, 2.0, 3.0),
+        nalgebra::Vector3::new(0.0, 0.0, FRAC_PI_2),
+    );
+    let pose = Pose::from(iso);
+    assert_eq!(
+        pose.position,
+        Some(Vector3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0
+        })
+    );
+    assert_eq!(nalgebra::Isometry3::from(pose), iso);
+
+    // Absent fields are the identity.
+    assert_eq!(
+        nalgebra::Isometry3::from(Pose::default()),
+        nalgebra::Isometry3::identity()
+    );
+
+    let tf = FrameTransform::from_pose("world", "base_link", iso);
+    assert_eq!(tf.parent_frame_id, "world");
+    assert_eq!(tf.child_frame_id, "base_link");
+    assert_eq!(tf.translation, Pose::from(iso).position);
+    assert_eq!(tf.rotation, Pose::from(iso).orientation);
+}
@@ -11,8 +11,12 @@ use crate::convert::{RangeError, SaturatingFrom};
 
 #[cfg(feature = "chrono")]
 mod chrono;
+#[cfg(feature = "serde")]
+pub(crate) mod serde;
 #[cfg(test)]
 mod tests;
+#[cfg(feature = "time")]
+mod time;
 
 /// The result type for [`normalize_nsec`].
 #[derive(Debug, PartialEq, Eq)]
@@ -91,6 +95,22 @@ impl NormalizeResult {
 ///     let duration: Duration = chrono::TimeDelta::microseconds(1_414_213).try_into().unwrap();
 ///     assert_eq!(duration, Duration::new(1, 414_213_000));
 /// }
+///
+/// #[cfg(feature = "time")]
+/// {
+///     let duration: Duration = time::Duration::microseconds(-1_732_050).try_into().unwrap();
+///     assert_eq!(duration, Duration::new(-2, 267_950_000));
+/// }
+/// ```
+///
+/// Conversions back to [`std::time::Duration`] fail for negative durations. Conversions to
+/// `time::Duration` are infallible.
+///
+/// ```
+/// # use foxglove::schemas::Duration;
+/// let duration = std::time::Duration::try_from(Duration::new(0, 577_215_000)).unwrap();
+/// assert_eq!(duration, std::time::Duration::from_micros(577_215));
+/// assert!(std::time::Duration::try_from(Duration::new(-1, 0)).is_err());
 /// ```
 ///
 /// The [`SaturatingFrom`] and [`SaturatingInto`][crate::convert::SaturatingInto] traits may be
@@ -243,6 +263,25 @@ impl TryFrom<std::time::Duration> for Duration {
     }
 }
 
+/// Fails with [`RangeError::LowerBound`] if the duration is negative.
+impl TryFrom<Duration> for std::time::Duration {
+    type Error = RangeError;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        let Ok(sec) = u64::try_from(duration.sec) else {
+            return Err(RangeError::LowerBound);
+        };
+        Ok(Self::new(sec, duration.nsec))
+    }
+}
+
+/// Saturates to zero if the duration is negative.
+impl SaturatingFrom<Duration> for std::time::Duration {
+    fn saturating_from(duration: Duration) -> Self {
+        Self::try_from(duration).unwrap_or(Self::ZERO)
+    }
+}
+
 impl<T> SaturatingFrom<T> for Duration
 where
     Self: TryFrom<T, Error = RangeError>,
@@ -282,6 +321,21 @@ where
 ///     let timestamp = Timestamp::try_from(chrono::NaiveDateTime::UNIX_EPOCH).unwrap();
 ///     assert_eq!(timestamp, Timestamp::MIN);
 /// }
+///
+/// #[cfg(feature = "time")]
+/// {
+///     let timestamp = Timestamp::try_from(time::OffsetDateTime::UNIX_EPOCH).unwrap();
+///     assert_eq!(timestamp, Timestamp::MIN);
+/// }
+/// ```
+///
+/// Every timestamp can be converted back to a [`SystemTime`](std::time::SystemTime), or with the
+/// `time` feature, a `time::OffsetDateTime`.
+///
+/// ```
+/// # use foxglove::schemas::Timestamp;
+/// let time = std::time::SystemTime::from(Timestamp::new(1, 0));
+/// assert_eq!(time, std::time::UNIX_EPOCH + std::time::Duration::from_secs(1));
 /// ```
 ///
 /// The [`SaturatingFrom`] and [`SaturatingInto`][crate::convert::SaturatingInto] traits may be
@@ -429,6 +483,12 @@ impl TryFrom<std::time::SystemTime> for Timestamp {
     }
 }
 
+impl From<Timestamp> for std::time::SystemTime {
+    fn from(timestamp: Timestamp) -> Self {
+        std::time::UNIX_EPOCH + std::time::Duration::new(u64::from(timestamp.sec), timestamp.nsec)
+    }
+}
+
 impl<T> SaturatingFrom<T> for Timestamp
 where
     Self: TryFrom<T, Error = RangeError>,
@@ -0,0 +1,122 @@
+//! Serde support for well-known types.
+//!
+//! The serialized representations match the Foxglove JSON schemas.
+
+use std::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Duration, Timestamp};
+
+#[cfg(test)]
+mod tests;
+
+/// The serialized representation of a [`Timestamp`].
+#[derive(Serialize, Deserialize)]
+struct RawTimestamp {
+    sec: u32,
+    nsec: u32,
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RawTimestamp {
+            sec: self.sec,
+            nsec: self.nsec,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawTimestamp { sec, nsec } = RawTimestamp::deserialize(deserializer)?;
+        Self::new_checked(sec, nsec).ok_or_else(|| de::Error::custom("timestamp out of range"))
+    }
+}
+
+/// The serialized representation of a [`Duration`].
+#[derive(Serialize, Deserialize)]
+struct RawDuration {
+    sec: i32,
+    nsec: u32,
+}
+
+impl Serialize for Duration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RawDuration {
+            sec: self.sec,
+            nsec: self.nsec,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Duration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawDuration { sec, nsec } = RawDuration::deserialize(deserializer)?;
+        Self::new_checked(sec, nsec).ok_or_else(|| de::Error::custom("duration out of range"))
+    }
+}
+
+/// Serde helpers for `bytes` fields in the generated schema types.
+///
+/// Human-readable formats such as JSON represent bytes as a base64-encoded string, as described
+/// by the JSON schemas. Binary formats represent them natively.
+pub(crate) mod bytes {
+    use super::*;
+    use base64::prelude::*;
+
+    pub fn serialize<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&BASE64_STANDARD.encode(data))
+        } else {
+            serializer.serialize_bytes(data)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<::bytes::Bytes, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(BytesVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor)
+        }
+    }
+
+    /// Accepts a base64-encoded string, a byte array, or a sequence of bytes.
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = ::bytes::Bytes;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a base64-encoded string or a byte array")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            BASE64_STANDARD
+                .decode(v)
+                .map(Into::into)
+                .map_err(de::Error::custom)
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Ok(::bytes::Bytes::copy_from_slice(v))
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+            Ok(v.into())
+        }
+
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut data = Vec::new();
+            while let Some(byte) = seq.next_element()? {
+                data.push(byte);
+            }
+            Ok(data.into())
+        }
+    }
+}
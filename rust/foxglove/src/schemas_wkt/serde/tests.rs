@@ -0,0 +1,35 @@
+use serde_json::json;
+
+use super::{Duration, Timestamp};
+
+#[test]
+fn test_timestamp_json() {
+    let timestamp = Timestamp::new(1, 2);
+    let value = serde_json::to_value(timestamp).unwrap();
+    assert_eq!(value, json!({"sec": 1, "nsec": 2}));
+    assert_eq!(
+        serde_json::from_value::<Timestamp>(value).unwrap(),
+        timestamp
+    );
+
+    // Nanoseconds are normalized.
+    let timestamp: Timestamp =
+        serde_json::from_value(json!({"sec": 1, "nsec": 1_500_000_000})).unwrap();
+    assert_eq!(timestamp, Timestamp::new(2, 500_000_000));
+
+    let err = serde_json::from_value::<Timestamp>(json!({"sec": u32::MAX, "nsec": 1_000_000_000}))
+        .unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{err}");
+}
+
+#[test]
+fn test_duration_json() {
+    let duration = Duration::new(-1, 2);
+    let value = serde_json::to_value(duration).unwrap();
+    assert_eq!(value, json!({"sec": -1, "nsec": 2}));
+    assert_eq!(serde_json::from_value::<Duration>(value).unwrap(), duration);
+
+    let err = serde_json::from_value::<Duration>(json!({"sec": i32::MAX, "nsec": 1_000_000_000}))
+        .unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{err}");
+}
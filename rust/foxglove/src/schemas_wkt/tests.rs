@@ -174,6 +174,33 @@ fn test_duration_from_std_duration() {
     assert_eq!(Duration::saturating_from(orig), Duration::MAX);
 }
 
+#[test]
+fn test_std_duration_from_duration() {
+    for dur in [
+        Duration::default(),
+        Duration::new(1, 234_000_000),
+        Duration::MAX,
+    ] {
+        let orig = std::time::Duration::try_from(dur).unwrap();
+        assert_eq!(Duration::try_from(orig).unwrap(), dur);
+    }
+    assert_eq!(
+        std::time::Duration::try_from(Duration::new(1, 234_000_000)).unwrap(),
+        std::time::Duration::from_millis(1234)
+    );
+
+    // negative
+    let dur = Duration::new(-1, 999_999_999);
+    assert_matches!(
+        std::time::Duration::try_from(dur),
+        Err(RangeError::LowerBound)
+    );
+    assert_eq!(
+        std::time::Duration::saturating_from(dur),
+        std::time::Duration::ZERO
+    );
+}
+
 #[test]
 fn test_timestamp_normalization() {
     assert_eq!(
@@ -281,3 +308,19 @@ fn test_timestamp_from_system_time() {
     assert_matches!(Timestamp::try_from(orig), Err(RangeError::UpperBound));
     assert_eq!(Timestamp::saturating_from(orig), Timestamp::MAX);
 }
+
+#[test]
+fn test_system_time_from_timestamp() {
+    for ts in [
+        Timestamp::MIN,
+        Timestamp::new(123, 456_789_000),
+        Timestamp::MAX,
+    ] {
+        let orig = std::time::SystemTime::from(ts);
+        assert_eq!(Timestamp::try_from(orig).unwrap(), ts);
+    }
+    assert_eq!(
+        std::time::SystemTime::from(Timestamp::new(1, 500_000_000)),
+        std::time::UNIX_EPOCH + std::time::Duration::from_millis(1500)
+    );
+}
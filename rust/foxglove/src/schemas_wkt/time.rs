@@ -0,0 +1,76 @@
+//! Conversions to and from time crate types.
+
+use super::{Duration, Timestamp};
+use crate::convert::RangeError;
+
+#[cfg(test)]
+mod tests;
+
+impl TryFrom<time::Duration> for Duration {
+    type Error = RangeError;
+
+    fn try_from(duration: time::Duration) -> Result<Self, Self::Error> {
+        let whole_seconds = duration.whole_seconds();
+        let Ok(mut sec) = i32::try_from(whole_seconds) else {
+            return Err(if whole_seconds > 0 {
+                RangeError::UpperBound
+            } else {
+                RangeError::LowerBound
+            });
+        };
+        let subsec_nanos = duration.subsec_nanoseconds();
+        let nsec = if subsec_nanos >= 0 {
+            u32::try_from(subsec_nanos).expect("positive")
+        } else if sec == i32::MIN {
+            return Err(RangeError::LowerBound);
+        } else {
+            sec -= 1;
+            u32::try_from(subsec_nanos + 1_000_000_000).expect("positive")
+        };
+        Ok(Self::new(sec, nsec))
+    }
+}
+
+/// Converts nanoseconds, which are always less than one second, to an `i32`.
+fn nsec_i32(nsec: u32) -> i32 {
+    i32::try_from(nsec)
+        .unwrap_or_else(|e| unreachable!("expected {nsec} to be within [0, 1_000_000_000): {e}"))
+}
+
+impl From<Duration> for time::Duration {
+    fn from(duration: Duration) -> Self {
+        Self::new(i64::from(duration.sec), nsec_i32(duration.nsec))
+    }
+}
+
+impl TryFrom<time::OffsetDateTime> for Timestamp {
+    type Error = RangeError;
+
+    fn try_from(time: time::OffsetDateTime) -> Result<Self, Self::Error> {
+        let timestamp = time.unix_timestamp();
+        let Ok(sec) = u32::try_from(timestamp) else {
+            return Err(if timestamp > 0 {
+                RangeError::UpperBound
+            } else {
+                RangeError::LowerBound
+            });
+        };
+        let nsec = time.nanosecond();
+        Ok(Self::new(sec, nsec))
+    }
+}
+
+/// The date and time is assumed to be in UTC.
+impl TryFrom<time::PrimitiveDateTime> for Timestamp {
+    type Error = RangeError;
+
+    fn try_from(time: time::PrimitiveDateTime) -> Result<Self, Self::Error> {
+        Self::try_from(time.assume_utc())
+    }
+}
+
+impl From<Timestamp> for time::OffsetDateTime {
+    fn from(timestamp: Timestamp) -> Self {
+        Self::UNIX_EPOCH + time::Duration::new(i64::from(timestamp.sec), nsec_i32(timestamp.nsec))
+    }
+}
@@ -0,0 +1,128 @@
+use assert_matches::assert_matches;
+
+use super::{Duration, Timestamp};
+use crate::convert::{RangeError, SaturatingFrom};
+
+#[test]
+fn test_duration_from_time_duration() {
+    // positive
+    let orig = time::Duration::new(1, 234_000_000);
+    let dur = Duration::try_from(orig).unwrap();
+    assert_eq!(
+        dur,
+        Duration {
+            sec: 1,
+            nsec: 234_000_000
+        }
+    );
+
+    // negative
+    let orig = time::Duration::new(-2, 345_000_000);
+    assert_eq!(orig.whole_seconds(), -1);
+    assert_eq!(orig.subsec_nanoseconds(), -655_000_000);
+    let dur = Duration::try_from(orig).unwrap();
+    assert_eq!(
+        dur,
+        Duration {
+            sec: -2,
+            nsec: 345_000_000
+        }
+    );
+
+    // max
+    let orig = time::Duration::new(i32::MAX as i64, 999_999_999);
+    assert_eq!(Duration::try_from(orig).unwrap(), Duration::MAX);
+
+    // min
+    let orig = time::Duration::new(i32::MIN as i64, 0);
+    assert_eq!(Duration::try_from(orig).unwrap(), Duration::MIN);
+
+    // seconds out of range, high
+    let orig = time::Duration::new(i32::MAX as i64 + 1, 0);
+    assert_matches!(Duration::try_from(orig), Err(RangeError::UpperBound));
+    assert_eq!(Duration::saturating_from(orig), Duration::MAX);
+
+    // seconds out of range, low
+    let orig = time::Duration::new(i32::MIN as i64 - 1, 0);
+    assert_matches!(Duration::try_from(orig), Err(RangeError::LowerBound));
+    assert_eq!(Duration::saturating_from(orig), Duration::MIN);
+
+    // whole seconds within range, but knocked out of range by nanos
+    let orig = time::Duration::new(i32::MIN as i64 - 1, 999_999_999);
+    assert_eq!(orig.whole_seconds(), i32::MIN as i64);
+    assert_eq!(orig.subsec_nanoseconds(), -1);
+    assert_matches!(Duration::try_from(orig), Err(RangeError::LowerBound));
+    assert_eq!(Duration::saturating_from(orig), Duration::MIN);
+}
+
+#[test]
+fn test_duration_round_trip() {
+    for dur in [
+        Duration::MIN,
+        Duration::new(-2, 345_000_000),
+        Duration::default(),
+        Duration::new(1, 234_000_000),
+        Duration::MAX,
+    ] {
+        let orig = time::Duration::from(dur);
+        assert_eq!(Duration::try_from(orig).unwrap(), dur);
+    }
+    assert_eq!(
+        time::Duration::from(Duration::new(-2, 345_000_000)),
+        time::Duration::nanoseconds(-1_655_000_000)
+    );
+}
+
+#[test]
+fn test_timestamp_from_offset_date_time() {
+    let orig = time::OffsetDateTime::from_unix_timestamp_nanos(123_456_789_000).unwrap();
+    let ts = Timestamp::try_from(orig).unwrap();
+    assert_eq!(
+        ts,
+        Timestamp {
+            sec: 123,
+            nsec: 456_789_000
+        }
+    );
+
+    // other offsets are converted to UTC
+    let offset = time::UtcOffset::from_hms(-8, 0, 0).unwrap();
+    assert_eq!(Timestamp::try_from(orig.to_offset(offset)).unwrap(), ts);
+
+    // naive date and time is UTC
+    let naive = time::PrimitiveDateTime::new(orig.date(), orig.time());
+    assert_eq!(Timestamp::try_from(naive).unwrap(), ts);
+
+    // min
+    let orig = time::OffsetDateTime::UNIX_EPOCH;
+    assert_eq!(Timestamp::try_from(orig).unwrap(), Timestamp::MIN);
+
+    // max
+    let orig = time::OffsetDateTime::from_unix_timestamp_nanos(
+        u32::MAX as i128 * 1_000_000_000 + 999_999_999,
+    )
+    .unwrap();
+    assert_eq!(Timestamp::try_from(orig).unwrap(), Timestamp::MAX);
+
+    // too future
+    let orig = time::OffsetDateTime::from_unix_timestamp(u32::MAX as i64 + 1).unwrap();
+    assert_matches!(Timestamp::try_from(orig), Err(RangeError::UpperBound));
+    assert_eq!(Timestamp::saturating_from(orig), Timestamp::MAX);
+
+    // too past
+    let orig = time::OffsetDateTime::from_unix_timestamp_nanos(-1).unwrap();
+    assert_matches!(Timestamp::try_from(orig), Err(RangeError::LowerBound));
+    assert_eq!(Timestamp::saturating_from(orig), Timestamp::MIN);
+}
+
+#[test]
+fn test_timestamp_round_trip() {
+    for ts in [
+        Timestamp::MIN,
+        Timestamp::new(123, 456_789_000),
+        Timestamp::MAX,
+    ] {
+        let orig = time::OffsetDateTime::from(ts);
+        assert_eq!(Timestamp::try_from(orig).unwrap(), ts);
+    }
+}
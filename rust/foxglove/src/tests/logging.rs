@@ -1,9 +1,10 @@
 use crate::testutil::GlobalContextTest;
-use crate::{ChannelBuilder, McapWriter, Schema, WebSocketServer};
+use crate::{ChannelBuilder, LogContext, McapWriter, Schema, WebSocketServer};
 use futures_util::{FutureExt, SinkExt, StreamExt};
 use serde_json::json;
 use std::{
-    io::{BufReader, BufWriter, Read, Seek},
+    io::{BufReader, BufWriter, Cursor, Read, Seek},
+    sync::Arc,
     time::Duration,
 };
 use tempfile::NamedTempFile;
@@ -172,6 +173,76 @@ async fn test_logging_to_file_and_live_sinks() {
     server.stop().await;
 }
 
+#[test]
+fn test_logging_to_scoped_contexts() {
+    let _cleanup = GlobalContextTest::new();
+
+    // The global context has a sink, which should not see any messages logged to scoped contexts.
+    let global_mcap = McapWriter::new()
+        .create(Cursor::new(Vec::new()))
+        .expect("Failed to create writer");
+
+    let mut contexts = Vec::new();
+    for name in ["left", "right"] {
+        let ctx = Arc::new(LogContext::new());
+        let mcap = McapWriter::new()
+            .context(&ctx)
+            .create(Cursor::new(Vec::new()))
+            .expect("Failed to create writer");
+        // The same topic can be registered in each context.
+        let channel = ChannelBuilder::new("/camera")
+            .message_encoding("json")
+            .with_context(&ctx)
+            .build()
+            .expect("Failed to create channel");
+        channel.log(format!(r#"{{"name": "{name}"}}"#).as_bytes());
+        contexts.push((name, ctx, channel, mcap));
+    }
+
+    for (name, _ctx, _channel, mcap) in contexts {
+        let buf = mcap.close().expect("Failed to close writer").into_inner();
+        let messages: Vec<_> = mcap::MessageStream::new(&buf)
+            .expect("Failed to create message stream")
+            .collect::<Result<_, _>>()
+            .expect("Failed to read messages");
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].channel.topic, "/camera");
+        let json: serde_json::Value = serde_json::from_slice(&messages[0].data).unwrap();
+        assert_eq!(json["name"], name);
+    }
+
+    let buf = global_mcap
+        .close()
+        .expect("Failed to close writer")
+        .into_inner();
+    let count = mcap::MessageStream::new(&buf)
+        .expect("Failed to create message stream")
+        .count();
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_writer_keeps_context_alive() {
+    let ctx = Arc::new(LogContext::new());
+    let mcap = McapWriter::new()
+        .context(&ctx)
+        .create(Cursor::new(Vec::new()))
+        .expect("Failed to create writer");
+    let channel = ChannelBuilder::new("/topic")
+        .message_encoding("json")
+        .with_context(&ctx)
+        .build()
+        .expect("Failed to create channel");
+    drop(ctx);
+
+    channel.log(b"{}");
+    let buf = mcap.close().expect("Failed to close writer").into_inner();
+    let count = mcap::MessageStream::new(&buf)
+        .expect("Failed to create message stream")
+        .count();
+    assert_eq!(count, 1);
+}
+
 fn ws_msg_to_json(msg: Message) -> serde_json::Value {
     let data = msg
         .into_text()
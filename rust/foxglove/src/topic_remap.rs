@@ -0,0 +1,250 @@
+//! Topic namespacing and remapping.
+
+use crate::FoxgloveError;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// The special key that sets the namespace in a remapping config.
+const NAMESPACE_KEY: &str = "__ns";
+
+/// Rules for resolving the topic of a channel when it is created.
+///
+/// Reusable components can log to relative topics, such as `camera/image`, without knowing where
+/// they will be placed. When the channel is created, its topic is resolved as follows:
+///
+/// 1. If a remapping rule matches the topic exactly, the topic is replaced by its target.
+/// 2. If the resulting topic is relative (it does not begin with `/`), the channel's own
+///    [namespace](crate::ChannelBuilder::namespace) is prepended to it, if any.
+/// 3. If the topic is still relative, the namespace of these rules is prepended to it, if any.
+///
+/// Absolute topics are never namespaced, but they may be remapped.
+///
+/// A `TopicRemap` is installed on a [`LogContext`](crate::LogContext) with
+/// [`LogContext::set_topic_remap`](crate::LogContext::set_topic_remap), and may also be applied
+/// to a single channel with [`ChannelBuilder::namespace`](crate::ChannelBuilder::namespace).
+///
+/// ```
+/// use foxglove::TopicRemap;
+///
+/// let remap = TopicRemap::new()
+///     .namespace("/left")
+///     .remap("camera/image", "camera/image_raw");
+/// assert_eq!(remap.resolve("camera/image"), "/left/camera/image_raw");
+/// assert_eq!(remap.resolve("camera/info"), "/left/camera/info");
+/// assert_eq!(remap.resolve("/tf"), "/tf");
+/// ```
+///
+/// Rules can also be loaded from a config string, with one `from:=to` rule per line. The special
+/// rule `__ns:=<namespace>` sets the namespace. Blank lines and lines beginning with `#` are
+/// ignored.
+///
+/// ```
+/// use foxglove::TopicRemap;
+///
+/// let remap: TopicRemap = "
+///     __ns:=/right
+///     camera/image:=image_rect
+///     /tf:=/right/tf
+/// "
+/// .parse()
+/// .unwrap();
+/// assert_eq!(remap.resolve("camera/image"), "/right/image_rect");
+/// assert_eq!(remap.resolve("/tf"), "/right/tf");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TopicRemap {
+    namespace: Option<String>,
+    rules: HashMap<String, String>,
+}
+
+impl TopicRemap {
+    /// Creates an empty set of rules, which leaves all topics unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the namespace that is prepended to relative topics.
+    #[must_use]
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Adds a rule that replaces the topic `from` with `to`.
+    ///
+    /// If there is already a rule for `from`, it is replaced.
+    #[must_use]
+    pub fn remap(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.rules.insert(from.into(), to.into());
+        self
+    }
+
+    /// Returns true if these rules leave all topics unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.namespace.is_none() && self.rules.is_empty()
+    }
+
+    /// Resolves a topic according to these rules.
+    pub fn resolve(&self, topic: &str) -> String {
+        self.resolve_with_namespace(topic, None)
+    }
+
+    /// Resolves a topic according to these rules, after applying an inner namespace.
+    ///
+    /// Remapping rules are matched against the topic as written. If the result is relative, the
+    /// inner namespace is applied, followed by the namespace of these rules.
+    pub(crate) fn resolve_with_namespace(&self, topic: &str, inner: Option<&str>) -> String {
+        let mut topic = self
+            .rules
+            .get(topic)
+            .map_or(topic, String::as_str)
+            .to_string();
+        for namespace in [inner, self.namespace.as_deref()].into_iter().flatten() {
+            if !topic.starts_with('/') {
+                topic = join_namespace(namespace, &topic);
+            }
+        }
+        topic
+    }
+}
+
+/// Joins a namespace and a relative topic with a single `/`.
+fn join_namespace(namespace: &str, topic: &str) -> String {
+    let namespace = namespace.trim_end_matches('/');
+    if namespace.is_empty() {
+        // The root namespace makes relative topics absolute.
+        format!("/{topic}")
+    } else {
+        format!("{namespace}/{topic}")
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for TopicRemap {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut remap = Self::new();
+        remap.extend(iter);
+        remap
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> Extend<(K, V)> for TopicRemap {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (from, to) in iter {
+            let from = from.into();
+            if from == NAMESPACE_KEY {
+                self.namespace = Some(to.into());
+            } else {
+                self.rules.insert(from, to.into());
+            }
+        }
+    }
+}
+
+impl FromStr for TopicRemap {
+    type Err = FoxgloveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rules = Vec::new();
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let rule = line
+                .split_once(":=")
+                .map(|(from, to)| (from.trim(), to.trim()))
+                .filter(|(from, to)| !from.is_empty() && !to.is_empty());
+            let Some(rule) = rule else {
+                return Err(FoxgloveError::InvalidTopicRemap(format!(
+                    "line {}: expected `from:=to`, got `{line}`",
+                    index + 1
+                )));
+            };
+            rules.push(rule);
+        }
+        Ok(rules.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_remap_is_identity() {
+        let remap = TopicRemap::new();
+        assert!(remap.is_empty());
+        assert_eq!(remap.resolve("camera"), "camera");
+        assert_eq!(remap.resolve("/camera"), "/camera");
+    }
+
+    #[test]
+    fn test_namespace_applies_to_relative_topics() {
+        let remap = TopicRemap::new().namespace("/left/");
+        assert_eq!(remap.resolve("camera/image"), "/left/camera/image");
+        assert_eq!(remap.resolve("/camera/image"), "/camera/image");
+
+        let remap = TopicRemap::new().namespace("/");
+        assert_eq!(remap.resolve("camera/image"), "/camera/image");
+    }
+
+    #[test]
+    fn test_remap_is_applied_before_namespace() {
+        let remap = TopicRemap::new()
+            .namespace("/left")
+            .remap("image", "image_raw")
+            .remap("info", "/shared/info");
+        assert_eq!(remap.resolve("image"), "/left/image_raw");
+        assert_eq!(remap.resolve("info"), "/shared/info");
+        assert_eq!(remap.resolve("other"), "/left/other");
+    }
+
+    #[test]
+    fn test_inner_namespace() {
+        let remap = TopicRemap::new()
+            .namespace("/robot")
+            .remap("image", "image_raw");
+        assert_eq!(
+            remap.resolve_with_namespace("image", Some("left")),
+            "/robot/left/image_raw"
+        );
+        assert_eq!(
+            remap.resolve_with_namespace("image", Some("/left")),
+            "/left/image_raw"
+        );
+        assert_eq!(
+            TopicRemap::new().resolve_with_namespace("image", Some("left")),
+            "left/image"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let remap: TopicRemap = "\n# comment\n__ns:=/robot\n  a := b  \n/c:=/d\n"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            remap,
+            TopicRemap::new()
+                .namespace("/robot")
+                .remap("a", "b")
+                .remap("/c", "/d")
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = "a:=b\nc=d".parse::<TopicRemap>().unwrap_err();
+        assert!(matches!(err, FoxgloveError::InvalidTopicRemap(_)));
+        assert!(err.to_string().contains("line 2"), "{err}");
+
+        let err = "a:=".parse::<TopicRemap>().unwrap_err();
+        assert!(matches!(err, FoxgloveError::InvalidTopicRemap(_)));
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let remap: TopicRemap = [("__ns", "/ns"), ("a", "b")].into_iter().collect();
+        assert_eq!(remap, TopicRemap::new().namespace("/ns").remap("a", "b"));
+    }
+}
@@ -0,0 +1,761 @@
+//! A tree of coordinate frames, for looking up and publishing transforms.
+//!
+//! A [`TransformTree`] stores the [`FrameTransform`]s between frames. Each frame has at most one
+//! parent. A transform is either static, such as the mounting of a sensor, or dynamic, such as
+//! the position of a robot in the world. Dynamic transforms are stored with their timestamps for
+//! a limited [cache duration](TransformTree::with_cache_duration).
+//!
+//! [`TransformTree::lookup`] finds the transform between any two connected frames at a point in
+//! time, by composing the transforms along the path between them. Dynamic transforms are
+//! interpolated between the samples on either side of the requested time.
+//! [`TransformTree::lookup_latest`] finds the most recent transform.
+//!
+//! ```
+//! use foxglove::schemas::{FrameTransform, Pose, Quaternion, Timestamp, Vector3};
+//! use foxglove::transform_tree::TransformTree;
+//!
+//! let tree = TransformTree::new();
+//! let camera = Pose::new(Vector3::new(0.0, 0.0, 1.0), Quaternion::identity());
+//! tree.set_static_transform(FrameTransform::from_pose("base_link", "camera", camera))
+//!     .unwrap();
+//! for (sec, x) in [(1, 0.0), (2, 2.0)] {
+//!     let base = Pose::new(Vector3::new(x, 0.0, 0.0), Quaternion::identity());
+//!     let tf = FrameTransform {
+//!         timestamp: Some(Timestamp::new(sec, 0)),
+//!         ..FrameTransform::from_pose("world", "base_link", base)
+//!     };
+//!     tree.set_transform(tf).unwrap();
+//! }
+//!
+//! let tf = tree.lookup("world", "camera", Timestamp::new(1, 500_000_000)).unwrap();
+//! assert_eq!(tf.translation, Some(Vector3::new(1.0, 0.0, 1.0)));
+//! ```
+//!
+//! A [`TransformPublisher`] publishes the transforms in a tree as [`FrameTransforms`] messages in
+//! the background, for visualization in the Foxglove app.
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::RwLock;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+use crate::nanoseconds_since_epoch;
+use crate::runtime::get_runtime_handle;
+use crate::schemas::{FrameTransform, FrameTransforms, Pose, Timestamp};
+use crate::{ChannelBuilder, FoxgloveError, LogContext, TypedChannel};
+
+/// The default duration for which dynamic transforms are stored.
+const DEFAULT_CACHE_DURATION: Duration = Duration::from_secs(10);
+
+/// An error that can occur when updating or querying a [`TransformTree`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum TransformError {
+    /// The transform is missing its parent or child frame ID.
+    #[error("Parent and child frame IDs are required")]
+    MissingFrameId,
+    /// Adding the transform would create a cycle.
+    #[error("Adding {parent} as the parent of {child} would create a cycle")]
+    Cycle {
+        /// The parent frame ID.
+        parent: String,
+        /// The child frame ID.
+        child: String,
+    },
+    /// The frame is not in the tree.
+    #[error("Frame {0} not found")]
+    FrameNotFound(String),
+    /// The frames are in the tree, but there is no path between them.
+    #[error("Frames {parent} and {child} are not connected")]
+    NotConnected {
+        /// The parent frame ID.
+        parent: String,
+        /// The child frame ID.
+        child: String,
+    },
+    /// A dynamic transform along the path has no samples on both sides of the requested time.
+    #[error(
+        "Transform from {parent} to {child} is not available at {}.{:09}",
+        .time.sec(),
+        .time.nsec()
+    )]
+    Extrapolation {
+        /// The parent frame ID of the transform.
+        parent: String,
+        /// The child frame ID of the transform.
+        child: String,
+        /// The requested time.
+        time: Timestamp,
+    },
+}
+
+#[derive(Debug)]
+enum Transform {
+    Static(Pose),
+    /// Samples keyed by nanoseconds since epoch.
+    Dynamic(BTreeMap<u64, Pose>),
+}
+
+#[derive(Debug)]
+struct Frame {
+    parent: String,
+    transform: Transform,
+}
+
+impl Frame {
+    /// Returns the pose of the frame in its parent frame at the given time.
+    fn pose_at(&self, time: u64) -> Option<Pose> {
+        let samples = match &self.transform {
+            Transform::Static(pose) => return Some(*pose),
+            Transform::Dynamic(samples) => samples,
+        };
+        let (&t1, after) = samples.range(time..).next()?;
+        if t1 == time {
+            return Some(*after);
+        }
+        let (&t0, before) = samples.range(..time).next_back()?;
+        let t = (time - t0) as f64 / (t1 - t0) as f64;
+        Some(before.interpolate(after, t))
+    }
+}
+
+/// A tree of coordinate frames. See the [module-level documentation](self) for details.
+///
+/// The tree can be shared between threads, for example in an [`Arc`].
+#[derive(Debug)]
+pub struct TransformTree {
+    /// Frames keyed by ID. Only frames with a parent are present.
+    frames: RwLock<BTreeMap<String, Frame>>,
+    cache_duration: u64,
+    /// Incremented whenever a static transform is set.
+    static_version: AtomicU64,
+}
+
+impl Default for TransformTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransformTree {
+    /// Creates an empty tree.
+    pub fn new() -> Self {
+        Self {
+            frames: RwLock::default(),
+            cache_duration: duration_nanos(DEFAULT_CACHE_DURATION),
+            static_version: AtomicU64::new(0),
+        }
+    }
+
+    /// Sets how long dynamic transforms are stored, relative to the latest sample of each
+    /// transform. The default is 10 seconds.
+    #[must_use]
+    pub fn with_cache_duration(mut self, duration: Duration) -> Self {
+        self.cache_duration = duration_nanos(duration);
+        self
+    }
+
+    /// Sets a static transform, which applies at all times.
+    ///
+    /// This replaces any previous transform of the child frame, static or dynamic.
+    pub fn set_static_transform(&self, transform: FrameTransform) -> Result<(), TransformError> {
+        let (parent, child, pose) = split_transform(transform)?;
+        let mut frames = self.frames.write();
+        check_cycle(&frames, &parent, &child)?;
+        frames.insert(
+            child,
+            Frame {
+                parent,
+                transform: Transform::Static(pose),
+            },
+        );
+        self.static_version.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Adds a sample of a dynamic transform.
+    ///
+    /// If the transform has no timestamp, the current time is used. Samples may be added out of
+    /// order. Samples older than the cache duration, relative to the latest sample, are dropped.
+    ///
+    /// If the child frame previously had a static transform, or a different parent, its previous
+    /// samples are discarded.
+    pub fn set_transform(&self, transform: FrameTransform) -> Result<(), TransformError> {
+        let time = transform
+            .timestamp
+            .map_or_else(nanoseconds_since_epoch, timestamp_nanos);
+        let (parent, child, pose) = split_transform(transform)?;
+        let mut frames = self.frames.write();
+        if frames.get(&child).is_none_or(|f| f.parent != parent) {
+            check_cycle(&frames, &parent, &child)?;
+        }
+        let frame = frames.entry(child).or_insert_with(|| Frame {
+            parent: parent.clone(),
+            transform: Transform::Dynamic(BTreeMap::new()),
+        });
+        let was_static = matches!(frame.transform, Transform::Static(_));
+        if was_static {
+            self.static_version.fetch_add(1, Ordering::Relaxed);
+        }
+        if frame.parent != parent || was_static {
+            *frame = Frame {
+                parent,
+                transform: Transform::Dynamic(BTreeMap::new()),
+            };
+        }
+        let Transform::Dynamic(samples) = &mut frame.transform else {
+            unreachable!("static transform was replaced");
+        };
+        samples.insert(time, pose);
+        let latest = *samples.keys().next_back().expect("not empty");
+        *samples = samples.split_off(&latest.saturating_sub(self.cache_duration));
+        Ok(())
+    }
+
+    /// Returns the transform from the parent frame to the child frame at the given time.
+    ///
+    /// The result is the pose of the child frame in the parent frame, composed along the path
+    /// between them. The frames do not need to be directly related; they only need to share an
+    /// ancestor. The returned transform has the requested timestamp.
+    ///
+    /// Returns [`TransformError::Extrapolation`] if a dynamic transform along the path has no
+    /// samples on both sides of the requested time. To look up the most recent transform, use
+    /// [`lookup_latest`](Self::lookup_latest).
+    pub fn lookup(
+        &self,
+        parent: &str,
+        child: &str,
+        time: Timestamp,
+    ) -> Result<FrameTransform, TransformError> {
+        self.lookup_at(parent, child, Some(time))
+    }
+
+    /// Returns the most recent transform from the parent frame to the child frame.
+    ///
+    /// This is the transform at the latest time for which every dynamic transform along the path
+    /// has a sample, like a lookup at time zero in tf2. The returned transform has that
+    /// timestamp, or zero if the path only has static transforms.
+    pub fn lookup_latest(
+        &self,
+        parent: &str,
+        child: &str,
+    ) -> Result<FrameTransform, TransformError> {
+        self.lookup_at(parent, child, None)
+    }
+
+    /// Looks up a transform at the given time, or at the latest common time if `None`.
+    fn lookup_at(
+        &self,
+        parent: &str,
+        child: &str,
+        time: Option<Timestamp>,
+    ) -> Result<FrameTransform, TransformError> {
+        let frames = self.frames.read();
+        for frame in [parent, child] {
+            if !frames.contains_key(frame) && !frames.values().any(|f| f.parent == frame) {
+                return Err(TransformError::FrameNotFound(frame.to_string()));
+            }
+        }
+
+        let parent_path = path_to_root(&frames, parent);
+        let child_path = path_to_root(&frames, child);
+        let ancestors: HashSet<&str> = parent_path.iter().copied().collect();
+        let Some(common) = child_path.iter().position(|f| ancestors.contains(f)) else {
+            return Err(TransformError::NotConnected {
+                parent: parent.to_string(),
+                child: child.to_string(),
+            });
+        };
+        let common_id = child_path[common];
+        let parent_depth = parent_path
+            .iter()
+            .position(|f| *f == common_id)
+            .expect("common ancestor");
+
+        let child_links = &child_path[..common];
+        let parent_links = &parent_path[..parent_depth];
+        let time = time.unwrap_or_else(|| {
+            let latest = child_links
+                .iter()
+                .chain(parent_links)
+                .filter_map(|id| match &frames[*id].transform {
+                    Transform::Static(_) => None,
+                    Transform::Dynamic(samples) => samples.keys().next_back().copied(),
+                })
+                .min();
+            nanos_timestamp(latest.unwrap_or_default())
+        });
+        let nanos = timestamp_nanos(time);
+        let child_in_common = compose_path(&frames, child_links, nanos, time)?;
+        let parent_in_common = compose_path(&frames, parent_links, nanos, time)?;
+        let pose = parent_in_common.inverse().compose(&child_in_common);
+        Ok(FrameTransform {
+            timestamp: Some(time),
+            ..FrameTransform::from_pose(parent, child, pose)
+        })
+    }
+
+    /// Returns the static transforms in the tree.
+    pub fn static_transforms(&self) -> FrameTransforms {
+        let frames = self.frames.read();
+        let transforms = frames
+            .iter()
+            .filter_map(|(child, frame)| match frame.transform {
+                Transform::Static(pose) => {
+                    Some(FrameTransform::from_pose(&frame.parent, child, pose))
+                }
+                Transform::Dynamic(_) => None,
+            })
+            .collect();
+        FrameTransforms { transforms }
+    }
+
+    /// Returns the latest sample of each dynamic transform in the tree.
+    pub fn latest_transforms(&self) -> FrameTransforms {
+        let frames = self.frames.read();
+        let transforms = frames
+            .iter()
+            .filter_map(|(child, frame)| match &frame.transform {
+                Transform::Static(_) => None,
+                Transform::Dynamic(samples) => {
+                    let (&time, pose) = samples.iter().next_back()?;
+                    Some(FrameTransform {
+                        timestamp: Some(nanos_timestamp(time)),
+                        ..FrameTransform::from_pose(&frame.parent, child, *pose)
+                    })
+                }
+            })
+            .collect();
+        FrameTransforms { transforms }
+    }
+}
+
+/// Publishes the transforms in a [`TransformTree`] in the background.
+///
+/// Dynamic transforms are published at the configured [rate](TransformPublisher::rate), using
+/// the latest sample of each transform. Static transforms are published when the publisher
+/// starts, and whenever they change. Since sinks have no notion of latched messages, static
+/// transforms are also republished at a slower [interval](TransformPublisher::static_interval),
+/// so that clients which connect later receive them.
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use foxglove::transform_tree::{TransformPublisher, TransformTree};
+///
+/// # fn func() -> Result<(), foxglove::FoxgloveError> {
+/// let tree = Arc::new(TransformTree::new());
+/// let publisher = TransformPublisher::new(tree.clone()).rate(30.0).start()?;
+///
+/// // Update the tree here.
+///
+/// publisher.stop();
+/// # Ok(()) }
+/// ```
+#[must_use]
+#[derive(Debug)]
+pub struct TransformPublisher<'a> {
+    tree: Arc<TransformTree>,
+    topic: String,
+    period: Duration,
+    static_interval: Duration,
+    context: Option<&'a LogContext>,
+}
+
+impl<'a> TransformPublisher<'a> {
+    /// Creates a publisher for a tree, which publishes on `/tf` at 10 Hz.
+    pub fn new(tree: Arc<TransformTree>) -> Self {
+        Self {
+            tree,
+            topic: "/tf".to_string(),
+            period: Duration::from_millis(100),
+            static_interval: Duration::from_secs(1),
+            context: None,
+        }
+    }
+
+    /// Sets the topic on which transforms are published. The default is `/tf`.
+    pub fn topic(mut self, topic: impl Into<String>) -> Self {
+        self.topic = topic.into();
+        self
+    }
+
+    /// Sets the rate, in Hz, at which dynamic transforms are published. The default is 10 Hz.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rate is not positive and finite.
+    pub fn rate(mut self, hz: f64) -> Self {
+        assert!(hz > 0.0 && hz.is_finite(), "rate must be positive");
+        self.period = Duration::from_secs_f64(1.0 / hz);
+        self
+    }
+
+    /// Sets the interval at which static transforms are republished. The default is 1 second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval is zero.
+    pub fn static_interval(mut self, interval: Duration) -> Self {
+        assert!(!interval.is_zero(), "interval must be non-zero");
+        self.static_interval = interval;
+        self
+    }
+
+    /// Sets the [`LogContext`] in which to create the channel.
+    ///
+    /// By default, the channel is created in the [global context](LogContext::global).
+    pub fn with_context(mut self, ctx: &'a LogContext) -> Self {
+        self.context = Some(ctx);
+        self
+    }
+
+    /// Creates the channel, and starts publishing in the background.
+    ///
+    /// Returns [`FoxgloveError::DuplicateChannel`] if a channel with the same topic already
+    /// exists in the context.
+    pub fn start(self) -> Result<TransformPublisherHandle, FoxgloveError> {
+        let mut builder = ChannelBuilder::new(self.topic);
+        if let Some(ctx) = self.context {
+            builder = builder.with_context(ctx);
+        }
+        let channel = builder.build_typed::<FrameTransforms>()?;
+        let cancellation_token = CancellationToken::new();
+        let task = get_runtime_handle().spawn(publish(
+            self.tree,
+            channel,
+            self.period,
+            self.static_interval,
+            cancellation_token.clone(),
+        ));
+        Ok(TransformPublisherHandle {
+            cancellation_token,
+            task,
+        })
+    }
+}
+
+/// A handle to a running [`TransformPublisher`].
+///
+/// The publisher stops when the handle is dropped.
+#[derive(Debug)]
+pub struct TransformPublisherHandle {
+    cancellation_token: CancellationToken,
+    task: JoinHandle<()>,
+}
+
+impl TransformPublisherHandle {
+    /// Stops publishing.
+    pub fn stop(self) {
+        self.cancellation_token.cancel();
+        self.task.abort();
+    }
+}
+
+impl Drop for TransformPublisherHandle {
+    fn drop(&mut self) {
+        self.cancellation_token.cancel();
+        self.task.abort();
+    }
+}
+
+async fn publish(
+    tree: Arc<TransformTree>,
+    channel: TypedChannel<FrameTransforms>,
+    period: Duration,
+    static_interval: Duration,
+    cancellation_token: CancellationToken,
+) {
+    let log = |msg: FrameTransforms| {
+        if !msg.transforms.is_empty() {
+            channel.log(&msg);
+        }
+    };
+    let mut dynamic = tokio::time::interval(period);
+    dynamic.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    let mut statics = tokio::time::interval(static_interval);
+    statics.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    let mut static_version = tree.static_version.load(Ordering::Relaxed);
+    loop {
+        tokio::select! {
+            // Publish static transforms before dynamic ones, which depend on them.
+            biased;
+            () = cancellation_token.cancelled() => break,
+            _ = statics.tick() => {
+                static_version = tree.static_version.load(Ordering::Relaxed);
+                log(tree.static_transforms());
+            }
+            _ = dynamic.tick() => {
+                let version = tree.static_version.load(Ordering::Relaxed);
+                if version != static_version {
+                    static_version = version;
+                    log(tree.static_transforms());
+                }
+                log(tree.latest_transforms());
+            }
+        }
+    }
+}
+
+/// Splits a transform into its parent and child frame IDs, and the pose of the child.
+fn split_transform(transform: FrameTransform) -> Result<(String, String, Pose), TransformError> {
+    if transform.parent_frame_id.is_empty() || transform.child_frame_id.is_empty() {
+        return Err(TransformError::MissingFrameId);
+    }
+    let pose = Pose {
+        position: transform.translation,
+        orientation: transform.rotation,
+    };
+    Ok((transform.parent_frame_id, transform.child_frame_id, pose))
+}
+
+/// Returns an error if making `parent` the parent of `child` would create a cycle.
+fn check_cycle(
+    frames: &BTreeMap<String, Frame>,
+    parent: &str,
+    child: &str,
+) -> Result<(), TransformError> {
+    if path_to_root(frames, parent).contains(&child) {
+        return Err(TransformError::Cycle {
+            parent: parent.to_string(),
+            child: child.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Returns the IDs of a frame and its ancestors, ending with the root of its tree.
+fn path_to_root<'a>(frames: &'a BTreeMap<String, Frame>, frame: &'a str) -> Vec<&'a str> {
+    let mut path = vec![frame];
+    let mut current = frame;
+    while let Some(next) = frames.get(current) {
+        current = &next.parent;
+        path.push(current);
+    }
+    path
+}
+
+/// Returns the pose of the first frame in the path in the frame that follows the path.
+fn compose_path(
+    frames: &BTreeMap<String, Frame>,
+    path: &[&str],
+    nanos: u64,
+    time: Timestamp,
+) -> Result<Pose, TransformError> {
+    let mut pose = Pose::identity();
+    for id in path {
+        let frame = &frames[*id];
+        let Some(link) = frame.pose_at(nanos) else {
+            return Err(TransformError::Extrapolation {
+                parent: frame.parent.clone(),
+                child: id.to_string(),
+                time,
+            });
+        };
+        pose = link.compose(&pose);
+    }
+    Ok(pose)
+}
+
+fn timestamp_nanos(time: Timestamp) -> u64 {
+    u64::from(time.sec()) * 1_000_000_000 + u64::from(time.nsec())
+}
+
+fn nanos_timestamp(nanos: u64) -> Timestamp {
+    let sec = u32::try_from(nanos / 1_000_000_000).unwrap_or(u32::MAX);
+    Timestamp::new(sec, (nanos % 1_000_000_000) as u32)
+}
+
+fn duration_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use prost::Message;
+
+    use super::*;
+    use crate::schemas::{Quaternion, Vector3};
+    use crate::testutil::RecordingSink;
+
+    fn transform(parent: &str, child: &str, sec: u32, x: f64, yaw: f64) -> FrameTransform {
+        let pose = Pose::new(
+            Vector3::new(x, 0.0, 0.0),
+            Quaternion::from_euler(0.0, 0.0, yaw),
+        );
+        FrameTransform {
+            timestamp: Some(Timestamp::new(sec, 0)),
+            ..FrameTransform::from_pose(parent, child, pose)
+        }
+    }
+
+    fn assert_translation(tf: &FrameTransform, x: f64, y: f64, z: f64) {
+        let t = tf.translation.unwrap();
+        let error = Vector3::new(t.x - x, t.y - y, t.z - z).norm();
+        assert!(error < 1e-9, "{t:?} != ({x}, {y}, {z})");
+    }
+
+    #[test]
+    fn test_lookup_chain() {
+        let tree = TransformTree::new();
+        // world -> base_link is dynamic, base_link -> {lidar, camera} are static.
+        tree.set_transform(transform("world", "base_link", 1, 0.0, 0.0))
+            .unwrap();
+        tree.set_transform(transform("world", "base_link", 3, 4.0, FRAC_PI_2))
+            .unwrap();
+        tree.set_static_transform(transform("base_link", "lidar", 0, 1.0, 0.0))
+            .unwrap();
+        tree.set_static_transform(transform("base_link", "camera", 0, 0.0, FRAC_PI_2))
+            .unwrap();
+
+        let t = |sec| Timestamp::new(sec, 0);
+        assert_translation(&tree.lookup("world", "lidar", t(1)).unwrap(), 1.0, 0.0, 0.0);
+        assert_translation(&tree.lookup("world", "lidar", t(3)).unwrap(), 4.0, 1.0, 0.0);
+
+        // Interpolated halfway, with a rotation of 45 degrees.
+        let tf = tree.lookup("world", "lidar", t(2)).unwrap();
+        let half = std::f64::consts::FRAC_1_SQRT_2;
+        assert_translation(&tf, 2.0 + half, half, 0.0);
+        assert_eq!(tf.timestamp, Some(t(2)));
+
+        // Lookups between siblings, and in the reverse direction.
+        let tf = tree.lookup("camera", "lidar", t(5_000)).unwrap();
+        assert_eq!(tf.parent_frame_id, "camera");
+        assert_translation(&tf, 0.0, -1.0, 0.0);
+        assert_translation(
+            &tree.lookup("lidar", "world", t(1)).unwrap(),
+            -1.0,
+            0.0,
+            0.0,
+        );
+        let identity = tree.lookup("lidar", "lidar", t(0)).unwrap();
+        assert_translation(&identity, 0.0, 0.0, 0.0);
+    }
+
+    #[test]
+    fn test_lookup_errors() {
+        let tree = TransformTree::new();
+        tree.set_transform(transform("world", "base_link", 2, 0.0, 0.0))
+            .unwrap();
+        tree.set_transform(transform("world", "base_link", 3, 0.0, 0.0))
+            .unwrap();
+        tree.set_static_transform(transform("map", "odom", 0, 0.0, 0.0))
+            .unwrap();
+
+        let t = Timestamp::new(1, 0);
+        assert_eq!(
+            tree.lookup("world", "base_link", t),
+            Err(TransformError::Extrapolation {
+                parent: "world".to_string(),
+                child: "base_link".to_string(),
+                time: t,
+            })
+        );
+        assert_eq!(
+            tree.lookup("world", "camera", t),
+            Err(TransformError::FrameNotFound("camera".to_string()))
+        );
+        assert!(matches!(
+            tree.lookup("map", "base_link", t),
+            Err(TransformError::NotConnected { .. })
+        ));
+        assert!(matches!(
+            tree.set_static_transform(transform("base_link", "world", 0, 0.0, 0.0)),
+            Err(TransformError::Cycle { .. })
+        ));
+        assert_eq!(
+            tree.set_transform(FrameTransform::default()),
+            Err(TransformError::MissingFrameId)
+        );
+    }
+
+    #[test]
+    fn test_lookup_latest() {
+        let tree = TransformTree::new();
+        tree.set_static_transform(transform("base_link", "lidar", 0, 1.0, 0.0))
+            .unwrap();
+        assert_eq!(
+            tree.lookup_latest("base_link", "lidar").unwrap().timestamp,
+            Some(Timestamp::new(0, 0))
+        );
+
+        tree.set_transform(transform("world", "base_link", 1, 0.0, 0.0))
+            .unwrap();
+        tree.set_transform(transform("world", "base_link", 3, 2.0, 0.0))
+            .unwrap();
+        tree.set_transform(transform("world", "odom", 1, 0.0, 0.0))
+            .unwrap();
+        tree.set_transform(transform("world", "odom", 2, 5.0, 0.0))
+            .unwrap();
+
+        // Any time after the latest sample is an extrapolation.
+        assert!(matches!(
+            tree.lookup("world", "lidar", Timestamp::new(4, 0)),
+            Err(TransformError::Extrapolation { .. })
+        ));
+        let tf = tree.lookup_latest("world", "lidar").unwrap();
+        assert_eq!(tf.timestamp, Some(Timestamp::new(3, 0)));
+        assert_translation(&tf, 3.0, 0.0, 0.0);
+
+        // The latest time at which both transforms along the path have samples.
+        let tf = tree.lookup_latest("odom", "lidar").unwrap();
+        assert_eq!(tf.timestamp, Some(Timestamp::new(2, 0)));
+        assert_translation(&tf, -3.0, 0.0, 0.0);
+    }
+
+    #[test]
+    fn test_cache_duration() {
+        let tree = TransformTree::new().with_cache_duration(Duration::from_secs(5));
+        for sec in [1, 10, 4, 6] {
+            tree.set_transform(transform("world", "base_link", sec, f64::from(sec), 0.0))
+                .unwrap();
+        }
+        // Samples older than 5 seconds before the latest sample are dropped.
+        let latest = tree.latest_transforms();
+        assert_eq!(latest.transforms.len(), 1);
+        assert_eq!(latest.transforms[0].timestamp, Some(Timestamp::new(10, 0)));
+        assert!(tree
+            .lookup("world", "base_link", Timestamp::new(4, 0))
+            .is_err());
+        let tf = tree
+            .lookup("world", "base_link", Timestamp::new(8, 0))
+            .unwrap();
+        assert_translation(&tf, 8.0, 0.0, 0.0);
+        assert!(tree.static_transforms().transforms.is_empty());
+    }
+
+    // Time is paused, so the sleep below advances it deterministically, rather than depending on
+    // how the publisher is scheduled in real time.
+    #[tokio::test(start_paused = true)]
+    async fn test_publisher() {
+        let ctx = LogContext::new();
+        let sink = Arc::new(RecordingSink::new());
+        ctx.add_sink(sink.clone());
+
+        let tree = Arc::new(TransformTree::new());
+        tree.set_static_transform(transform("base_link", "lidar", 0, 1.0, 0.0))
+            .unwrap();
+        tree.set_transform(transform("world", "base_link", 1, 0.0, 0.0))
+            .unwrap();
+        let publisher = TransformPublisher::new(tree.clone())
+            .rate(100.0)
+            .with_context(&ctx)
+            .start()
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        publisher.stop();
+
+        let statics = tree.static_transforms().encode_to_vec();
+        let latest = tree.latest_transforms().encode_to_vec();
+        let recorded = sink.recorded.lock();
+        assert!(recorded.len() > 2);
+        assert_eq!(recorded[0].msg, statics);
+        assert!(recorded[1..].iter().all(|call| call.msg == latest));
+        assert!(ctx.get_channel_by_topic("/tf").is_some());
+    }
+}
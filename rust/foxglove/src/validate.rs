@@ -0,0 +1,221 @@
+//! Validation of messages before they are logged.
+//!
+//! Messages that are malformed, such as a [`PointCloud`](crate::schemas::PointCloud) whose data
+//! is not a whole number of points, or a [`SceneEntity`](crate::schemas::SceneEntity) without a
+//! frame ID, are logged successfully, but render as nothing in the Foxglove app. To catch such
+//! mistakes during development, enable validation for a channel with
+//! [`ChannelBuilder::validation`](crate::ChannelBuilder::validation), or for every channel in a
+//! context with [`LogContext::set_validation`](crate::LogContext::set_validation).
+//!
+//! When validation is enabled, [`TypedChannel`](crate::TypedChannel) checks each message with
+//! [`Encode::validate`](crate::Encode::validate) before logging it, and reports any violations
+//! according to the [`ValidationMode`]. Messages are logged whether or not they are valid.
+//! Validation is off by default, since it adds overhead to every call to `log`.
+//!
+//! ```no_run
+//! use foxglove::validate::ValidationMode;
+//! use foxglove::schemas::SceneUpdate;
+//! use foxglove::ChannelBuilder;
+//!
+//! # fn func() -> Result<(), foxglove::FoxgloveError> {
+//! let channel = ChannelBuilder::new("/scene")
+//!     .validation(ValidationMode::Warn)
+//!     .build_typed::<SceneUpdate>()?;
+//! # Ok(()) }
+//! ```
+//!
+//! The [`Validate`] trait is implemented for the well-known [schema types](crate::schemas). The
+//! checks include the layout of point clouds, grids and raw images, unit quaternions, required
+//! frame IDs, and the lengths of fixed-size arrays. Nested messages are validated as well.
+//!
+//! A schema type logged with another encoding, such as [`Json`](crate::json::Json) or
+//! [`Cdr`](crate::ros2::Cdr), is validated in the same way. Each encoding trait, such as
+//! [`JsonEncode`](crate::json::JsonEncode), has a `validate` method to which the wrapper
+//! forwards. The method accepts every message by default, and the schema types override it to
+//! check their [`Validate`] implementation.
+
+use std::fmt;
+use std::sync::Arc;
+
+mod invariants;
+#[cfg(test)]
+mod tests;
+
+/// A violation of a message invariant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// The path of the field, such as `entities[0].frame_id`.
+    ///
+    /// Empty if the violation applies to the top-level message as a whole.
+    pub field: String,
+    /// A description of the violation.
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.field.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.field, self.message)
+        }
+    }
+}
+
+/// An error indicating that a message violates one or more invariants.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub struct ValidationError {
+    /// The violations, in the order they were found.
+    pub violations: Vec<Violation>,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid message: ")?;
+        for (i, violation) in self.violations.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{violation}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Collects violations while validating a message. See [`Validate`].
+#[derive(Debug, Default)]
+pub struct Validator {
+    prefix: String,
+    violations: Vec<Violation>,
+}
+
+impl Validator {
+    /// Creates a new validator with no violations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reports a violation for a field of the current message.
+    ///
+    /// An empty field name reports a violation for the message as a whole.
+    pub fn violation(&mut self, field: &str, message: impl Into<String>) {
+        let field = if field.is_empty() {
+            self.prefix.trim_end_matches('.').to_string()
+        } else {
+            format!("{}{field}", self.prefix)
+        };
+        self.violations.push(Violation {
+            field,
+            message: message.into(),
+        });
+    }
+
+    /// Reports a violation if the condition does not hold.
+    pub fn check(&mut self, field: &str, condition: bool, message: impl FnOnce() -> String) {
+        if !condition {
+            self.violation(field, message());
+        }
+    }
+
+    /// Validates an optional nested message.
+    pub fn message<T: Validate>(&mut self, field: &str, value: Option<&T>) {
+        if let Some(value) = value {
+            self.nested(field, value);
+        }
+    }
+
+    /// Validates each message in a repeated field.
+    pub fn messages<T: Validate>(&mut self, field: &str, values: &[T]) {
+        for (i, value) in values.iter().enumerate() {
+            self.nested(&format!("{field}[{i}]"), value);
+        }
+    }
+
+    fn nested<T: Validate>(&mut self, field: &str, value: &T) {
+        let len = self.prefix.len();
+        self.prefix.push_str(field);
+        self.prefix.push('.');
+        value.validate_fields(self);
+        self.prefix.truncate(len);
+    }
+
+    /// Returns an error if any violations were reported.
+    pub fn finish(self) -> Result<(), ValidationError> {
+        if self.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError {
+                violations: self.violations,
+            })
+        }
+    }
+}
+
+/// A message with invariants that can be checked before it is logged.
+///
+/// This trait is implemented for the well-known [schema types](crate::schemas). To validate a
+/// custom message, implement this trait, and override [`Encode::validate`](crate::Encode::validate)
+/// to call [`validate`].
+pub trait Validate {
+    /// Reports violations of the invariants of the message, and of its nested messages.
+    ///
+    /// The default implementation reports no violations.
+    fn validate_fields(&self, _validator: &mut Validator) {}
+}
+
+/// Validates a message, and returns an error listing any violations.
+pub fn validate<T: Validate + ?Sized>(msg: &T) -> Result<(), ValidationError> {
+    let mut validator = Validator::new();
+    msg.validate_fields(&mut validator);
+    validator.finish()
+}
+
+/// A callback which receives the topic of a channel, and the violations of a message logged to it.
+pub type ValidationCallback = dyn Fn(&str, &ValidationError) + Send + Sync;
+
+/// How a [`TypedChannel`](crate::TypedChannel) validates messages before logging them.
+///
+/// In every mode, messages are logged whether or not they are valid.
+#[derive(Clone, Default)]
+pub enum ValidationMode {
+    /// Messages are not validated.
+    #[default]
+    Off,
+    /// Violations are reported as warnings with [`tracing`].
+    Warn,
+    /// Violations are reported to a callback, with the topic of the channel.
+    Callback(Arc<ValidationCallback>),
+}
+
+impl ValidationMode {
+    /// Creates a mode which reports violations to a callback.
+    pub fn callback(callback: impl Fn(&str, &ValidationError) + Send + Sync + 'static) -> Self {
+        Self::Callback(Arc::new(callback))
+    }
+
+    /// Validates a message, if validation is enabled, and reports any violations.
+    pub(crate) fn check(
+        &self,
+        topic: &str,
+        validate: impl FnOnce() -> Result<(), ValidationError>,
+    ) {
+        let report: &ValidationCallback = match self {
+            Self::Off => return,
+            Self::Warn => &|topic, err| tracing::warn!("{topic}: {err}"),
+            Self::Callback(callback) => callback.as_ref(),
+        };
+        if let Err(err) = validate() {
+            report(topic, &err);
+        }
+    }
+}
+
+impl fmt::Debug for ValidationMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Off => write!(f, "Off"),
+            Self::Warn => write!(f, "Warn"),
+            Self::Callback(_) => write!(f, "Callback"),
+        }
+    }
+}
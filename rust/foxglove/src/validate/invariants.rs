@@ -0,0 +1,217 @@
+//! Hand-written invariants for the well-known schema types.
+//!
+//! The generated [`Validate`](super::Validate) implementations call these methods, and recurse
+//! into nested messages.
+
+use super::Validator;
+use crate::point_cloud::{numeric_type_size, PointCloudReader};
+use crate::schemas::location_fix::PositionCovarianceType;
+use crate::schemas::packed_element_field::NumericType;
+use crate::schemas::{
+    CameraCalibration, CompressedImage, FrameTransform, GeoJson, Grid, LaserScan, LocationFix,
+    PackedElementField, PointCloud, Quaternion, RawImage, SceneEntity,
+};
+
+/// Tolerance on the norm of a quaternion representing a rotation.
+const UNIT_NORM_TOLERANCE: f64 = 1e-3;
+
+/// Returns the number of bytes per pixel of a raw image encoding, or `None` if it is unknown.
+fn bytes_per_pixel(encoding: &str) -> Option<u32> {
+    match encoding {
+        "mono8" | "8UC1" | "bayer_rggb8" | "bayer_bggr8" | "bayer_gbrg8" | "bayer_grbg8" => Some(1),
+        "mono16" | "16UC1" | "uyvy" | "yuv422" | "yuyv" | "yuv422_yuy2" => Some(2),
+        "rgb8" | "bgr8" | "8UC3" => Some(3),
+        "rgba8" | "bgra8" | "32FC1" => Some(4),
+        _ => None,
+    }
+}
+
+/// Checks that each field fits within a stride, and returns the names of the fields.
+fn check_fields<'a>(
+    validator: &mut Validator,
+    fields: &'a [PackedElementField],
+    stride: u32,
+) -> Vec<&'a str> {
+    for (i, field) in fields.iter().enumerate() {
+        let size = NumericType::try_from(field.r#type)
+            .ok()
+            .and_then(numeric_type_size);
+        match size {
+            None => validator.violation(&format!("fields[{i}].type"), "unknown numeric type"),
+            Some(size) => validator.check(
+                &format!("fields[{i}].offset"),
+                u64::from(field.offset) + size as u64 <= u64::from(stride),
+                || {
+                    format!(
+                        "field {} does not fit in the stride of {stride}",
+                        field.name
+                    )
+                },
+            ),
+        }
+    }
+    fields.iter().map(|f| f.name.as_str()).collect()
+}
+
+impl CameraCalibration {
+    pub(crate) fn check_invariants(&self, validator: &mut Validator) {
+        for (field, values, len) in [("k", &self.k, 9), ("r", &self.r, 9), ("p", &self.p, 12)] {
+            validator.check(field, values.is_empty() || values.len() == len, || {
+                format!("expected {len} values, found {}", values.len())
+            });
+        }
+    }
+}
+
+impl CompressedImage {
+    pub(crate) fn check_invariants(&self, validator: &mut Validator) {
+        validator.check("format", !self.format.is_empty(), || {
+            "format is required".to_string()
+        });
+    }
+}
+
+impl FrameTransform {
+    pub(crate) fn check_invariants(&self, validator: &mut Validator) {
+        validator.check("parent_frame_id", !self.parent_frame_id.is_empty(), || {
+            "parent frame ID is required".to_string()
+        });
+        validator.check("child_frame_id", !self.child_frame_id.is_empty(), || {
+            "child frame ID is required".to_string()
+        });
+    }
+}
+
+impl GeoJson {
+    /// The data must be a JSON object with a `type` member, with or without the `geo` feature.
+    /// The full GeoJSON structure is only checked by `GeoJson::from_str`.
+    pub(crate) fn check_invariants(&self, validator: &mut Validator) {
+        let result = serde_json::from_str::<serde_json::Value>(&self.geojson)
+            .map_err(|e| e.to_string())
+            .and_then(|value| match value.get("type") {
+                Some(serde_json::Value::String(_)) => Ok(()),
+                _ => Err("expected an object with a type".to_string()),
+            });
+        if let Err(err) = result {
+            validator.violation("geojson", format!("invalid GeoJSON: {err}"));
+        }
+    }
+}
+
+impl Grid {
+    pub(crate) fn check_invariants(&self, validator: &mut Validator) {
+        check_fields(validator, &self.fields, self.cell_stride);
+        let row_len = u64::from(self.column_count) * u64::from(self.cell_stride);
+        validator.check("row_stride", row_len <= u64::from(self.row_stride), || {
+            format!(
+                "{} columns of {} bytes do not fit in the row stride of {}",
+                self.column_count, self.cell_stride, self.row_stride
+            )
+        });
+        let data_len = self.data.len() as u64;
+        validator.check(
+            "data",
+            match u64::from(self.row_stride) {
+                0 => data_len == 0,
+                stride => data_len.is_multiple_of(stride),
+            },
+            || {
+                format!(
+                    "data length {data_len} is not a multiple of the row stride {}",
+                    self.row_stride
+                )
+            },
+        );
+    }
+}
+
+impl LaserScan {
+    pub(crate) fn check_invariants(&self, validator: &mut Validator) {
+        validator.check(
+            "intensities",
+            self.intensities.is_empty() || self.intensities.len() == self.ranges.len(),
+            || {
+                format!(
+                    "{} intensities do not match {} ranges",
+                    self.intensities.len(),
+                    self.ranges.len()
+                )
+            },
+        );
+    }
+}
+
+impl LocationFix {
+    pub(crate) fn check_invariants(&self, validator: &mut Validator) {
+        validator.check("latitude", (-90.0..=90.0).contains(&self.latitude), || {
+            format!("latitude {} is not between -90 and 90", self.latitude)
+        });
+        validator.check(
+            "longitude",
+            (-180.0..=180.0).contains(&self.longitude),
+            || format!("longitude {} is not between -180 and 180", self.longitude),
+        );
+        let len = self.position_covariance.len();
+        let known = self.position_covariance_type() != PositionCovarianceType::Unknown;
+        validator.check(
+            "position_covariance",
+            len == 9 || (len == 0 && !known),
+            || format!("covariance has {len} elements, but 9 are required"),
+        );
+    }
+}
+
+impl PointCloud {
+    pub(crate) fn check_invariants(&self, validator: &mut Validator) {
+        let names = check_fields(validator, &self.fields, self.point_stride);
+        let coordinates = ["x", "y", "z"].iter().filter(|c| names.contains(c)).count();
+        validator.check("fields", coordinates >= 2, || {
+            "at least two of the fields x, y and z are required".to_string()
+        });
+        if let Err(err) = PointCloudReader::new(self) {
+            validator.violation("data", err.to_string());
+        }
+    }
+}
+
+impl Quaternion {
+    pub(crate) fn check_invariants(&self, validator: &mut Validator) {
+        let norm = self.norm();
+        validator.check("", (norm - 1.0).abs() <= UNIT_NORM_TOLERANCE, || {
+            format!("quaternion has norm {norm}, but a unit quaternion is required")
+        });
+    }
+}
+
+impl RawImage {
+    pub(crate) fn check_invariants(&self, validator: &mut Validator) {
+        let Some(bpp) = bytes_per_pixel(&self.encoding) else {
+            validator.violation("encoding", format!("unknown encoding {:?}", self.encoding));
+            return;
+        };
+        let row_len = u64::from(self.width) * u64::from(bpp);
+        validator.check("step", u64::from(self.step) >= row_len, || {
+            format!(
+                "step {} is less than {} pixels of {bpp} bytes",
+                self.step, self.width
+            )
+        });
+        let image_len = u64::from(self.step) * u64::from(self.height);
+        validator.check("data", self.data.len() as u64 >= image_len, || {
+            format!(
+                "data length {} is less than {} rows of {} bytes",
+                self.data.len(),
+                self.height,
+                self.step
+            )
+        });
+    }
+}
+
+impl SceneEntity {
+    pub(crate) fn check_invariants(&self, validator: &mut Validator) {
+        validator.check("frame_id", !self.frame_id.is_empty(), || {
+            "frame ID is required".to_string()
+        });
+    }
+}
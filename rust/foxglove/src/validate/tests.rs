@@ -0,0 +1,234 @@
+use super::*;
+use crate::point_cloud::PointCloudBuilder;
+use crate::schemas::{
+    CubePrimitive, FrameTransform, GeoJson, LocationFix, Point3, Pose, Quaternion, RawImage,
+    SceneEntity, SceneUpdate,
+};
+use crate::testutil::RecordingSink;
+use crate::{ChannelBuilder, Encode, LogContext};
+use parking_lot::Mutex;
+
+fn violations(err: ValidationError) -> Vec<(String, String)> {
+    err.violations
+        .into_iter()
+        .map(|v| (v.field, v.message))
+        .collect()
+}
+
+#[test]
+fn test_valid_messages() {
+    let cloud = PointCloudBuilder::new()
+        .frame_id("lidar")
+        .points(&[Point3::new(1.0, 2.0, 3.0), Point3::new(4.0, 5.0, 6.0)])
+        .build()
+        .unwrap();
+    assert_eq!(cloud.validate(), Ok(()));
+
+    let tf = FrameTransform::from_pose("world", "base_link", Pose::identity());
+    assert_eq!(tf.validate(), Ok(()));
+
+    let image = RawImage {
+        width: 2,
+        height: 2,
+        encoding: "rgb8".to_string(),
+        step: 6,
+        data: vec![0; 12].into(),
+        ..Default::default()
+    };
+    assert_eq!(image.validate(), Ok(()));
+}
+
+#[test]
+fn test_nested_violations() {
+    let update = SceneUpdate {
+        entities: vec![
+            SceneEntity {
+                frame_id: "world".to_string(),
+                ..Default::default()
+            },
+            SceneEntity {
+                cubes: vec![CubePrimitive {
+                    pose: Some(Pose {
+                        position: None,
+                        orientation: Some(Quaternion::default()),
+                    }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+    let err = update.validate().unwrap_err();
+    assert_eq!(
+        violations(err.clone()),
+        vec![
+            (
+                "entities[1].frame_id".to_string(),
+                "frame ID is required".to_string()
+            ),
+            (
+                "entities[1].cubes[0].pose.orientation".to_string(),
+                "quaternion has norm 0, but a unit quaternion is required".to_string()
+            ),
+        ]
+    );
+    assert!(err.to_string().starts_with(
+        "Invalid message: entities[1].frame_id: frame ID is required; entities[1].cubes[0]"
+    ));
+
+    // A top-level violation has an empty field path.
+    let err = Quaternion::new(0.0, 0.0, 0.0, 2.0).validate().unwrap_err();
+    assert_eq!(err.violations[0].field, "");
+}
+
+#[test]
+fn test_layout_violations() {
+    let mut cloud = PointCloudBuilder::new()
+        .column("x", &[1.0f32, 2.0])
+        .build()
+        .unwrap();
+    cloud.data.truncate(6);
+    let fields: Vec<_> = violations(cloud.validate().unwrap_err())
+        .into_iter()
+        .map(|(field, _)| field)
+        .collect();
+    assert_eq!(fields, vec!["fields", "data"]);
+
+    let image = RawImage {
+        width: 4,
+        height: 2,
+        encoding: "rgba8".to_string(),
+        step: 8,
+        data: vec![0; 8].into(),
+        ..Default::default()
+    };
+    let fields: Vec<_> = violations(image.validate().unwrap_err())
+        .into_iter()
+        .map(|(field, _)| field)
+        .collect();
+    assert_eq!(fields, vec!["step", "data"]);
+
+    let image = RawImage {
+        encoding: "rgb565".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(
+        violations(image.validate().unwrap_err()),
+        vec![(
+            "encoding".to_string(),
+            "unknown encoding \"rgb565\"".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_location_violations() {
+    let fix = LocationFix::new(47.6, -122.3, 10.0).with_diagonal_covariance(1.0, 1.0, 4.0);
+    assert_eq!(fix.validate(), Ok(()));
+
+    let mut fix = LocationFix::new(91.0, 181.0, 0.0).with_diagonal_covariance(1.0, 1.0, 4.0);
+    fix.position_covariance.truncate(3);
+    let fields: Vec<_> = violations(fix.validate().unwrap_err())
+        .into_iter()
+        .map(|(field, _)| field)
+        .collect();
+    assert_eq!(fields, vec!["latitude", "longitude", "position_covariance"]);
+
+    let geojson = GeoJson {
+        geojson: r#"{"type": "Point", "coordinates": [1.0, 2.0]}"#.to_string(),
+    };
+    assert_eq!(geojson.validate(), Ok(()));
+    // The structure is not checked, so validation is the same with and without the geo feature.
+    let geojson = GeoJson {
+        geojson: r#"{"type": "Point"}"#.to_string(),
+    };
+    assert_eq!(geojson.validate(), Ok(()));
+    let geojson = GeoJson {
+        geojson: "[1.0, 2.0]".to_string(),
+    };
+    let fields: Vec<_> = violations(geojson.validate().unwrap_err())
+        .into_iter()
+        .map(|(field, _)| field)
+        .collect();
+    assert_eq!(fields, vec!["geojson"]);
+}
+
+#[test]
+fn test_validation_mode() {
+    let ctx = LogContext::new();
+    let sink = Arc::new(RecordingSink::new());
+    ctx.add_sink(sink.clone());
+
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let reported_clone = reported.clone();
+    ctx.set_validation(ValidationMode::callback(move |topic, err| {
+        reported_clone
+            .lock()
+            .push((topic.to_string(), err.violations.len()));
+    }));
+
+    let checked = ChannelBuilder::new("/checked")
+        .with_context(&ctx)
+        .build_typed::<SceneEntity>()
+        .unwrap();
+    let unchecked = ChannelBuilder::new("/unchecked")
+        .with_context(&ctx)
+        .validation(ValidationMode::Off)
+        .build_typed::<SceneEntity>()
+        .unwrap();
+
+    let invalid = SceneEntity::default();
+    checked.log(&invalid);
+    unchecked.log(&invalid);
+    checked.log(&SceneEntity {
+        frame_id: "world".to_string(),
+        ..Default::default()
+    });
+
+    assert_eq!(*reported.lock(), vec![("/checked".to_string(), 1)]);
+    // Invalid messages are still logged.
+    assert_eq!(sink.recorded.lock().len(), 3);
+
+    // Changing the context's mode affects existing channels.
+    ctx.set_validation(ValidationMode::Off);
+    checked.log(&invalid);
+    assert_eq!(reported.lock().len(), 1);
+    assert_eq!(sink.recorded.lock().len(), 4);
+}
+
+#[test]
+fn test_wrapped_validation() {
+    fn check<T: Encode>(msg: &T) -> Vec<String> {
+        let ctx = LogContext::new();
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let reported_clone = reported.clone();
+        ctx.set_validation(ValidationMode::callback(move |topic, _| {
+            reported_clone.lock().push(topic.to_string());
+        }));
+        let channel = ChannelBuilder::new("/wrapped")
+            .with_context(&ctx)
+            .build_typed::<T>()
+            .unwrap();
+        channel.log(msg);
+        let reported = reported.lock().clone();
+        reported
+    }
+
+    let invalid = SceneEntity::default();
+    assert_eq!(check(&crate::ros2::Cdr(invalid.clone())), ["/wrapped"]);
+    assert_eq!(check(&crate::ros1::Ros1(invalid.clone())), ["/wrapped"]);
+    #[cfg(feature = "flatbuffers")]
+    assert_eq!(
+        check(&crate::flatbuffer::FlatBuffer(invalid.clone())),
+        ["/wrapped"]
+    );
+    #[cfg(feature = "serde")]
+    assert_eq!(check(&crate::json::Json(invalid.clone())), ["/wrapped"]);
+
+    let valid = SceneEntity {
+        frame_id: "world".to_string(),
+        ..Default::default()
+    };
+    assert!(check(&crate::ros2::Cdr(valid)).is_empty());
+}
@@ -0,0 +1,439 @@
+//! Framing H.264 and H.265 bitstreams as [`CompressedVideo`] messages.
+//!
+//! Each [`CompressedVideo`] message must contain exactly one access unit, which is the set of NAL
+//! units needed to decode one frame, in Annex B format. Each keyframe must also include the
+//! parameter sets needed to decode it. Encoders and cameras commonly produce a continuous byte
+//! stream instead, and send parameter sets only once, at the start of the stream.
+//!
+//! An [`AnnexBFramer`] splits an Annex B byte stream into access units, and caches the most
+//! recent parameter sets, so that they can be inserted before keyframes which lack them. Data can
+//! be pushed in chunks of any size, which need not align with NAL unit boundaries:
+//!
+//! ```no_run
+//! use std::io::Read;
+//!
+//! use foxglove::schemas::{CompressedVideo, Timestamp};
+//! use foxglove::video::{AnnexBFramer, VideoCodec};
+//! use foxglove::TypedChannel;
+//!
+//! # fn func(mut camera: impl Read, now: impl Fn() -> Timestamp) -> Result<(), Box<dyn std::error::Error>> {
+//! let channel = TypedChannel::<CompressedVideo>::new("/camera/video")?;
+//! let mut framer = AnnexBFramer::new(VideoCodec::H264).frame_id("camera");
+//! let mut buf = [0; 4096];
+//! loop {
+//!     let len = camera.read(&mut buf)?;
+//!     if len == 0 {
+//!         break;
+//!     }
+//!     for frame in framer.push(&buf[..len], now()) {
+//!         channel.log(&frame.message);
+//!     }
+//! }
+//! for frame in framer.flush() {
+//!     channel.log(&frame.message);
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! An access unit is returned once the first NAL unit of the following access unit has been
+//! received, or when the framer is flushed. Its timestamp is the timestamp passed with the data
+//! containing the start of its first NAL unit.
+
+use std::fmt;
+
+use bytes::Bytes;
+
+use crate::schemas::{CompressedVideo, Timestamp};
+
+/// The Annex B start code which precedes each NAL unit in the framed output.
+const START_CODE: [u8; 4] = [0, 0, 0, 1];
+
+/// A video codec which uses Annex B framing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VideoCodec {
+    /// H.264, also known as AVC.
+    H264,
+    /// H.265, also known as HEVC.
+    H265,
+}
+
+impl VideoCodec {
+    /// Returns the value of [`CompressedVideo::format`] for the codec.
+    pub fn format(self) -> &'static str {
+        match self {
+            Self::H264 => "h264",
+            Self::H265 => "h265",
+        }
+    }
+
+    /// Returns the parameter sets which a keyframe requires.
+    fn required_parameter_sets(self) -> &'static [ParameterSet] {
+        match self {
+            Self::H264 => &[ParameterSet::Sps, ParameterSet::Pps],
+            Self::H265 => &[ParameterSet::Vps, ParameterSet::Sps, ParameterSet::Pps],
+        }
+    }
+
+    /// Classifies a NAL unit, which must not be empty.
+    fn classify(self, nal: &[u8]) -> NalKind {
+        // The first bit of a slice header is set in the first slice of a picture. For H.264, this
+        // is because `first_mb_in_slice` is zero, which is encoded as a single set bit.
+        let first_slice = |header_len: usize| nal.get(header_len).is_some_and(|b| b & 0x80 != 0);
+        match self {
+            Self::H264 => match nal[0] & 0x1f {
+                1 => NalKind::Slice {
+                    first: first_slice(1),
+                    keyframe: false,
+                },
+                5 => NalKind::Slice {
+                    first: first_slice(1),
+                    keyframe: true,
+                },
+                7 => NalKind::ParameterSet(ParameterSet::Sps),
+                8 => NalKind::ParameterSet(ParameterSet::Pps),
+                9 => NalKind::AccessUnitDelimiter,
+                6 | 14..=18 => NalKind::Prefix,
+                _ => NalKind::Other,
+            },
+            Self::H265 => match (nal[0] >> 1) & 0x3f {
+                t @ 0..=31 => NalKind::Slice {
+                    first: first_slice(2),
+                    // Intra random access point pictures.
+                    keyframe: (16..=23).contains(&t),
+                },
+                32 => NalKind::ParameterSet(ParameterSet::Vps),
+                33 => NalKind::ParameterSet(ParameterSet::Sps),
+                34 => NalKind::ParameterSet(ParameterSet::Pps),
+                35 => NalKind::AccessUnitDelimiter,
+                39 | 41..=44 | 48..=55 => NalKind::Prefix,
+                _ => NalKind::Other,
+            },
+        }
+    }
+}
+
+impl fmt::Display for VideoCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.format())
+    }
+}
+
+/// A parameter set, which is cached by the framer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParameterSet {
+    Vps = 0,
+    Sps = 1,
+    Pps = 2,
+}
+
+/// The role of a NAL unit in an access unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NalKind {
+    /// A slice of a picture.
+    Slice { first: bool, keyframe: bool },
+    /// A parameter set, which starts a new access unit.
+    ParameterSet(ParameterSet),
+    /// An access unit delimiter, which is always the first NAL unit of an access unit.
+    AccessUnitDelimiter,
+    /// A NAL unit other than a parameter set, such as SEI, which starts a new access unit.
+    Prefix,
+    /// A NAL unit which belongs to the current access unit.
+    Other,
+}
+
+/// A frame of video, framed as a [`CompressedVideo`] message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoFrame {
+    /// The message containing the access unit.
+    pub message: CompressedVideo,
+    /// Whether the frame is a keyframe, which can be decoded without preceding frames.
+    pub keyframe: bool,
+}
+
+/// An access unit which has not yet been completed.
+#[derive(Debug, Default)]
+struct AccessUnit {
+    nals: Vec<Vec<u8>>,
+    timestamp: Option<Timestamp>,
+    has_slice: bool,
+    keyframe: bool,
+    parameter_sets: [bool; 3],
+}
+
+/// Splits an Annex B byte stream into access units, and frames them as [`CompressedVideo`]
+/// messages.
+///
+/// See the [module documentation](self) for an example.
+#[must_use]
+#[derive(Debug)]
+pub struct AnnexBFramer {
+    codec: VideoCodec,
+    frame_id: String,
+    /// Data which has not yet been split into NAL units.
+    buffer: Vec<u8>,
+    /// The offset in the buffer of the NAL unit which is being received.
+    nal_start: Option<usize>,
+    /// The timestamp of the NAL unit which is being received.
+    nal_timestamp: Option<Timestamp>,
+    /// The offset in the buffer from which to continue searching for a start code.
+    scan_from: usize,
+    access_unit: AccessUnit,
+    parameter_sets: [Option<Vec<u8>>; 3],
+}
+
+impl AnnexBFramer {
+    /// Creates a framer for the given codec.
+    pub fn new(codec: VideoCodec) -> Self {
+        Self {
+            codec,
+            frame_id: String::new(),
+            buffer: Vec::new(),
+            nal_start: None,
+            nal_timestamp: None,
+            scan_from: 0,
+            access_unit: AccessUnit::default(),
+            parameter_sets: Default::default(),
+        }
+    }
+
+    /// Sets the frame of reference of the messages.
+    pub fn frame_id(mut self, frame_id: impl Into<String>) -> Self {
+        self.frame_id = frame_id.into();
+        self
+    }
+
+    /// Returns the codec of the stream.
+    pub fn codec(&self) -> VideoCodec {
+        self.codec
+    }
+
+    /// Pushes data from the byte stream, and returns the access units which it completes.
+    ///
+    /// The timestamp applies to the NAL units which start in this data.
+    pub fn push(&mut self, data: &[u8], timestamp: Timestamp) -> Vec<VideoFrame> {
+        let mut frames = Vec::new();
+        self.buffer.extend_from_slice(data);
+        let mut i = self.scan_from;
+        while i + 3 <= self.buffer.len() {
+            if self.buffer[i..i + 3] != [0, 0, 1] {
+                i += 1;
+                continue;
+            }
+            if let Some(start) = self.nal_start {
+                let nal = self.buffer[start..i].to_vec();
+                frames.extend(self.process_nal(nal));
+            }
+            i += 3;
+            self.nal_start = Some(i);
+            self.nal_timestamp = Some(timestamp);
+        }
+
+        // Discard the data which has been processed, keeping the last two bytes, which may be the
+        // start of a start code, if no NAL unit is being received.
+        let keep_from = self
+            .nal_start
+            .unwrap_or_else(|| self.buffer.len().saturating_sub(2));
+        self.buffer.drain(..keep_from);
+        self.nal_start = self.nal_start.map(|start| start - keep_from);
+        self.scan_from = i.saturating_sub(keep_from);
+        frames
+    }
+
+    /// Completes the NAL unit and access unit being received, and returns the access units which
+    /// they complete.
+    ///
+    /// This should be called at the end of the stream.
+    pub fn flush(&mut self) -> Vec<VideoFrame> {
+        let mut frames = Vec::new();
+        if let Some(start) = self.nal_start.take() {
+            let nal = self.buffer[start..].to_vec();
+            frames.extend(self.process_nal(nal));
+        }
+        self.buffer.clear();
+        self.scan_from = 0;
+        frames.extend(self.finish_access_unit());
+        frames
+    }
+
+    /// Adds a NAL unit to the current access unit, and returns the previous access unit, if the
+    /// NAL unit starts a new one.
+    fn process_nal(&mut self, mut nal: Vec<u8>) -> Option<VideoFrame> {
+        // A NAL unit cannot end with a zero byte, so these belong to the next start code.
+        let len = nal.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+        nal.truncate(len);
+        if nal.is_empty() {
+            return None;
+        }
+
+        let kind = self.codec.classify(&nal);
+        let starts_access_unit = match kind {
+            NalKind::Slice { first, .. } => first,
+            NalKind::ParameterSet(_) | NalKind::AccessUnitDelimiter | NalKind::Prefix => true,
+            NalKind::Other => false,
+        };
+        let frame = if starts_access_unit && self.access_unit.has_slice {
+            self.finish_access_unit()
+        } else {
+            None
+        };
+
+        let au = &mut self.access_unit;
+        match kind {
+            NalKind::Slice { keyframe, .. } => {
+                au.has_slice = true;
+                au.keyframe |= keyframe;
+            }
+            NalKind::ParameterSet(ps) => {
+                au.parameter_sets[ps as usize] = true;
+                self.parameter_sets[ps as usize] = Some(nal.clone());
+            }
+            _ => (),
+        }
+        au.timestamp = au.timestamp.or(self.nal_timestamp);
+        au.nals.push(nal);
+        frame
+    }
+
+    /// Frames the current access unit, and starts a new one.
+    ///
+    /// Returns `None` if the access unit does not contain a picture.
+    fn finish_access_unit(&mut self) -> Option<VideoFrame> {
+        let mut au = std::mem::take(&mut self.access_unit);
+        if !au.has_slice {
+            return None;
+        }
+        if au.keyframe {
+            // Insert missing parameter sets after the access unit delimiter, if there is one.
+            let mut index = usize::from(
+                au.nals.first().map(|nal| self.codec.classify(nal))
+                    == Some(NalKind::AccessUnitDelimiter),
+            );
+            for &ps in self.codec.required_parameter_sets() {
+                if au.parameter_sets[ps as usize] {
+                    continue;
+                }
+                if let Some(nal) = &self.parameter_sets[ps as usize] {
+                    au.nals.insert(index, nal.clone());
+                    index += 1;
+                } else {
+                    tracing::debug!("Keyframe without {ps:?}, which has not been received");
+                }
+            }
+        }
+
+        let len = au.nals.iter().map(|nal| START_CODE.len() + nal.len()).sum();
+        let mut data = Vec::with_capacity(len);
+        for nal in &au.nals {
+            data.extend_from_slice(&START_CODE);
+            data.extend_from_slice(nal);
+        }
+        Some(VideoFrame {
+            message: CompressedVideo {
+                timestamp: au.timestamp,
+                frame_id: self.frame_id.clone(),
+                data: Bytes::from(data),
+                format: self.codec.format().to_string(),
+            },
+            keyframe: au.keyframe,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPS: &[u8] = &[0x67, 0x42, 0x00, 0x1e];
+    const PPS: &[u8] = &[0x68, 0xce, 0x38, 0x80];
+    const AUD: &[u8] = &[0x09, 0xf0];
+    const IDR: &[u8] = &[0x65, 0x88, 0x84, 0x00, 0x33];
+    const IDR_SECOND_SLICE: &[u8] = &[0x65, 0x40, 0x11];
+    const P_SLICE: &[u8] = &[0x41, 0x9a, 0x02];
+
+    fn annex_b(nals: &[&[u8]]) -> Vec<u8> {
+        nals.iter()
+            .flat_map(|nal| START_CODE.iter().chain(nal.iter()).copied())
+            .collect()
+    }
+
+    #[test]
+    fn test_split_access_units() {
+        // Three-byte start codes and trailing zeros are normalized.
+        let mut stream = annex_b(&[SPS, PPS, IDR, IDR_SECOND_SLICE]);
+        stream.extend_from_slice(&[0, 0, 1]);
+        stream.extend_from_slice(P_SLICE);
+        stream.extend_from_slice(&[0, 0]);
+        stream.extend(annex_b(&[P_SLICE]));
+
+        let mut framer = AnnexBFramer::new(VideoCodec::H264).frame_id("camera");
+        let mut frames = Vec::new();
+        for (i, chunk) in stream.chunks(3).enumerate() {
+            frames.extend(framer.push(chunk, Timestamp::new(i as u32, 0)));
+        }
+        frames.extend(framer.flush());
+
+        assert_eq!(frames.len(), 3);
+        assert!(frames[0].keyframe);
+        assert_eq!(
+            frames[0].message.data,
+            annex_b(&[SPS, PPS, IDR, IDR_SECOND_SLICE])
+        );
+        assert_eq!(frames[0].message.format, "h264");
+        assert_eq!(frames[0].message.frame_id, "camera");
+        // The first chunk contains only part of the start code.
+        assert_eq!(frames[0].message.timestamp, Some(Timestamp::new(1, 0)));
+        assert!(!frames[1].keyframe);
+        assert_eq!(frames[1].message.data, annex_b(&[P_SLICE]));
+        assert_ne!(frames[1].message.timestamp, frames[0].message.timestamp);
+        assert_eq!(frames[2].message.data, annex_b(&[P_SLICE]));
+        assert_eq!(framer.flush(), vec![]);
+    }
+
+    #[test]
+    fn test_insert_parameter_sets() {
+        let mut framer = AnnexBFramer::new(VideoCodec::H264);
+        let ts = Timestamp::new(1, 0);
+        let mut frames = framer.push(&annex_b(&[SPS, PPS, IDR, P_SLICE]), ts);
+        frames.extend(framer.push(&annex_b(&[AUD, IDR, AUD, P_SLICE]), ts));
+        frames.extend(framer.flush());
+
+        let data: Vec<_> = frames.iter().map(|f| f.message.data.clone()).collect();
+        assert_eq!(
+            data,
+            vec![
+                annex_b(&[SPS, PPS, IDR]),
+                annex_b(&[P_SLICE]),
+                annex_b(&[AUD, SPS, PPS, IDR]),
+                annex_b(&[AUD, P_SLICE]),
+            ]
+        );
+        let keyframes: Vec<_> = frames.iter().map(|f| f.keyframe).collect();
+        assert_eq!(keyframes, [true, false, true, false]);
+    }
+
+    #[test]
+    fn test_h265() {
+        let vps: &[u8] = &[0x40, 0x01, 0x0c];
+        let sps: &[u8] = &[0x42, 0x01, 0x01];
+        let pps: &[u8] = &[0x44, 0x01, 0xc1];
+        // IDR_W_RADL, and TRAIL_R, each with the first slice segment flag set.
+        let idr: &[u8] = &[0x26, 0x01, 0xaf, 0x06];
+        let trail: &[u8] = &[0x02, 0x01, 0xd0, 0x2c];
+
+        let mut framer = AnnexBFramer::new(VideoCodec::H265);
+        let ts = Timestamp::new(1, 0);
+        let mut frames = framer.push(&annex_b(&[vps, sps, pps, idr, trail, idr]), ts);
+        frames.extend(framer.flush());
+
+        let data: Vec<_> = frames.iter().map(|f| f.message.data.clone()).collect();
+        assert_eq!(
+            data,
+            vec![
+                annex_b(&[vps, sps, pps, idr]),
+                annex_b(&[trail]),
+                annex_b(&[vps, sps, pps, idr]),
+            ]
+        );
+        assert!(frames.iter().all(|f| f.message.format == "h265"));
+    }
+}
@@ -8,7 +8,7 @@ pub(crate) use crate::websocket::protocol::client::{
 pub use crate::websocket::protocol::server::{
     Parameter, ParameterType, ParameterValue, Status, StatusLevel,
 };
-use crate::{get_runtime_handle, Channel, FoxgloveError, LogSink, Metadata};
+use crate::{get_runtime_handle, Channel, FoxgloveError, LogContext, LogSink, Metadata};
 use bimap::BiHashMap;
 use bytes::{BufMut, Bytes, BytesMut};
 use flume::TrySendError;
@@ -28,7 +28,16 @@ use tokio::{
     sync::Mutex,
 };
 use tokio_tungstenite::{
-    tungstenite::{self, handshake::server, http::HeaderValue, Message},
+    tungstenite::{
+        self,
+        handshake::server,
+        http::HeaderValue,
+        protocol::frame::{
+            coding::{Data, OpCode},
+            Frame,
+        },
+        Message,
+    },
     WebSocketStream,
 };
 use tokio_util::sync::CancellationToken;
@@ -38,9 +47,11 @@ pub use fetch_asset::{AssetHandler, AssetResponder};
 pub(crate) use fetch_asset::{AsyncAssetHandlerFn, BlockingAssetHandlerFn};
 mod connection_graph;
 mod protocol;
+mod recording;
 mod semaphore;
 pub mod service;
 pub use connection_graph::ConnectionGraph;
+pub use recording::RecordingServices;
 pub(crate) use semaphore::{Semaphore, SemaphoreGuard};
 #[cfg(test)]
 mod tests;
@@ -115,7 +126,7 @@ impl Client {
         if let Some(client) = self.client.upgrade() {
             match result {
                 Ok(asset) => client.send_asset_response(&asset, request_id),
-                Err(err) => client.send_asset_error(&err.to_string(), request_id),
+                Err(err) => client.send_asset_error(&err, request_id),
             }
         }
     }
@@ -164,6 +175,40 @@ const MAX_SEND_RETRIES: usize = 10;
 
 type WebsocketSender = SplitSink<WebSocketStream<TcpStream>, Message>;
 
+/// A message queued on a client's data plane.
+enum DataMessage {
+    Message(Message),
+    /// A message data frame. The header contains the client's subscription ID, and the payload
+    /// is shared by all clients, so the two are sent as separate fragments of one message.
+    MessageData {
+        header: Bytes,
+        payload: Bytes,
+    },
+}
+
+impl From<Message> for DataMessage {
+    fn from(message: Message) -> Self {
+        Self::Message(message)
+    }
+}
+
+/// Sends a message from the data plane.
+async fn send_data_message(
+    sender: &mut WebsocketSender,
+    message: DataMessage,
+) -> Result<(), tungstenite::Error> {
+    match message {
+        DataMessage::Message(message) => sender.send(message).await,
+        DataMessage::MessageData { header, payload } => {
+            // https://www.rfc-editor.org/rfc/rfc6455#section-5.4
+            let first = Frame::message(header, OpCode::Data(Data::Binary), false);
+            let last = Frame::message(payload, OpCode::Data(Data::Continue), true);
+            sender.feed(Message::Frame(first)).await?;
+            sender.send(Message::Frame(last)).await
+        }
+    }
+}
+
 // Queue up to 1024 messages per connected client before dropping messages
 // Can be overridden by ServerOptions::message_backlog_size.
 const DEFAULT_MESSAGE_BACKLOG_SIZE: usize = 1024;
@@ -187,6 +232,8 @@ pub(crate) struct ServerOptions {
     pub supported_encodings: Option<HashSet<String>>,
     pub runtime: Option<Handle>,
     pub fetch_asset_handler: Option<Box<dyn AssetHandler>>,
+    pub context: Option<Arc<LogContext>>,
+    pub recording_services: Option<RecordingServices>,
 }
 
 impl std::fmt::Debug for ServerOptions {
@@ -198,6 +245,7 @@ impl std::fmt::Debug for ServerOptions {
             .field("services", &self.services)
             .field("capabilities", &self.capabilities)
             .field("supported_encodings", &self.supported_encodings)
+            .field("recording_services", &self.recording_services)
             .finish()
     }
 }
@@ -240,6 +288,11 @@ pub(crate) struct Server {
     services: parking_lot::RwLock<ServiceMap>,
     /// Handler for fetch asset requests
     fetch_asset_handler: Option<Box<dyn AssetHandler>>,
+    /// The log context that the server is registered with as a sink.
+    ///
+    /// The context holds a reference to the server until the server is removed from it when it
+    /// is stopped.
+    context: Arc<LogContext>,
 }
 
 /// Provides a mechanism for registering callbacks for handling client message events.
@@ -310,8 +363,8 @@ pub(crate) struct ConnectedClient {
     weak_self: Weak<Self>,
     /// Write side of a WS stream
     sender: Mutex<WebsocketSender>,
-    data_plane_tx: flume::Sender<Message>,
-    data_plane_rx: flume::Receiver<Message>,
+    data_plane_tx: flume::Sender<DataMessage>,
+    data_plane_rx: flume::Receiver<DataMessage>,
     control_plane_tx: flume::Sender<Message>,
     control_plane_rx: flume::Receiver<Message>,
     service_call_sem: Semaphore,
@@ -404,12 +457,12 @@ impl ConnectedClient {
     }
 
     /// Send the message on the data plane, dropping up to retries older messages to make room, if necessary.
-    fn send_data_lossy(&self, message: Message, retries: usize) -> SendLossyResult {
+    fn send_data_lossy(&self, message: impl Into<DataMessage>, retries: usize) -> SendLossyResult {
         send_lossy(
             &self.addr,
             &self.data_plane_tx,
             &self.data_plane_rx,
-            message,
+            message.into(),
             retries,
         )
     }
@@ -1041,13 +1094,25 @@ impl Server {
     pub fn new(weak_self: Weak<Self>, opts: ServerOptions) -> Self {
         let mut capabilities = opts.capabilities.unwrap_or_default();
         let mut supported_encodings = opts.supported_encodings.unwrap_or_default();
+        let context = opts.context.unwrap_or_else(|| LogContext::global().clone());
+
+        let mut services = opts.services;
+        if let Some(recording_services) = opts.recording_services {
+            for service in
+                recording_services.into_services(weak_self.clone(), Arc::downgrade(&context))
+            {
+                if let Some(s) = services.insert(service.name().to_string(), service) {
+                    tracing::warn!("Redefining service {}", s.name());
+                }
+            }
+        }
 
         // If the server was declared with services, automatically add the "services" capability
         // and the set of supported request encodings.
-        if !opts.services.is_empty() {
+        if !services.is_empty() {
             capabilities.insert(Capability::Services);
             supported_encodings.extend(
-                opts.services
+                services
                     .values()
                     .filter_map(|svc| svc.schema().request().map(|s| s.encoding.clone())),
             );
@@ -1079,8 +1144,9 @@ impl Server {
             connection_graph: parking_lot::Mutex::new(ConnectionGraph::new()),
             connection_graph_subscriber_count: parking_lot::Mutex::new(0),
             cancellation_token: CancellationToken::new(),
-            services: parking_lot::RwLock::new(ServiceMap::from_iter(opts.services.into_values())),
+            services: parking_lot::RwLock::new(ServiceMap::from_iter(services.into_values())),
             fetch_asset_handler: opts.fetch_asset_handler,
+            context,
         }
     }
 
@@ -1094,6 +1160,11 @@ impl Server {
         self.port.load(Acquire)
     }
 
+    /// Returns the log context that the server is registered with.
+    pub(crate) fn context(&self) -> &Arc<LogContext> {
+        &self.context
+    }
+
     // Returns a handle to the async runtime that this server is using.
     pub fn runtime(&self) -> &Handle {
         &self.runtime
@@ -1370,7 +1441,7 @@ impl Server {
         let send_messages = async {
             while let Ok(msg) = new_client.data_plane_rx.recv_async().await {
                 let mut sender = new_client.sender.lock().await;
-                if let Err(err) = sender.send(msg).await {
+                if let Err(err) = send_data_message(&mut sender, msg).await {
                     if self.started.load(Acquire) {
                         tracing::error!("Error sending data message to client {addr}: {err}");
                     } else {
@@ -1603,11 +1674,11 @@ enum SendLossyResult {
 /// If the channel is full, drop the oldest message and try again. If the send eventually succeeds
 /// in this manner, this function returns `SendLossyResult::SentLossy(dropped)`. If the maximum
 /// number of retries is reached, it returns `SendLossyResult::ExhaustedRetries`.
-fn send_lossy(
+fn send_lossy<T>(
     client_addr: &SocketAddr,
-    tx: &flume::Sender<Message>,
-    rx: &flume::Receiver<Message>,
-    mut message: Message,
+    tx: &flume::Sender<T>,
+    rx: &flume::Receiver<T>,
+    mut message: T,
     retries: usize,
 ) -> SendLossyResult {
     // If the queue is full, drop the oldest message(s). We do this because the websocket
@@ -1644,24 +1715,36 @@ fn send_lossy(
 
 impl LogSink for Server {
     fn log(&self, channel: &Channel, msg: &[u8], metadata: &Metadata) -> Result<(), FoxgloveError> {
+        // The payload is copied once, and shared by all clients. Only the header, which contains
+        // the client's subscription ID, is written for each client.
+        let mut payload = None;
         let clients = self.clients.get();
         for client in clients.iter() {
-            let subscriptions = client.subscriptions.lock();
-            let Some(subscription_id) = subscriptions.get_by_left(&channel.id).copied() else {
+            let Some(subscription_id) = client
+                .subscriptions
+                .lock()
+                .get_by_left(&channel.id)
+                .copied()
+            else {
                 continue;
             };
 
             // https://github.com/foxglove/ws-protocol/blob/main/docs/spec.md#message-data
-            let header_size: usize = 1 + 4 + 8;
-            let mut buf = BytesMut::with_capacity(header_size + msg.len());
-            buf.put_u8(protocol::server::BinaryOpcode::MessageData as u8);
-            buf.put_u32_le(subscription_id.into());
-            buf.put_u64_le(metadata.log_time);
-            buf.put_slice(msg);
-
-            let message = Message::binary(buf);
-
-            client.send_data_lossy(message, MAX_SEND_RETRIES);
+            let mut header = BytesMut::with_capacity(1 + 4 + 8);
+            header.put_u8(protocol::server::BinaryOpcode::MessageData as u8);
+            header.put_u32_le(subscription_id.into());
+            header.put_u64_le(metadata.log_time);
+            let payload = payload
+                .get_or_insert_with(|| Bytes::copy_from_slice(msg))
+                .clone();
+
+            client.send_data_lossy(
+                DataMessage::MessageData {
+                    header: header.freeze(),
+                    payload,
+                },
+                MAX_SEND_RETRIES,
+            );
         }
         Ok(())
     }
@@ -1693,6 +1776,7 @@ async fn handle_connections(server: Arc<Server>, listener: TcpListener) {
 /// Add the subprotocol header to the response if the client requested one we support.
 /// If the client doesn't support our protocol, do not include the protocol header in the response;
 /// the client must fail the connection. [WebSocket RFC](https://www.rfc-editor.org/rfc/rfc6455#section-4)
+#[allow(clippy::result_large_err)]
 async fn do_handshake(stream: TcpStream) -> Result<WebSocketStream<TcpStream>, tungstenite::Error> {
     tokio_tungstenite::accept_hdr_async(
         stream,
@@ -0,0 +1,396 @@
+//! Services for controlling MCAP recordings from clients.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use bytes::Bytes;
+use parking_lot::Mutex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+
+use super::service::{Request, Service, ServiceSchema};
+use super::{Server, Status, StatusLevel};
+use crate::{FoxgloveError, LogContext, McapWriter, McapWriterHandle, Schema};
+
+#[cfg(test)]
+mod tests;
+
+/// The ID of the status messages which report the progress of a recording.
+const STATUS_ID: &str = "foxglove.recording";
+
+/// Configures services which clients can call to record the server's channels to MCAP files.
+///
+/// Register the services with [`WebSocketServer::recording_services`][crate::WebSocketServer::recording_services].
+/// The services use JSON requests and responses:
+///
+/// - `/recording/start` starts recording to a new file in the directory. The request may give the
+///   file `name`, and the `topics` to record. By default, the file is named after the current
+///   time, and all topics are recorded.
+/// - `/recording/stop` stops the recording, and returns the name and size of the file.
+/// - `/recording/list` returns the name and size of each MCAP file in the directory.
+/// - `/recording/status` returns the recording in progress, if any.
+///
+/// Only one recording can be in progress at a time. While recording, the server periodically
+/// publishes a [`Status`] message with the progress of the recording.
+///
+/// ```no_run
+/// use foxglove::websocket::RecordingServices;
+/// use foxglove::{FlushPolicy, McapWriter};
+/// use std::time::Duration;
+///
+/// # async fn func() -> Result<(), foxglove::FoxgloveError> {
+/// let server = foxglove::WebSocketServer::new()
+///     .recording_services(
+///         RecordingServices::new("/data/recordings").mcap_writer(
+///             McapWriter::new().flush_policy(FlushPolicy::new().interval(Duration::from_secs(1))),
+///         ),
+///     )
+///     .start()
+///     .await?;
+/// # Ok(()) }
+/// ```
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct RecordingServices {
+    dir: PathBuf,
+    writer: McapWriter,
+    status_interval: Duration,
+}
+
+impl RecordingServices {
+    /// Creates services which record to files in the given directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            writer: McapWriter::new(),
+            status_interval: Duration::from_secs(5),
+        }
+    }
+
+    /// Sets the writer used to create recordings, for example to set the MCAP write options, or
+    /// the flush policy.
+    ///
+    /// The writer's context and topics are replaced by the server's context, and the topics given
+    /// when the recording is started.
+    pub fn mcap_writer(mut self, writer: McapWriter) -> Self {
+        self.writer = writer;
+        self
+    }
+
+    /// Sets the interval at which the progress of a recording is published as a status message.
+    ///
+    /// By default, the progress is published every five seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval is zero.
+    pub fn status_interval(mut self, interval: Duration) -> Self {
+        assert!(!interval.is_zero(), "status interval must be non-zero");
+        self.status_interval = interval;
+        self
+    }
+
+    /// Returns the services, which record the channels of the server's context.
+    pub(crate) fn into_services(
+        self,
+        server: Weak<Server>,
+        context: Weak<LogContext>,
+    ) -> Vec<Service> {
+        let recorder = Arc::new(Recorder {
+            config: self,
+            server,
+            context,
+            active: Mutex::new(None),
+        });
+        let status_schema = || Schema::json_schema::<RecordingStatus>();
+        vec![
+            Service::builder(
+                "/recording/start",
+                ServiceSchema::new("foxglove.StartRecording")
+                    .with_request("json", Schema::json_schema::<StartRequest>())
+                    .with_response("json", status_schema()),
+            )
+            .blocking_handler_fn({
+                let recorder = recorder.clone();
+                move |req: Request| {
+                    let request = if req.payload().is_empty() {
+                        StartRequest::default()
+                    } else {
+                        serde_json::from_slice(req.payload())?
+                    };
+                    encode(&recorder.start(request)?)
+                }
+            }),
+            Service::builder(
+                "/recording/stop",
+                ServiceSchema::new("foxglove.StopRecording")
+                    .with_response("json", Schema::json_schema::<RecordingFile>()),
+            )
+            .blocking_handler_fn({
+                let recorder = recorder.clone();
+                move |_| encode(&recorder.stop()?)
+            }),
+            Service::builder(
+                "/recording/list",
+                ServiceSchema::new("foxglove.ListRecordings")
+                    .with_response("json", Schema::json_schema::<Vec<RecordingFile>>()),
+            )
+            .blocking_handler_fn({
+                let recorder = recorder.clone();
+                move |_| encode(&recorder.list()?)
+            }),
+            Service::builder(
+                "/recording/status",
+                ServiceSchema::new("foxglove.RecordingStatus")
+                    .with_response("json", status_schema()),
+            )
+            // Starting a recording holds the lock while it creates the file.
+            .blocking_handler_fn(move |_| encode(&recorder.status())),
+        ]
+    }
+}
+
+/// An error handling a recording service call.
+#[derive(Debug, thiserror::Error)]
+enum RecordingError {
+    #[error("A recording is already in progress: {0}")]
+    AlreadyRecording(String),
+    #[error("No recording is in progress")]
+    NotRecording,
+    #[error("Invalid recording name: {0}")]
+    InvalidName(String),
+    #[error("The server's log context was dropped")]
+    ContextDropped,
+    #[error("Invalid request: {0}")]
+    InvalidRequest(#[from] serde_json::Error),
+    #[error(transparent)]
+    Foxglove(#[from] FoxgloveError),
+}
+
+impl From<std::io::Error> for RecordingError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Foxglove(err.into())
+    }
+}
+
+/// Encodes a service response as JSON.
+fn encode<T: Serialize>(value: &T) -> Result<Bytes, RecordingError> {
+    Ok(serde_json::to_vec(value)?.into())
+}
+
+/// A request to start a recording.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+struct StartRequest {
+    /// The name of the file to create in the recording directory. The `.mcap` extension is added
+    /// if it is missing. Defaults to a name based on the current time.
+    name: Option<String>,
+    /// The topics to record. Defaults to all topics.
+    topics: Option<Vec<String>>,
+}
+
+/// The recording in progress.
+#[derive(Debug, Default, Serialize, JsonSchema)]
+struct RecordingStatus {
+    /// The name of the file being recorded, or null if no recording is in progress.
+    name: Option<String>,
+    /// The topics being recorded, or null if all topics are recorded.
+    topics: Option<Vec<String>>,
+    /// The time since the recording started, in seconds.
+    elapsed: Option<f64>,
+}
+
+/// A recording in the recording directory.
+#[derive(Debug, PartialEq, Serialize, JsonSchema)]
+struct RecordingFile {
+    /// The name of the file.
+    name: String,
+    /// The size of the file, in bytes.
+    size: u64,
+}
+
+struct ActiveRecording {
+    name: String,
+    topics: Option<Vec<String>>,
+    started: SystemTime,
+    handle: McapWriterHandle<BufWriter<File>>,
+    status_task: Option<JoinHandle<()>>,
+}
+
+impl ActiveRecording {
+    fn status(&self) -> RecordingStatus {
+        RecordingStatus {
+            name: Some(self.name.clone()),
+            topics: self.topics.clone(),
+            elapsed: Some(elapsed_secs(self.started)),
+        }
+    }
+}
+
+/// The state of the recording services.
+struct Recorder {
+    config: RecordingServices,
+    server: Weak<Server>,
+    context: Weak<LogContext>,
+    active: Mutex<Option<ActiveRecording>>,
+}
+
+impl Recorder {
+    fn start(&self, request: StartRequest) -> Result<RecordingStatus, RecordingError> {
+        let mut active = self.active.lock();
+        if let Some(recording) = active.as_ref() {
+            return Err(RecordingError::AlreadyRecording(recording.name.clone()));
+        }
+        let name = match request.name {
+            Some(name) if name.ends_with(".mcap") => name,
+            Some(name) => format!("{name}.mcap"),
+            None => default_name(SystemTime::now()),
+        };
+        let path = self.path(&name)?;
+        let context = self
+            .context
+            .upgrade()
+            .ok_or(RecordingError::ContextDropped)?;
+
+        let mut writer = self.config.writer.clone().context(&context);
+        if let Some(topics) = &request.topics {
+            writer = writer.topics(topics.iter().cloned());
+        }
+        let handle = writer.create_new_buffered_file(&path)?;
+        let started = SystemTime::now();
+        self.publish_status(StatusLevel::Info, format!("Recording to {name}"));
+        let status_task = self.server.upgrade().map(|server| {
+            server.runtime().spawn(publish_progress(
+                self.server.clone(),
+                name.clone(),
+                path,
+                started,
+                self.config.status_interval,
+            ))
+        });
+
+        let recording = active.insert(ActiveRecording {
+            name,
+            topics: request.topics,
+            started,
+            handle,
+            status_task,
+        });
+        Ok(recording.status())
+    }
+
+    fn stop(&self) -> Result<RecordingFile, RecordingError> {
+        let recording = self
+            .active
+            .lock()
+            .take()
+            .ok_or(RecordingError::NotRecording)?;
+        if let Some(task) = recording.status_task {
+            task.abort();
+        }
+        let result = recording.handle.close();
+        if let Err(e) = &result {
+            self.publish_status(
+                StatusLevel::Error,
+                format!("Failed to save {}: {e}", recording.name),
+            );
+        }
+        let file = result?.into_inner().map_err(|e| e.into_error())?;
+        let file = RecordingFile {
+            name: recording.name,
+            size: file.metadata()?.len(),
+        };
+        self.publish_status(
+            StatusLevel::Info,
+            format!("Saved {} ({} bytes)", file.name, file.size),
+        );
+        Ok(file)
+    }
+
+    fn list(&self) -> Result<Vec<RecordingFile>, RecordingError> {
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(&self.config.dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension().is_none_or(|ext| ext != "mcap") {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+            if metadata.is_file() {
+                files.push(RecordingFile {
+                    name: entry.file_name().to_string_lossy().into_owned(),
+                    size: metadata.len(),
+                });
+            }
+        }
+        files.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(files)
+    }
+
+    fn status(&self) -> RecordingStatus {
+        self.active
+            .lock()
+            .as_ref()
+            .map_or(RecordingStatus::default(), ActiveRecording::status)
+    }
+
+    /// Returns the path of a file in the recording directory.
+    ///
+    /// Names which refer to other directories are rejected.
+    fn path(&self, name: &str) -> Result<PathBuf, RecordingError> {
+        let path = Path::new(name);
+        if path.file_name().is_none_or(|n| n != name) {
+            return Err(RecordingError::InvalidName(name.to_string()));
+        }
+        Ok(self.config.dir.join(path))
+    }
+
+    fn publish_status(&self, level: StatusLevel, message: String) {
+        if let Some(server) = self.server.upgrade() {
+            server.publish_status(Status::new(level, message).with_id(STATUS_ID));
+        }
+    }
+}
+
+/// Returns the default name of a recording started at the given time.
+fn default_name(time: SystemTime) -> String {
+    let millis = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    format!("recording-{millis}.mcap")
+}
+
+/// Returns the time since `started`, in seconds.
+fn elapsed_secs(started: SystemTime) -> f64 {
+    started.elapsed().unwrap_or_default().as_secs_f64()
+}
+
+/// Periodically publishes the progress of a recording, until the task is aborted.
+async fn publish_progress(
+    server: Weak<Server>,
+    name: String,
+    path: PathBuf,
+    started: SystemTime,
+    interval: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    // The first tick completes immediately.
+    ticker.tick().await;
+    loop {
+        ticker.tick().await;
+        let Some(server) = server.upgrade() else {
+            break;
+        };
+        // The size only includes the data which has been flushed.
+        let size = std::fs::metadata(&path).map_or(0, |m| m.len());
+        let message = format!(
+            "Recording to {name}: {:.0}s, {size} bytes",
+            elapsed_secs(started)
+        );
+        server.publish_status(Status::new(StatusLevel::Info, message).with_id(STATUS_ID));
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+
+use bytes::{Buf, BufMut, BytesMut};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio_tungstenite::tungstenite::Message;
+
+use super::*;
+use crate::websocket::tests::connect_client;
+use crate::websocket::{create_server, ServerOptions};
+use crate::ChannelBuilder;
+
+fn read_topics(path: &Path) -> Vec<String> {
+    let buf = std::fs::read(path).expect("Failed to read recording");
+    mcap::MessageStream::new(&buf)
+        .expect("Failed to create message stream")
+        .map(|msg| msg.expect("Failed to read message").channel.topic.clone())
+        .collect()
+}
+
+fn new_recorder(dir: &Path, ctx: &Arc<LogContext>) -> Recorder {
+    Recorder {
+        config: RecordingServices::new(dir),
+        server: Weak::new(),
+        context: Arc::downgrade(ctx),
+        active: Mutex::new(None),
+    }
+}
+
+#[test]
+fn test_start_stop() {
+    let dir = tempfile::tempdir().expect("Failed to create tempdir");
+    let ctx = Arc::new(LogContext::new());
+    let recorder = new_recorder(dir.path(), &ctx);
+    let channels = ["/a", "/b"].map(|topic| {
+        ChannelBuilder::new(topic)
+            .message_encoding("json")
+            .with_context(&ctx)
+            .build()
+            .expect("Failed to create channel")
+    });
+
+    assert_matches::assert_matches!(recorder.stop(), Err(RecordingError::NotRecording));
+    assert_matches::assert_matches!(
+        recorder.start(StartRequest {
+            name: Some("../escape".into()),
+            topics: None,
+        }),
+        Err(RecordingError::InvalidName(_))
+    );
+
+    let status = recorder
+        .start(StartRequest {
+            name: Some("first".into()),
+            topics: Some(vec!["/a".into()]),
+        })
+        .expect("Failed to start");
+    assert_eq!(status.name.as_deref(), Some("first.mcap"));
+    assert_eq!(recorder.status().topics, Some(vec!["/a".to_string()]));
+    assert_matches::assert_matches!(
+        recorder.start(StartRequest::default()),
+        Err(RecordingError::AlreadyRecording(name)) if name == "first.mcap"
+    );
+    for channel in &channels {
+        channel.log(b"{}");
+    }
+    let file = recorder.stop().expect("Failed to stop");
+    assert_eq!(file.name, "first.mcap");
+    assert!(recorder.status().name.is_none());
+    assert_eq!(read_topics(&dir.path().join("first.mcap")), ["/a"]);
+
+    // By default, all topics are recorded to a file named after the current time.
+    let status = recorder
+        .start(StartRequest::default())
+        .expect("Failed to start");
+    let name = status.name.expect("Missing name");
+    assert!(name.starts_with("recording-"));
+    for channel in &channels {
+        channel.log(b"{}");
+    }
+    recorder.stop().expect("Failed to stop");
+    assert_eq!(read_topics(&dir.path().join(&name)), ["/a", "/b"]);
+
+    // Existing files are not overwritten.
+    assert!(recorder
+        .start(StartRequest {
+            name: Some(name.clone()),
+            topics: None,
+        })
+        .is_err());
+
+    let names: Vec<_> = recorder
+        .list()
+        .expect("Failed to list")
+        .into_iter()
+        .map(|f| f.name)
+        .collect();
+    assert_eq!(names, ["first.mcap".to_string(), name]);
+}
+
+/// Calls a service, and returns the response payload.
+///
+/// If `expect_status` is set, also waits for a status message, which may be sent before or after
+/// the response, and returns it.
+async fn call_service<S>(
+    client: &mut S,
+    service_id: u32,
+    payload: &[u8],
+    expect_status: bool,
+) -> (Result<Value, String>, Option<Value>)
+where
+    S: StreamExt<Item = Result<Message, tokio_tungstenite::tungstenite::Error>>
+        + SinkExt<Message>
+        + Unpin,
+    <S as futures_util::Sink<Message>>::Error: std::fmt::Debug,
+{
+    let mut buf = BytesMut::new();
+    buf.put_u8(2); // opcode
+    buf.put_u32_le(service_id);
+    buf.put_u32_le(1); // call id
+    buf.put_u32_le(4); // encoding length
+    buf.put(b"json".as_slice());
+    buf.put(payload);
+    client
+        .send(Message::binary(buf.freeze()))
+        .await
+        .expect("Failed to send");
+
+    let mut response = None;
+    let mut status = None;
+    while response.is_none() || (expect_status && status.is_none()) {
+        let msg = client
+            .next()
+            .await
+            .expect("No response")
+            .expect("Failed to read response");
+        match msg {
+            Message::Binary(mut data) => {
+                assert_eq!(data.get_u8(), 3); // opcode
+                data.advance(4 + 4); // service id, call id
+                let len = data.get_u32_le() as usize;
+                data.advance(len); // encoding
+                response = Some(Ok(serde_json::from_slice(&data).expect("Invalid response")));
+            }
+            Message::Text(text) => {
+                let value: Value = serde_json::from_str(text.as_str()).expect("Invalid JSON");
+                match value["op"].as_str() {
+                    Some("status") => status = Some(value),
+                    Some("serviceCallFailure") => {
+                        let message = value["message"].as_str().unwrap_or_default();
+                        response = Some(Err(message.to_string()));
+                    }
+                    _ => (),
+                }
+            }
+            _ => (),
+        }
+    }
+    (response.expect("No response"), status)
+}
+
+#[tokio::test]
+async fn test_recording_services() {
+    let dir = tempfile::tempdir().expect("Failed to create tempdir");
+    let ctx = Arc::new(LogContext::new());
+    let server = create_server(ServerOptions {
+        context: Some(ctx.clone()),
+        recording_services: Some(RecordingServices::new(dir.path())),
+        ..Default::default()
+    });
+    let addr = server
+        .start("127.0.0.1", 0)
+        .await
+        .expect("Failed to start server");
+    ctx.add_sink(server.clone());
+    let channel = ChannelBuilder::new("/a")
+        .message_encoding("json")
+        .with_context(&ctx)
+        .build()
+        .expect("Failed to create channel");
+
+    let mut client = connect_client(addr).await;
+    let info = client.next().await.expect("No serverInfo sent").unwrap();
+    let info: Value = serde_json::from_str(info.into_text().unwrap().as_str()).unwrap();
+    assert!(info["capabilities"]
+        .as_array()
+        .unwrap()
+        .contains(&json!("services")));
+
+    let mut service_ids = HashMap::new();
+    while service_ids.is_empty() {
+        let msg = client.next().await.expect("No message sent").unwrap();
+        let Message::Text(text) = msg else { continue };
+        let value: Value = serde_json::from_str(text.as_str()).unwrap();
+        if value["op"] == "advertiseServices" {
+            for service in value["services"].as_array().unwrap() {
+                let id = u32::try_from(service["id"].as_u64().unwrap()).unwrap();
+                service_ids.insert(service["name"].as_str().unwrap().to_string(), id);
+            }
+        }
+    }
+    let mut names: Vec<_> = service_ids.keys().cloned().collect();
+    names.sort();
+    assert_eq!(
+        names,
+        [
+            "/recording/list",
+            "/recording/start",
+            "/recording/status",
+            "/recording/stop"
+        ]
+    );
+
+    let (response, status) = call_service(
+        &mut client,
+        service_ids["/recording/start"],
+        br#"{"name": "test"}"#,
+        true,
+    )
+    .await;
+    assert_eq!(response.unwrap()["name"], "test.mcap");
+    let status = status.unwrap();
+    assert_eq!(status["id"], STATUS_ID);
+    assert_eq!(status["message"], "Recording to test.mcap");
+
+    channel.log(b"{}");
+
+    let (response, _) =
+        call_service(&mut client, service_ids["/recording/status"], b"", false).await;
+    assert_eq!(response.unwrap()["name"], "test.mcap");
+
+    let (response, status) =
+        call_service(&mut client, service_ids["/recording/stop"], b"", true).await;
+    let response = response.unwrap();
+    assert_eq!(response["name"], "test.mcap");
+    assert!(status.unwrap()["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Saved test.mcap"));
+    assert_eq!(read_topics(&dir.path().join("test.mcap")), ["/a"]);
+
+    let (response, _) = call_service(&mut client, service_ids["/recording/stop"], b"", false).await;
+    assert_eq!(response.unwrap_err(), "No recording is in progress");
+
+    let (response, _) = call_service(&mut client, service_ids["/recording/list"], b"", false).await;
+    assert_eq!(
+        response.unwrap(),
+        json!([{ "name": "test.mcap", "size": std::fs::metadata(dir.path().join("test.mcap")).unwrap().len() }])
+    );
+
+    server.stop().await;
+}
+
+#[test]
+#[should_panic(expected = "status interval must be non-zero")]
+fn test_zero_status_interval() {
+    let _ = RecordingServices::new("/tmp").status_interval(Duration::ZERO);
+}
@@ -432,6 +432,68 @@ async fn test_log_only_to_subscribers() {
     server.stop().await;
 }
 
+#[traced_test]
+#[tokio::test]
+async fn test_log_to_subscribers_with_shared_and_distinct_ids() {
+    let server = create_server(ServerOptions::default());
+    let ctx = LogContext::new();
+    ctx.add_sink(server.clone());
+    let ch = new_channel("/foo", &ctx);
+
+    let addr = server
+        .start("127.0.0.1", 0)
+        .await
+        .expect("Failed to start server");
+
+    // Two clients share subscription ID 1, and a third uses subscription ID 7.
+    let mut clients = Vec::new();
+    for subscription_id in [1, 1, 7] {
+        let mut client = connect_client(addr).await;
+        let _ = client.next().await.expect("No serverInfo sent").unwrap();
+        let _ = client.next().await.expect("No advertisement sent").unwrap();
+        let subscribe = json!({
+            "op": "subscribe",
+            "subscriptions": [{ "id": subscription_id, "channelId": ch.id() }]
+        });
+        client
+            .send(Message::text(subscribe.to_string()))
+            .await
+            .expect("Failed to send");
+        clients.push((subscription_id, client));
+    }
+
+    // Allow the server to process the subscription
+    // FG-10395 replace this with something more precise
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let metadata = Metadata {
+        log_time: 42,
+        ..Metadata::default()
+    };
+    server.log(&ch, b"payload", &metadata).unwrap();
+
+    // The header and the shared payload are sent as fragments, which the client reassembles.
+    for (subscription_id, client) in &mut clients {
+        let msg = client
+            .next()
+            .await
+            .unwrap()
+            .expect("Failed to parse message");
+        let Message::Binary(data) = msg else {
+            panic!("Expected a binary message, got {msg:?}");
+        };
+        assert_eq!(data[0], 0x01); // message data opcode
+        assert_eq!(
+            u32::from_le_bytes(data[1..=4].try_into().unwrap()),
+            *subscription_id
+        );
+        assert_eq!(u64::from_le_bytes(data[5..=12].try_into().unwrap()), 42);
+        assert_eq!(&data[13..], b"payload");
+    }
+
+    server.stop().await;
+}
+
 #[traced_test]
 #[tokio::test]
 async fn test_error_when_client_publish_unsupported() {
@@ -7,7 +7,7 @@ use std::sync::Arc;
 use crate::websocket::service::Service;
 use crate::websocket::{
     create_server, AssetHandler, AsyncAssetHandlerFn, BlockingAssetHandlerFn, Capability, Client,
-    ConnectionGraph, Parameter, Server, ServerOptions, Status,
+    ConnectionGraph, Parameter, RecordingServices, Server, ServerOptions, Status,
 };
 use crate::{get_runtime_handle, FoxgloveError, LogContext, LogSink};
 use bytes::Bytes;
@@ -134,6 +134,16 @@ impl WebSocketServer {
         self
     }
 
+    /// Registers services which clients can call to start and stop MCAP recordings of the
+    /// server's channels.
+    ///
+    /// Automatically adds [`Capability::Services`] to the set of advertised capabilities. Refer to
+    /// [`RecordingServices`] for the services and their requests.
+    pub fn recording_services(mut self, services: RecordingServices) -> Self {
+        self.options.recording_services = Some(services);
+        self
+    }
+
     /// Configure the set of supported encodings for client requests.
     ///
     /// This is used for both client-side publishing as well as service call request/responses.
@@ -156,6 +166,15 @@ impl WebSocketServer {
         self
     }
 
+    /// Sets the [`LogContext`] whose channels are advertised to clients.
+    ///
+    /// By default, the server is registered with the [global context](LogContext::global). The
+    /// server keeps the context alive until it is stopped.
+    pub fn context(mut self, ctx: &Arc<LogContext>) -> Self {
+        self.options.context = Some(ctx.clone());
+        self
+    }
+
     /// Configure the tokio runtime for the server to use for async tasks.
     ///
     /// By default, the server will use either the current runtime (if started with
@@ -175,7 +194,7 @@ impl WebSocketServer {
     pub async fn start(self) -> Result<WebSocketServerHandle, FoxgloveError> {
         let server = create_server(self.options);
         server.start(&self.host, self.port).await?;
-        LogContext::global().add_sink(server.clone());
+        server.context().add_sink(server.clone());
         Ok(WebSocketServerHandle(server))
     }
 
@@ -286,7 +305,7 @@ impl WebSocketServerHandle {
     /// Gracefully shutdown the websocket server.
     pub async fn stop(self) {
         let sink = self.0.clone() as Arc<dyn LogSink>;
-        LogContext::global().remove_sink(&sink);
+        self.0.context().remove_sink(&sink);
         self.0.stop().await;
     }
 }
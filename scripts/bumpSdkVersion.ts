@@ -5,6 +5,8 @@ import path from "path";
 import semver from "semver";
 
 const versionRegex = /^version\s*=\s*"([^"]*)"/m;
+// Published crates must specify the version of their workspace dependencies.
+const workspaceDependencyRegex = /^(foxglove-derive\s*=\s*\{\s*version\s*=\s*)"[^"]*"/m;
 
 async function main() {
   const newVersionV = process.argv[2];
@@ -53,7 +55,9 @@ async function main() {
       continue;
     }
 
-    const updatedContent = content.replace(versionRegex, `version = "${newVersion}"`);
+    const updatedContent = content
+      .replace(versionRegex, `version = "${newVersion}"`)
+      .replace(workspaceDependencyRegex, `$1"${newVersion}"`);
     if (content === updatedContent) {
       console.error(`  ❌ Version could not be updated from "${prevVersion}" to "${newVersion}"`);
       success = false;
@@ -524,9 +524,10 @@ export function generateChannelClasses(messageSchemas: FoxgloveMessageSchema[]):
   const schemas = messageSchemas.filter((schema) => !schema.name.endsWith("Primitive"));
 
   const imports = [
-    `use foxglove::{TypedChannel, PartialMetadata};`,
+    `use foxglove::{ChannelBuilder, TypedChannel, PartialMetadata};`,
     `use pyo3::prelude::*;`,
     `use crate::errors::PyFoxgloveError;`,
+    `use crate::PyContext;`,
     `use super::schemas;`,
   ].join("\n");
 
@@ -545,9 +546,14 @@ impl ${channelClass} {
     /// Create a new channel.
     ///
     /// :param topic: The topic to log messages to.
+    /// :param context: The context to register the channel with. Defaults to the global context.
     #[new]
-    fn new(topic: &str) -> PyResult<Self> {
-        let base = TypedChannel::new(topic).map_err(PyFoxgloveError::from)?;
+    #[pyo3(signature = (topic, *, context=None))]
+    fn new(topic: &str, context: Option<PyRef<PyContext>>) -> PyResult<Self> {
+        let base = ChannelBuilder::new(topic)
+            .with_context(&PyContext::or_global(context))
+            .build_typed()
+            .map_err(PyFoxgloveError::from)?;
         Ok(Self(Some(base)))
     }
 
@@ -610,6 +616,7 @@ export function generatePyChannelStub(messageSchemas: FoxgloveMessageSchema[]):
   const schemas = messageSchemas.filter((schema) => !schema.name.endsWith("Primitive"));
 
   const imports = [
+    `from . import Context`,
     ...schemas.map((schema) =>  `from .schemas import ${structName(schema.name)}`),
   ];
 
@@ -626,6 +633,8 @@ export function generatePyChannelStub(messageSchemas: FoxgloveMessageSchema[]):
         `    def __new__(`,
         `        cls,`,
         `        topic: str,`,
+        `        *,`,
+        `        context: Context | None = None,`,
         `    ) -> "${channelClass}": ...\n`,
         `    def close(self) -> None: ...`,
         `    def log(`,